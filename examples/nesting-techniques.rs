@@ -9,25 +9,19 @@ struct Foo {
 	string: String,
 }
 
-// Demonstrate how to create pseudo 'on change' callbacks by aliasing the properties as actions
-// Specify before or after on change by changing the order in which they are listed to the visitor
-impl Foo {
-	fn before_int_changed(&mut self, _args: &str, writer: &mut dyn cvar::IWrite) {
-		self.string = self.int.to_string();
-		let _ = writeln!(writer, "Before int is changed!");
-	}
-	fn after_float_changed(&mut self, _args: &str, writer: &mut dyn cvar::IWrite) {
-		self.string = self.float.to_string();
-		let _ = writeln!(writer, "After float has changed!");
-	}
-}
-
+// Demonstrate how to create 'on change' callbacks by wrapping the properties
+// The callback runs after the property has changed and receives the old and the new value
 impl cvar::IVisit for Foo {
 	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn cvar::INode)) {
-		f(&mut cvar::Action("int", |args, writer| self.before_int_changed(args, writer)));
-		f(&mut cvar::Property("int", &mut self.int, &0));
-		f(&mut cvar::Property("float", &mut self.float, &0.0));
-		f(&mut cvar::Action("float", |args, writer| self.after_float_changed(args, writer)));
+		let string = &mut self.string;
+		f(&mut cvar::ApplyProp(cvar::Property("int", &mut self.int, &0), |old: &i32, _new: &i32, writer| {
+			*string = old.to_string();
+			let _ = write!(writer, "int was {old}!");
+		}));
+		f(&mut cvar::ApplyProp(cvar::Property("float", &mut self.float, &0.0), |_old: &f32, new: &f32, writer| {
+			*string = new.to_string();
+			let _ = write!(writer, "After float has changed!");
+		}));
 		f(&mut cvar::Property("string", &mut self.string, &String::new()));
 	}
 }
@@ -51,7 +45,7 @@ fn main() {
 	let mut nested = Nested::default();
 
 	// This property appears nested but is set in the parent context
-	cvar::console::set_value(&mut nested, "foo.bool", &true, &mut cvar::NullWriter);
+	cvar::console::set_value_silent(&mut nested, "foo.bool", &true);
 	assert!(nested.boolean);
 
 	println!("Hit enter to list all the cvars and their values.");
@@ -64,9 +58,9 @@ fn main() {
 			break;
 		}
 
-		// Crude command line parsing
+		// Split the path from its arguments
 		let (path, args) = split_line(&line);
-		cvar::console::poke(&mut nested, path, args, &mut cvar::IoWriter::stdout());
+		cvar::console::poke_stdout(&mut nested, path, args);
 	}
 }
 
@@ -75,12 +69,12 @@ pub fn read_line(line: &mut String) -> bool {
 	use std::io;
 	print!(">>> ");
 	let _ = io::Write::flush(&mut io::stdout());
-	return io::stdin().read_line(line).is_err() || line.is_empty();
+	io::stdin().read_line(line).is_err() || line.is_empty()
 }
 
 pub fn split_line(line: &str) -> (&str, Option<&str>) {
-	let line = line.trim_start();
-	let path = line.split_ascii_whitespace().next().unwrap_or("");
-	let args = &line[path.len()..].trim();
-	(path, if args.len() == 0 { None } else { Some(args) })
+	let mut tokens = cvar::console::tokenize(line);
+	let path = tokens.next().unwrap_or("");
+	let args = tokens.remainder().trim_end();
+	(path, if args.is_empty() { None } else { Some(args) })
 }
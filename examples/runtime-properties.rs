@@ -5,36 +5,40 @@ This example demonstrates how properties can be created and destroyed at runtime
 #[derive(Debug, Default)]
 struct RuntimeProps {
 	// Store the list of runtime properties somewhere
-	props: Vec<Box<dyn cvar::IProperty>>,
+	props: cvar::DynamicList,
 }
 
 impl RuntimeProps {
 	// Action to create new properties
 	fn create(&mut self, args: &str, writer: &mut dyn cvar::IWrite) {
-		// Crude argument parsing
-		let args = args.trim();
-		let first = args.split_ascii_whitespace().next().unwrap_or("");
-		let args = args[first.len()..].trim_start();
-		let second = args.split_ascii_whitespace().next().unwrap_or("");
-		let third = args[second.len()..].trim_start();
-		if first.len() == 0 {
+		let mut tokens = cvar::console::tokenize(args);
+		let first = tokens.next_unescaped().unwrap_or_default();
+		let second = tokens.next_unescaped().unwrap_or_default();
+		let third = tokens.next_unescaped().unwrap_or_default();
+		if first.is_empty() {
 			let _ = writeln!(writer, "Invalid arguments! expecting <type> <name> <value>");
 			return;
 		}
-		match first {
+		match &*first {
 			"string" => {
-				let prop = cvar::OwnedProp(second.into(), String::from(third), String::from(third));
-				self.props.push(Box::new(prop));
+				let prop = cvar::OwnedProp(second.to_string(), third.to_string(), third.to_string());
+				self.props.insert(&second, Box::new(prop));
 			},
 			"int" => {
-				let value: i32 = third.parse().unwrap();
-				let prop = cvar::OwnedProp(second.into(), value, value);
-				self.props.push(Box::new(prop));
+				let Ok(value) = third.parse::<i32>() else {
+					let _ = writeln!(writer, "Invalid value! expecting an int: {third:?}");
+					return;
+				};
+				let prop = cvar::OwnedProp(second.to_string(), value, value);
+				self.props.insert(&second, Box::new(prop));
 			},
 			"float" => {
-				let value: f32 = third.parse().unwrap();
-				let prop = cvar::OwnedProp(second.into(), value, value);
-				self.props.push(Box::new(prop));
+				let Ok(value) = third.parse::<f32>() else {
+					let _ = writeln!(writer, "Invalid value! expecting a float: {third:?}");
+					return;
+				};
+				let prop = cvar::OwnedProp(second.to_string(), value, value);
+				self.props.insert(&second, Box::new(prop));
 			},
 			_ => {
 				let _ = writeln!(writer, "Invalid type! supports string, int or float");
@@ -43,11 +47,13 @@ impl RuntimeProps {
 	}
 	// Action to remove properties
 	fn destroy(&mut self, args: &str, writer: &mut dyn cvar::IWrite) {
-		if args.len() == 0 {
+		let Some(name) = cvar::console::tokenize(args).next_unescaped() else {
 			let _ = writeln!(writer, "Invalid arguments! expecting the name of the property to remove");
 			return;
+		};
+		if self.props.remove(&name).is_none() {
+			let _ = writeln!(writer, "Unknown property {name}!");
 		}
-		self.props.retain(|prop| prop.name() != args);
 	}
 }
 
@@ -55,9 +61,7 @@ impl cvar::IVisit for RuntimeProps {
 	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn cvar::INode)) {
 		f(&mut cvar::Action("create!", |args, writer| self.create(args, writer)));
 		f(&mut cvar::Action("destroy!", |args, writer| self.destroy(args, writer)));
-		for prop in &mut self.props {
-			f(prop.as_inode());
-		}
+		self.props.visit(f);
 	}
 }
 
@@ -67,18 +71,20 @@ fn main() {
 	// Create some runtime props
 	let mut writer = String::new();
 	cvar::console::invoke(&mut runtime_props, "create!", "float f 3.141592", &mut writer);
-	cvar::console::invoke(&mut runtime_props, "create!", "string s Hello World!", &mut writer);
+	cvar::console::invoke(&mut runtime_props, "create!", r#"string s "Hello World!""#, &mut writer);
 	cvar::console::invoke(&mut runtime_props, "create!", "int i 42", &mut writer);
+	cvar::console::invoke(&mut runtime_props, "create!", "int bad 4.2", &mut writer);
 
 	// Inspect the underlying props
 	assert_eq!(runtime_props.props.len(), 3);
-	assert_eq!(runtime_props.props[0].get_value().to_string(), "3.141592");
-	assert_eq!(runtime_props.props[1].get_value().to_string(), "Hello World!");
-	assert_eq!(runtime_props.props[2].get_value().to_string(), "42");
+	assert_eq!(cvar::console::get(&mut runtime_props, "f").as_deref(), Some("3.141592"));
+	assert_eq!(cvar::console::get(&mut runtime_props, "s").as_deref(), Some("Hello World!"));
+	assert_eq!(cvar::console::get(&mut runtime_props, "i").as_deref(), Some("42"));
+	assert_eq!(writer, "Invalid value! expecting an int: \"4.2\"\n");
 
 	println!("Hit enter to list all the cvars and their values.");
 	println!("Assign value to cvar with `<name> <value>`.");
-	println!("Create new cvars with `create! <type> <name> <value>`.");
+	println!("Create new cvars with `create! <type> <name> <value>`, quote values containing spaces.");
 	println!("Destroy the cvars with `destroy! <name>.");
 
 	loop {
@@ -88,9 +94,9 @@ fn main() {
 			break;
 		}
 
-		// Crude command line parsing
+		// Split the path from its arguments
 		let (path, args) = split_line(&line);
-		cvar::console::poke(&mut runtime_props, path, args, &mut cvar::IoWriter::stdout());
+		cvar::console::poke_stdout(&mut runtime_props, path, args);
 	}
 }
 
@@ -99,12 +105,12 @@ pub fn read_line(line: &mut String) -> bool {
 	use std::io;
 	print!(">>> ");
 	let _ = io::Write::flush(&mut io::stdout());
-	return io::stdin().read_line(line).is_err() || line.is_empty();
+	io::stdin().read_line(line).is_err() || line.is_empty()
 }
 
 pub fn split_line(line: &str) -> (&str, Option<&str>) {
-	let line = line.trim_start();
-	let path = line.split_ascii_whitespace().next().unwrap_or("");
-	let args = &line[path.len()..].trim();
-	(path, if args.len() == 0 { None } else { Some(args) })
+	let mut tokens = cvar::console::tokenize(line);
+	let path = tokens.next().unwrap_or("");
+	let args = tokens.remainder().trim_end();
+	(path, if args.is_empty() { None } else { Some(args) })
 }
@@ -0,0 +1,331 @@
+use super::*;
+
+/// Property node accessing its value through a getter and a setter.
+///
+/// For values which do not live in a plain field, eg. state owned by a window or an audio device.
+/// The getter is called when the node is created and after every change, its result is what the property displays.
+/// The setter applies parsed values and may reject them with an error which is written to the writer.
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// struct Window { vsync: Cell<bool> }
+/// let window = Window { vsync: Cell::new(true) };
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::AccessorProp("vsync", &true, || window.vsync.get(), |vsync| {
+/// 		window.vsync.set(vsync);
+/// 		Ok::<_, String>(())
+/// 	}));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "vsync", "false"));
+/// assert!(!window.vsync.get());
+/// ```
+pub struct AccessorProp<'a, T: 'static, G, S> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	value: T,
+	default: &'a T,
+	get: G,
+	set: S,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn AccessorProp<'a, T, G: Fn() -> T, S>(name: &'a str, default: &'a T, get: G, set: S) -> AccessorProp<'a, T, G, S> {
+	AccessorProp::new(name, default, get, set)
+}
+
+impl<'a, T, G: Fn() -> T, S> AccessorProp<'a, T, G, S> {
+	#[inline]
+	pub fn new(name: &'a str, default: &'a T, get: G, set: S) -> AccessorProp<'a, T, G, S> {
+		let value = get();
+		AccessorProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, value, default, get, set }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+}
+
+impl<'a, T, G, S, E> AccessorProp<'a, T, G, S>
+	where G: Fn() -> T,
+	      S: FnMut(T) -> Result<(), E>,
+	      E: fmt::Display
+{
+	// Applies the value through the setter and reads it back
+	fn store(&mut self, val: T, writer: &mut dyn IWrite) -> bool {
+		let result = (self.set)(val);
+		self.value = (self.get)();
+		match result {
+			Ok(()) => true,
+			Err(err) => {
+				let _ = write!(writer, "{err}");
+				false
+			},
+		}
+	}
+}
+
+impl<'a, T, G, S, E> INode for AccessorProp<'a, T, G, S>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static,
+	      G: Fn() -> T,
+	      S: FnMut(T) -> Result<(), E>,
+	      E: fmt::Display
+{
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, T, G, S, E> IProperty for AccessorProp<'a, T, G, S>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static,
+	      G: Fn() -> T,
+	      S: FnMut(T) -> Result<(), E>,
+	      E: fmt::Display
+{
+	fn get_value(&self) -> &dyn IValue {
+		&self.value
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(val) = val.downcast_ref::<T>() {
+			self.store(val.clone(), writer)
+		}
+		else {
+			let _ = write_mismatched_types::<T>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => self.store(val, writer),
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		validate_parse::<T>(val, writer)
+	}
+
+	fn reset(&mut self) {
+		self.store(self.default.clone(), &mut NullWriter);
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.default
+	}
+
+	fn state(&self) -> PropState {
+		match self.value == *self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Read-only property node computing its value from a closure.
+///
+/// For derived values such as the frame rate or the number of entities.
+/// The closure is evaluated when the node is created, as nodes are created every time the tree is visited the console always shows the current value.
+///
+/// The value is its own default and has the [`READONLY`](flags::READONLY) and [`NO_PERSIST`](flags::NO_PERSIST) flags.
+///
+/// ```
+/// let entities = vec!["player", "enemy", "pickup"];
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ComputedProp("entity_count", || entities.len()));
+/// });
+///
+/// assert_eq!(cvar::console::get(&mut root, "entity_count").as_deref(), Some("3"));
+/// assert!(!cvar::console::set_silent(&mut root, "entity_count", "4"));
+/// ```
+pub struct ComputedProp<'a, T: 'static> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	value: T,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn ComputedProp<'a, T, F: FnOnce() -> T>(name: &'a str, compute: F) -> ComputedProp<'a, T> {
+	ComputedProp::new(name, compute)
+}
+
+impl<'a, T> ComputedProp<'a, T> {
+	#[inline]
+	pub fn new<F: FnOnce() -> T>(name: &'a str, compute: F) -> ComputedProp<'a, T> {
+		ComputedProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, value: compute() }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+}
+
+impl<'a, T: IValue> INode for ComputedProp<'a, T> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, T: IValue> IProperty for ComputedProp<'a, T> {
+	fn get_value(&self) -> &dyn IValue {
+		&self.value
+	}
+
+	fn set_value(&mut self, _val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		let _ = writer.write_str("cannot set read-only property");
+		false
+	}
+
+	fn set(&mut self, _val: &str, writer: &mut dyn IWrite) -> bool {
+		let _ = writer.write_str("cannot set read-only property");
+		false
+	}
+
+	fn validate(&self, _val: &str, writer: &mut dyn IWrite) -> bool {
+		let _ = writer.write_str("cannot set read-only property");
+		false
+	}
+
+	fn reset(&mut self) {}
+
+	fn default_value(&self) -> &dyn IValue {
+		&self.value
+	}
+
+	fn state(&self) -> PropState {
+		PropState::Default
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags | flags::READONLY | flags::NO_PERSIST
+	}
+}
@@ -0,0 +1,372 @@
+use super::*;
+
+/// Node visitor which adds short names for nodes deep in the tree.
+///
+/// Wraps a visitor and adds an alias node for every entry in the table, mapping the alias name to the path of its target.
+/// The aliases forward to their target, giving power users short console names without flattening the tree.
+///
+/// Aliased properties have the [`NO_PERSIST`](flags::NO_PERSIST) flag so their values are only saved under the path of the target.
+///
+/// ```
+/// let mut fov = 90;
+/// let mut camera = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("fov", &mut fov, &90));
+/// });
+/// let mut render = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("camera", &mut camera));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("render", &mut render));
+/// });
+/// let mut root = cvar::Aliases(&mut root, &[("fov", "render.camera.fov")]);
+///
+/// assert!(cvar::console::set(&mut root, "fov", "110", &mut cvar::NullWriter));
+/// assert_eq!(cvar::console::get(&mut root, "render.camera.fov").as_deref(), Some("110"));
+/// ```
+pub struct Aliases<'a, 'x> {
+	root: &'x mut dyn IVisit,
+	aliases: &'a [(&'a str, &'a str)],
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn Aliases<'a, 'x>(root: &'x mut dyn IVisit, aliases: &'a [(&'a str, &'a str)]) -> Aliases<'a, 'x> {
+	Aliases { root, aliases }
+}
+
+impl<'a, 'x> Aliases<'a, 'x> {
+	#[inline]
+	pub fn new(root: &'x mut dyn IVisit, aliases: &'a [(&'a str, &'a str)]) -> Aliases<'a, 'x> {
+		Aliases { root, aliases }
+	}
+}
+
+impl<'a, 'x> fmt::Debug for Aliases<'a, 'x> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Aliases")
+			.field("aliases", &self.aliases)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a, 'x> IVisit for Aliases<'a, 'x> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_aliases(self.root, self.aliases, false, f);
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Node visitor which keeps renamed nodes reachable under their old paths.
+///
+/// Wraps a visitor and adds an alias node for every entry in the table, mapping the deprecated path to the path of the node it was renamed to.
+/// The aliases forward to their target, properties set through an alias write a deprecation note and actions invoked through one write a warning first.
+///
+/// Aliased properties have the [`HIDDEN`](flags::HIDDEN) and [`NO_PERSIST`](flags::NO_PERSIST) flags so they stay out of listings and saved configs, which migrate to the new path on the next save.
+/// They also have the [`DEPRECATED`](flags::DEPRECATED) flag, see [`console::startup_report`].
+///
+/// ```
+/// let mut sensitivity = 1.0;
+/// let mut input = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("sensitivity", &mut sensitivity, &1.0));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("input", &mut input));
+/// });
+/// let mut root = cvar::Deprecated(&mut root, &[("m_sensitivity", "input.sensitivity")]);
+///
+/// let mut writer = String::new();
+/// assert!(cvar::console::set(&mut root, "m_sensitivity", "2.5", &mut writer));
+/// assert_eq!(writer, "m_sensitivity: deprecated, use input.sensitivity\n");
+/// assert_eq!(cvar::console::get(&mut root, "input.sensitivity").as_deref(), Some("2.5"));
+/// ```
+pub struct Deprecated<'a, 'x> {
+	root: &'x mut dyn IVisit,
+	aliases: &'a [(&'a str, &'a str)],
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn Deprecated<'a, 'x>(root: &'x mut dyn IVisit, aliases: &'a [(&'a str, &'a str)]) -> Deprecated<'a, 'x> {
+	Deprecated { root, aliases }
+}
+
+impl<'a, 'x> Deprecated<'a, 'x> {
+	#[inline]
+	pub fn new(root: &'x mut dyn IVisit, aliases: &'a [(&'a str, &'a str)]) -> Deprecated<'a, 'x> {
+		Deprecated { root, aliases }
+	}
+}
+
+impl<'a, 'x> fmt::Debug for Deprecated<'a, 'x> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Deprecated")
+			.field("aliases", &self.aliases)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a, 'x> IVisit for Deprecated<'a, 'x> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_aliases(self.root, self.aliases, true, f);
+	}
+}
+
+//----------------------------------------------------------------
+
+fn visit_aliases(root: &mut dyn IVisit, aliases: &[(&str, &str)], deprecated: bool, f: &mut dyn FnMut(&mut dyn INode)) {
+	root.visit(f);
+	for &(name, target) in aliases {
+		console::find(root, target, |node| visit_alias(node, name, target, deprecated, f));
+	}
+}
+
+fn visit_alias(node: &mut dyn INode, name: &str, target: &str, deprecated: bool, f: &mut dyn FnMut(&mut dyn INode)) {
+	match node.as_node() {
+		Node::Prop(prop) => f(&mut AliasProp { prop, name, target, deprecated }),
+		Node::List(list) => f(&mut AliasList { list, name, target, deprecated }),
+		Node::Action(act) => f(&mut AliasAction { act, name, target, deprecated }),
+	}
+}
+
+struct AliasProp<'a> {
+	prop: &'a mut dyn IProperty,
+	name: &'a str,
+	target: &'a str,
+	deprecated: bool,
+}
+
+impl<'a> AliasProp<'a> {
+	// Writes the deprecation note followed by the notes of the target
+	fn note(&self, result: bool, notes: &str, writer: &mut dyn IWrite) -> bool {
+		if result && self.deprecated {
+			let _ = write!(writer, "deprecated, use {}", self.target);
+			if !notes.is_empty() {
+				let _ = write!(writer, "; {notes}");
+			}
+		}
+		else {
+			let _ = writer.write_str(notes);
+		}
+		result
+	}
+}
+
+impl<'a> INode for AliasProp<'a> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.prop.description()
+	}
+
+	fn label(&self) -> &str {
+		self.prop.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		None
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.prop.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IProperty for AliasProp<'a> {
+	fn get_value(&self) -> &dyn IValue {
+		self.prop.get_value()
+	}
+
+	fn persist_value(&self) -> &dyn IValue {
+		self.prop.persist_value()
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		let mut notes = String::new();
+		let result = self.prop.set_value(val, &mut notes);
+		self.note(result, &notes, writer)
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		let mut notes = String::new();
+		let result = self.prop.set(val, &mut notes);
+		self.note(result, &notes, writer)
+	}
+
+	fn set_layer(&mut self, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+		let mut notes = String::new();
+		let result = self.prop.set_layer(layer, val, &mut notes);
+		self.note(result, &notes, writer)
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.validate(val, writer)
+	}
+
+	fn reset(&mut self) {
+		self.prop.reset();
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.prop.default_value()
+	}
+
+	fn set_default(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.set_default(val, writer)
+	}
+
+	fn state(&self) -> PropState {
+		self.prop.state()
+	}
+
+	fn flags(&self) -> u32 {
+		match self.deprecated {
+			true => self.prop.flags() | flags::HIDDEN | flags::NO_PERSIST | flags::DEPRECATED,
+			false => self.prop.flags() | flags::NO_PERSIST,
+		}
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.prop.epsilon()
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.prop.min_value()
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.prop.max_value()
+	}
+
+	fn provenance(&self) -> Layer {
+		self.prop.provenance()
+	}
+
+	#[cfg(feature = "type_name")]
+	fn type_name(&self) -> &str {
+		self.prop.type_name()
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		self.prop.values()
+	}
+}
+
+struct AliasList<'a> {
+	list: &'a mut dyn IList,
+	name: &'a str,
+	target: &'a str,
+	deprecated: bool,
+}
+
+impl<'a> INode for AliasList<'a> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.list.description()
+	}
+
+	fn label(&self) -> &str {
+		self.list.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		None
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.list.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IList for AliasList<'a> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a> IVisit for AliasList<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let (target, deprecated) = (self.target, self.deprecated);
+		self.list.as_ivisit().visit(&mut |node| {
+			let name = node.name().to_string();
+			let target = format!("{target}.{name}");
+			visit_alias(node, &name, &target, deprecated, f);
+		});
+	}
+}
+
+struct AliasAction<'a> {
+	act: &'a mut dyn IAction,
+	name: &'a str,
+	target: &'a str,
+	deprecated: bool,
+}
+
+impl<'a> INode for AliasAction<'a> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.act.description()
+	}
+
+	fn label(&self) -> &str {
+		self.act.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		None
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.act.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Action(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IAction for AliasAction<'a> {
+	fn invoke(&mut self, args: &str, writer: &mut dyn IWrite) -> BoxResult {
+		if self.deprecated {
+			// warning: deprecated, use cvar.action
+			let _ = writeln!(writer, "warning: deprecated, use {}", self.target);
+		}
+		self.act.invoke(args, writer)
+	}
+
+	fn usage(&self) -> &str {
+		self.act.usage()
+	}
+
+	fn complete(&mut self, partial_args: &str) -> Vec<String> {
+		self.act.complete(partial_args)
+	}
+}
@@ -0,0 +1,315 @@
+use super::*;
+use std::marker::PhantomData;
+
+/// List node of staged properties which are applied as a unit.
+///
+/// Settings such as the video mode consist of several properties which should only take effect together.
+/// The properties of the staged value can be edited freely, invoking the `apply!` action commits them all at once.
+/// The `revert!` action discards the staged changes.
+///
+/// When applied the callback is given the current and the staged values, if it returns `true` the staged value becomes the current value.
+///
+/// ```
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct VideoMode {
+/// 	width: i32,
+/// 	height: i32,
+/// 	fullscreen: bool,
+/// }
+///
+/// impl cvar::IVisit for VideoMode {
+/// 	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn cvar::INode)) {
+/// 		f(&mut cvar::Property("width", &mut self.width, &1920));
+/// 		f(&mut cvar::Property("height", &mut self.height, &1080));
+/// 		f(&mut cvar::Property("fullscreen", &mut self.fullscreen, &false));
+/// 	}
+/// }
+///
+/// let mut current = VideoMode { width: 1920, height: 1080, fullscreen: false };
+/// let mut staged = current.clone();
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ApplyGroup("video", &mut current, &mut staged, |old, new, writer| {
+/// 		let _ = writeln!(writer, "{}x{} -> {}x{}", old.width, old.height, new.width, new.height);
+/// 		true
+/// 	}));
+/// });
+///
+/// let mut writer = String::new();
+/// cvar::console::set_silent(&mut root, "video.width", "1280");
+/// cvar::console::set_silent(&mut root, "video.height", "720");
+/// cvar::console::invoke(&mut root, "video.apply!", "", &mut writer);
+/// assert_eq!(writer, "1920x1080 -> 1280x720\n");
+/// # drop(root);
+/// # assert_eq!(current, VideoMode { width: 1280, height: 720, fullscreen: false });
+/// ```
+pub struct ApplyGroup<'a, 'x, T, F> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	current: &'x mut T,
+	staged: &'x mut T,
+	apply: F,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn ApplyGroup<'a, 'x, T, F>(name: &'a str, current: &'x mut T, staged: &'x mut T, apply: F) -> ApplyGroup<'a, 'x, T, F>
+	where T: IVisit + Clone, F: FnMut(&T, &T, &mut dyn IWrite) -> bool
+{
+	ApplyGroup { name, desc: "", label: "", id: None, current, staged, apply }
+}
+
+impl<'a, 'x, T, F> ApplyGroup<'a, 'x, T, F>
+	where T: IVisit + Clone, F: FnMut(&T, &T, &mut dyn IWrite) -> bool
+{
+	#[inline]
+	pub fn new(name: &'a str, current: &'x mut T, staged: &'x mut T, apply: F) -> ApplyGroup<'a, 'x, T, F> {
+		ApplyGroup { name, desc: "", label: "", id: None, current, staged, apply }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+}
+
+impl<'a, 'x, T, F> INode for ApplyGroup<'a, 'x, T, F>
+	where T: IVisit + Clone, F: FnMut(&T, &T, &mut dyn IWrite) -> bool
+{
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x, T, F> IList for ApplyGroup<'a, 'x, T, F>
+	where T: IVisit + Clone, F: FnMut(&T, &T, &mut dyn IWrite) -> bool
+{
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a, 'x, T, F> IVisit for ApplyGroup<'a, 'x, T, F>
+	where T: IVisit + Clone, F: FnMut(&T, &T, &mut dyn IWrite) -> bool
+{
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		self.staged.visit(f);
+		f(&mut Action("apply!", |_args, writer| -> BoxResult {
+			if !(self.apply)(self.current, self.staged, writer) {
+				return Err("staged changes rejected".into());
+			}
+			self.current.clone_from(self.staged);
+			Ok(())
+		}).with_desc("Applies the staged changes."));
+		f(&mut Action("revert!", |_args, _writer| {
+			self.staged.clone_from(self.current);
+		}).with_desc("Discards the staged changes."));
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Property node which runs a callback after its value changes.
+///
+/// Wraps a property and, after every successful set, calls the callback with the old and the new value and the writer.
+/// Use it for side effects such as reconnecting a socket or resizing a buffer when the property changes.
+/// The callback also runs after the property is reset, with a writer which discards its output.
+///
+/// The type of the values is given by the callback and must be the value type of the property, eg. [`FloatValue<f32>`](FloatValue) for a [`FloatProp`](struct@FloatProp).
+/// If the types differ the callback cannot be called and every change is refused with a `mismatched types` error.
+///
+/// ```
+/// let mut buffer_size: usize = 1024;
+/// let mut buffer = vec![0u8; 1024];
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ApplyProp(cvar::Property("buffer_size", &mut buffer_size, &1024), |old: &usize, new: &usize, writer| {
+/// 		buffer.resize(*new, 0);
+/// 		let _ = write!(writer, "resized from {old} to {new}");
+/// 	}));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(cvar::console::set(&mut root, "buffer_size", "4096", &mut writer));
+/// assert_eq!(writer, "buffer_size: resized from 1024 to 4096\n");
+/// # drop(root);
+/// # assert_eq!(buffer.len(), 4096);
+/// ```
+pub struct ApplyProp<P, T, F> {
+	inner: P,
+	on_apply: F,
+	_value: PhantomData<fn(&T)>,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn ApplyProp<P, T, F>(inner: P, on_apply: F) -> ApplyProp<P, T, F>
+	where P: IProperty, T: Clone + 'static, F: FnMut(&T, &T, &mut dyn IWrite)
+{
+	ApplyProp { inner, on_apply, _value: PhantomData }
+}
+
+impl<P, T, F> ApplyProp<P, T, F>
+	where P: IProperty, T: Clone + 'static, F: FnMut(&T, &T, &mut dyn IWrite)
+{
+	#[inline]
+	pub fn new(inner: P, on_apply: F) -> ApplyProp<P, T, F> {
+		ApplyProp { inner, on_apply, _value: PhantomData }
+	}
+
+	// Runs the change and calls the callback if it succeeded
+	fn apply(&mut self, writer: &mut dyn IWrite, change: impl FnOnce(&mut P, &mut dyn IWrite) -> bool) -> bool {
+		// Refuse the change rather than silently skipping the callback
+		let Some(old) = self.inner.get_value().downcast_ref::<T>().cloned() else {
+			let _ = write!(writer, "mismatched types: expected `{}`", any::type_name::<T>());
+			return false;
+		};
+		if !change(&mut self.inner, writer) {
+			return false;
+		}
+		if let Some(new) = self.inner.get_value().downcast_ref::<T>() {
+			(self.on_apply)(&old, new, writer);
+		}
+		true
+	}
+}
+
+impl<P, T, F> INode for ApplyProp<P, T, F>
+	where P: IProperty, T: Clone + 'static, F: FnMut(&T, &T, &mut dyn IWrite)
+{
+	fn name(&self) -> &str {
+		self.inner.name()
+	}
+
+	fn description(&self) -> &str {
+		self.inner.description()
+	}
+
+	fn label(&self) -> &str {
+		self.inner.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.inner.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.inner.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<P, T, F> IProperty for ApplyProp<P, T, F>
+	where P: IProperty, T: Clone + 'static, F: FnMut(&T, &T, &mut dyn IWrite)
+{
+	fn get_value(&self) -> &dyn IValue {
+		self.inner.get_value()
+	}
+
+	fn persist_value(&self) -> &dyn IValue {
+		self.inner.persist_value()
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		self.apply(writer, |inner, writer| inner.set_value(val, writer))
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.apply(writer, |inner, writer| inner.set(val, writer))
+	}
+
+	fn set_layer(&mut self, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.apply(writer, |inner, writer| inner.set_layer(layer, val, writer))
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.inner.validate(val, writer)
+	}
+
+	fn reset(&mut self) {
+		self.apply(&mut NullWriter, |inner, _writer| { inner.reset(); true });
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.inner.default_value()
+	}
+
+	fn set_default(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.inner.set_default(val, writer)
+	}
+
+	fn state(&self) -> PropState {
+		self.inner.state()
+	}
+
+	fn flags(&self) -> u32 {
+		self.inner.flags()
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.inner.epsilon()
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.inner.min_value()
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.inner.max_value()
+	}
+
+	fn provenance(&self) -> Layer {
+		self.inner.provenance()
+	}
+
+	#[cfg(feature = "type_name")]
+	fn type_name(&self) -> &str {
+		self.inner.type_name()
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		self.inner.values()
+	}
+}
@@ -0,0 +1,232 @@
+use super::*;
+
+/// Bit flags property node with named bits.
+///
+/// The names are given in bit order, the first name is bit 0.
+/// The value is displayed as the names of the set bits joined by `|`, bits without a name are displayed as a hex number and no bits as `0`.
+///
+/// Parses names and numbers separated by `|` or whitespace.
+/// If the first one starts with `+` or `-` the bits are set or cleared in the current value, otherwise the bits are combined into a new value.
+/// The names are the [valid values](IProperty::values) of the property.
+///
+/// ```
+/// let mut filters = 0b011;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::FlagsProp("log.filters", &mut filters, &0, &["io", "net", "gfx"]));
+/// });
+///
+/// assert_eq!(cvar::console::get(&mut root, "log.filters").as_deref(), Some("io|net"));
+/// assert!(cvar::console::set_silent(&mut root, "log.filters", "+gfx -net"));
+/// assert_eq!(cvar::console::get(&mut root, "log.filters").as_deref(), Some("io|gfx"));
+/// assert!(cvar::console::set_silent(&mut root, "log.filters", "net|0x100"));
+/// assert_eq!(cvar::console::get(&mut root, "log.filters").as_deref(), Some("net|0x100"));
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut root, "log.filters", "audio", &mut writer));
+/// assert_eq!(writer, "error: log.filters \"audio\": unknown flag: audio\n");
+/// ```
+pub struct FlagsProp<'a, 'x> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	variable: &'x mut u32,
+	default: &'a u32,
+	names: &'a [&'a str],
+	// The displayed value and default value
+	display: String,
+	default_display: String,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn FlagsProp<'a, 'x>(name: &'a str, variable: &'x mut u32, default: &'a u32, names: &'a [&'a str]) -> FlagsProp<'a, 'x> {
+	FlagsProp::new(name, variable, default, names)
+}
+
+impl<'a, 'x> FlagsProp<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut u32, default: &'a u32, names: &'a [&'a str]) -> FlagsProp<'a, 'x> {
+		let display = format_flags(*variable, names);
+		let default_display = format_flags(*default, names);
+		FlagsProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default, names, display, default_display }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+
+	fn store(&mut self, val: u32) {
+		*self.variable = val;
+		self.display = format_flags(val, self.names);
+	}
+
+	fn parse(&self, val: &str, writer: &mut dyn IWrite) -> Option<u32> {
+		let mut tokens = val.split(|chr: char| chr == '|' || chr.is_whitespace()).filter(|token| !token.is_empty()).peekable();
+		let relative = tokens.peek().is_some_and(|token| token.starts_with(['+', '-']));
+		let mut value = if relative { *self.variable } else { 0 };
+		for token in tokens {
+			let (clear, name) = match token.as_bytes()[0] {
+				b'+' => (false, &token[1..]),
+				b'-' => (true, &token[1..]),
+				_ => (false, token),
+			};
+			let bits = match self.names.iter().take(32).position(|&flag| flag == name) {
+				Some(bit) => 1 << bit,
+				None => match name.parse::<HexValue<u32>>() {
+					Ok(HexValue(bits)) => bits,
+					Err(_) => {
+						let _ = write!(writer, "unknown flag: {name}");
+						return None;
+					},
+				},
+			};
+			if clear {
+				value &= !bits;
+			}
+			else {
+				value |= bits;
+			}
+		}
+		Some(value)
+	}
+}
+
+// Formats the names of the set bits joined by `|`
+fn format_flags(value: u32, names: &[&str]) -> String {
+	let mut result = String::new();
+	let mut unnamed = value;
+	for (bit, name) in names.iter().enumerate().take(32) {
+		if value & (1 << bit) != 0 {
+			if !result.is_empty() {
+				result.push('|');
+			}
+			result.push_str(name);
+			unnamed &= !(1 << bit);
+		}
+	}
+	if unnamed != 0 {
+		if !result.is_empty() {
+			result.push('|');
+		}
+		result.push_str(&format!("{unnamed:#x}"));
+	}
+	if result.is_empty() {
+		result.push('0');
+	}
+	result
+}
+
+impl<'a, 'x> INode for FlagsProp<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IProperty for FlagsProp<'a, 'x> {
+	fn get_value(&self) -> &dyn IValue {
+		&self.display
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(&val) = val.downcast_ref::<u32>() {
+			self.store(val);
+			true
+		}
+		else {
+			let _ = write_mismatched_types::<u32>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match self.parse(val, writer) {
+			Some(val) => {
+				self.store(val);
+				true
+			},
+			None => false,
+		}
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.parse(val, writer).is_some()
+	}
+
+	fn reset(&mut self) {
+		self.store(*self.default);
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		&self.default_display
+	}
+
+	fn state(&self) -> PropState {
+		match *self.variable == *self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		Some(self.names)
+	}
+}
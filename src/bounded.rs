@@ -0,0 +1,172 @@
+use super::*;
+
+/// String property node with validation.
+///
+/// Rejects values longer than the maximum number of characters and, if a character set is given, values containing characters outside it.
+/// Use for strings which end up in filenames, URLs or fixed size buffers.
+///
+/// ```
+/// let mut name = String::from("Player");
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::BoundedStringProp("name", &mut name, &String::from("Player"), 8)
+/// 		.with_charset(|chr| chr.is_ascii_alphanumeric() || chr == '_'));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut root, "name", "../etc", &mut writer));
+/// assert!(!cvar::console::set(&mut root, "name", "Player_One", &mut writer));
+/// assert_eq!(writer, "error: name \"../etc\": invalid character '.' at position 0\nerror: name \"Player_One\": too long: 10 characters, at most 8 allowed\n");
+/// assert!(cvar::console::set(&mut root, "name", "Player_1", &mut writer));
+/// ```
+pub struct BoundedStringProp<'a, 'x> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	variable: &'x mut String,
+	default: &'a String,
+	max_len: usize,
+	charset: Option<fn(char) -> bool>,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn BoundedStringProp<'a, 'x>(name: &'a str, variable: &'x mut String, default: &'a String, max_len: usize) -> BoundedStringProp<'a, 'x> {
+	BoundedStringProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default, max_len, charset: None }
+}
+
+impl<'a, 'x> BoundedStringProp<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut String, default: &'a String, max_len: usize) -> BoundedStringProp<'a, 'x> {
+		BoundedStringProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default, max_len, charset: None }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+
+	/// Sets the allowed characters.
+	#[inline]
+	pub fn with_charset(self, charset: fn(char) -> bool) -> Self {
+		Self { charset: Some(charset), ..self }
+	}
+}
+
+impl<'a, 'x> INode for BoundedStringProp<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IProperty for BoundedStringProp<'a, 'x> {
+	fn get_value(&self) -> &dyn IValue {
+		&*self.variable
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(val) = val.downcast_ref::<String>() {
+			self.set(val, writer)
+		}
+		else {
+			let _ = write_mismatched_types::<String>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		if !self.validate(val, writer) {
+			return false;
+		}
+		self.variable.clear();
+		self.variable.push_str(val);
+		true
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		let len = val.chars().count();
+		if len > self.max_len {
+			let _ = write!(writer, "too long: {len} characters, at most {} allowed", self.max_len);
+			return false;
+		}
+		if let Some(charset) = self.charset {
+			if let Some((pos, chr)) = val.chars().enumerate().find(|&(_, chr)| !charset(chr)) {
+				let _ = write!(writer, "invalid character {chr:?} at position {pos}");
+				return false;
+			}
+		}
+		true
+	}
+
+	fn reset(&mut self) {
+		self.variable.clone_from(self.default);
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.default
+	}
+
+	fn state(&self) -> PropState {
+		match *self.variable == *self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+}
@@ -0,0 +1,259 @@
+/*!
+Visitors for collections of visitors.
+
+Every element is exposed as a child list, named by its index for sequences and by its key for maps.
+Sequences are visited in order and maps sorted by key, so the visitation order only depends on the contents and not on hashing.
+
+```
+struct Weapon {
+	damage: i32,
+}
+
+impl cvar::IVisit for Weapon {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn cvar::INode)) {
+		f(&mut cvar::Property("damage", &mut self.damage, &10));
+	}
+}
+
+let mut worlds = vec![Weapon { damage: 10 }, Weapon { damage: 20 }];
+let mut weapons = std::collections::BTreeMap::new();
+weapons.insert(String::from("rifle"), Weapon { damage: 30 });
+
+let mut root = cvar::Visit(|f| {
+	f(&mut cvar::List("worlds", &mut worlds));
+	f(&mut cvar::List("weapons", &mut weapons));
+});
+
+assert_eq!(cvar::console::get(&mut root, "worlds.1.damage").as_deref(), Some("20"));
+assert!(cvar::console::set_silent(&mut root, "weapons.rifle.damage", "35"));
+# drop(root);
+assert_eq!(weapons["rifle"].damage, 35);
+```
+*/
+
+use std::collections::{BTreeMap, HashMap};
+use super::*;
+
+impl<T: IVisit> IVisit for [T] {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		for (index, item) in self.iter_mut().enumerate() {
+			let name = index.to_string();
+			f(&mut List(&name, item));
+		}
+	}
+}
+
+impl<T: IVisit> IVisit for Vec<T> {
+	#[inline]
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		self.as_mut_slice().visit(f)
+	}
+}
+
+impl<T: IVisit> IVisit for BTreeMap<String, T> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		for (key, item) in self.iter_mut() {
+			f(&mut List(key, item));
+		}
+	}
+}
+
+/// The entries are visited sorted by key so the visitation order is stable.
+impl<T: IVisit, S> IVisit for HashMap<String, T, S> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let mut entries: Vec<(&String, &mut T)> = self.iter_mut().collect();
+		entries.sort_by(|a, b| a.0.cmp(b.0));
+		for (key, item) in entries {
+			f(&mut List(key, item));
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Visitor for maps of values.
+///
+/// Every entry is exposed as a property named by its key, the entries are visited sorted by key.
+/// With actions enabled the `create!` action inserts an entry and the `remove!` action removes one.
+///
+/// ```
+/// let mut binds = std::collections::BTreeMap::new();
+/// binds.insert(String::from("w"), String::from("forward"));
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("binds", &mut cvar::MapProps(&mut binds, &String::new()).with_actions(true)));
+/// });
+///
+/// let mut writer = String::new();
+/// cvar::console::invoke(&mut root, "binds.create!", "space jump", &mut writer);
+/// cvar::console::invoke(&mut root, "binds.remove!", "w", &mut writer);
+/// assert_eq!(cvar::console::get(&mut root, "binds.space").as_deref(), Some("jump"));
+/// assert!(cvar::console::set_silent(&mut root, "binds.space", "crouch"));
+///
+/// assert!(!cvar::console::invoke(&mut root, "binds.remove!", "w", &mut writer));
+/// assert_eq!(writer, "error: binds.remove!: unknown key: w\n");
+/// # drop(root);
+/// assert_eq!(binds.len(), 1);
+/// ```
+pub struct MapProps<'a, 'x, M, T: 'static> {
+	map: &'x mut M,
+	default: &'a T,
+	actions: bool,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn MapProps<'a, 'x, M, T>(map: &'x mut M, default: &'a T) -> MapProps<'a, 'x, M, T> {
+	MapProps { map, default, actions: false }
+}
+
+impl<'a, 'x, M, T> MapProps<'a, 'x, M, T> {
+	#[inline]
+	pub fn new(map: &'x mut M, default: &'a T) -> MapProps<'a, 'x, M, T> {
+		MapProps { map, default, actions: false }
+	}
+
+	/// Adds the `create!` and `remove!` actions.
+	///
+	/// `create! <key> [value]` inserts an entry with the value or the default value, `remove! <key>` removes the entry.
+	#[inline]
+	pub fn with_actions(self, actions: bool) -> Self {
+		Self { actions, ..self }
+	}
+}
+
+// Parses the arguments of the create action: <key> [value]
+fn parse_create<T>(args: &str, default: &T) -> Result<(String, T), Box<dyn StdError>>
+	where T: Clone + FromStr,
+	      T::Err: StdError
+{
+	let args = args.trim();
+	let (key, val) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+	if key.is_empty() {
+		return Err(Box::new(InvalidArgs));
+	}
+	if key.contains('.') || !is_valid_name(key) {
+		return Err(format!("invalid key: {key:?}").into());
+	}
+	let val = val.trim_start();
+	let value = if val.is_empty() {
+		default.clone()
+	}
+	else {
+		val.parse().map_err(|err| format!("invalid value {val:?}: {err}"))?
+	};
+	Ok((key.to_string(), value))
+}
+
+impl<'a, 'x, T> IVisit for MapProps<'a, 'x, BTreeMap<String, T>, T>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		use std::collections::btree_map::Entry;
+		for (key, value) in self.map.iter_mut() {
+			f(&mut Property(key, value, self.default));
+		}
+		if self.actions {
+			f(&mut Action("create!", |args, _writer| -> BoxResult {
+				let (key, value) = parse_create(args, self.default)?;
+				match self.map.entry(key) {
+					Entry::Occupied(entry) => Err(format!("key already exists: {}", entry.key()).into()),
+					Entry::Vacant(entry) => {
+						entry.insert(value);
+						Ok(())
+					},
+				}
+			}).with_desc("Creates an entry.").with_usage("<key> [value]"));
+			f(&mut Action("remove!", |args, _writer| -> BoxResult {
+				let key = args.trim();
+				match self.map.remove(key) {
+					Some(_) => Ok(()),
+					None => Err(format!("unknown key: {key}").into()),
+				}
+			}).with_desc("Removes an entry.").with_usage("<key>"));
+		}
+	}
+}
+
+impl<'a, 'x, T, S> IVisit for MapProps<'a, 'x, HashMap<String, T, S>, T>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static,
+	      S: std::hash::BuildHasher
+{
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		use std::collections::hash_map::Entry;
+		let mut entries: Vec<(&String, &mut T)> = self.map.iter_mut().collect();
+		entries.sort_by(|a, b| a.0.cmp(b.0));
+		for (key, value) in entries {
+			f(&mut Property(key, value, self.default));
+		}
+		if self.actions {
+			f(&mut Action("create!", |args, _writer| -> BoxResult {
+				let (key, value) = parse_create(args, self.default)?;
+				match self.map.entry(key) {
+					Entry::Occupied(entry) => Err(format!("key already exists: {}", entry.key()).into()),
+					Entry::Vacant(entry) => {
+						entry.insert(value);
+						Ok(())
+					},
+				}
+			}).with_desc("Creates an entry.").with_usage("<key> [value]"));
+			f(&mut Action("remove!", |args, _writer| -> BoxResult {
+				let key = args.trim();
+				match self.map.remove(key) {
+					Some(_) => Ok(()),
+					None => Err(format!("unknown key: {key}").into()),
+				}
+			}).with_desc("Removes an entry.").with_usage("<key>"));
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Visitor for fixed-size arrays of values.
+///
+/// Every item is exposed as a property named by its index, with the item of the default array as its default value.
+/// See [`ArrayValue`] to expose the array as a single property instead.
+///
+/// ```
+/// let mut color = [255u8, 128, 0];
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("color", &mut cvar::ArrayProps(&mut color, &[255, 255, 255])));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "color.2", "64"));
+/// assert_eq!(cvar::console::get(&mut root, "color.1").as_deref(), Some("128"));
+/// # drop(root);
+/// assert_eq!(color, [255, 128, 64]);
+/// ```
+pub struct ArrayProps<'a, 'x, T: 'static, const N: usize> {
+	array: &'x mut [T; N],
+	default: &'a [T; N],
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn ArrayProps<'a, 'x, T, const N: usize>(array: &'x mut [T; N], default: &'a [T; N]) -> ArrayProps<'a, 'x, T, N> {
+	ArrayProps { array, default }
+}
+
+impl<'a, 'x, T, const N: usize> ArrayProps<'a, 'x, T, N> {
+	#[inline]
+	pub fn new(array: &'x mut [T; N], default: &'a [T; N]) -> ArrayProps<'a, 'x, T, N> {
+		ArrayProps { array, default }
+	}
+}
+
+impl<'a, 'x, T, const N: usize> IVisit for ArrayProps<'a, 'x, T, N>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		for (index, (value, default)) in self.array.iter_mut().zip(self.default).enumerate() {
+			let name = index.to_string();
+			f(&mut Property(&name, value, default));
+		}
+	}
+}
@@ -0,0 +1,471 @@
+/*!
+Standard console commands.
+
+These action nodes operate on a visitor they borrow for the duration of the visit.
+Mount them next to the tree they operate on:
+
+```
+struct Settings {
+	volume: f32,
+}
+
+impl cvar::IVisit for Settings {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn cvar::INode)) {
+		f(&mut cvar::Property("volume", &mut self.volume, &1.0).with_desc("Master audio volume"));
+	}
+}
+
+struct Console {
+	settings: Settings,
+}
+
+impl cvar::IVisit for Console {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn cvar::INode)) {
+		self.settings.visit(f);
+		f(&mut cvar::commands::FindAction("find", &mut self.settings));
+	}
+}
+
+let mut console = Console { settings: Settings { volume: 0.5 } };
+let mut writer = String::new();
+cvar::console::invoke(&mut console, "find", "audio", &mut writer);
+assert_eq!(writer, "volume is \"0.5\" - Master audio volume\n");
+```
+*/
+
+use super::*;
+
+//----------------------------------------------------------------
+
+/// Find action.
+///
+/// Implements `find <text>`, searches the paths, descriptions and values of every node case-insensitively and prints the matches, see [`console::grep`].
+/// Hidden properties are not searched.
+#[derive(Debug)]
+pub struct FindAction<'a, 'x> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	root: &'x mut dyn IVisit,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn FindAction<'a, 'x>(name: &'a str, root: &'x mut dyn IVisit) -> FindAction<'a, 'x> {
+	FindAction { name, desc: "Finds nodes by path or description.", label: "", id: None, menu_hint: None, root }
+}
+
+impl<'a, 'x> FindAction<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, root: &'x mut dyn IVisit) -> FindAction<'a, 'x> {
+		FindAction { name, desc: "Finds nodes by path or description.", label: "", id: None, menu_hint: None, root }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+}
+
+impl<'a, 'x> INode for FindAction<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Action(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IAction for FindAction<'a, 'x> {
+	fn invoke(&mut self, args: &str, writer: &mut dyn IWrite) -> BoxResult {
+		let text = args.trim();
+		if text.is_empty() {
+			return Err(Box::new(InvalidArgs));
+		}
+		if !console::grep(self.root, text, writer) {
+			let _ = writeln!(writer, "no matches for {text:?}");
+		}
+		Ok(())
+	}
+
+	fn usage(&self) -> &str {
+		"<text>"
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Watched paths.
+///
+/// Managed by the [`WatchAction`], print the watched values after every console interaction with [`Watches::print`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Watches {
+	paths: Vec<String>,
+}
+
+impl Watches {
+	/// Creates an empty list of watches.
+	#[inline]
+	pub const fn new() -> Watches {
+		Watches { paths: Vec::new() }
+	}
+
+	/// Returns the watched paths in the order they were added.
+	#[inline]
+	pub fn paths(&self) -> &[String] {
+		&self.paths
+	}
+
+	/// Watches the path, returns `false` if it was already watched.
+	pub fn add(&mut self, path: &str) -> bool {
+		if self.paths.iter().any(|watch| watch == path) {
+			return false;
+		}
+		self.paths.push(path.to_string());
+		true
+	}
+
+	/// Stops watching the path, returns `false` if it was not watched.
+	pub fn remove(&mut self, path: &str) -> bool {
+		let len = self.paths.len();
+		self.paths.retain(|watch| watch != path);
+		self.paths.len() != len
+	}
+
+	/// Stops watching all paths.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.paths.clear();
+	}
+
+	/// Prints the current value of every watched path.
+	pub fn print(&self, root: &mut dyn IVisit, writer: &mut dyn IWrite) {
+		for path in &self.paths {
+			match console::get(root, path) {
+				Some(value) => { let _ = writeln!(writer, "{path} is {value:?}"); },
+				None => { let _ = writeln!(writer, "unknown: {path}"); },
+			}
+		}
+	}
+}
+
+/// Watch action.
+///
+/// Implements `watch add <path>`, `watch remove <path>`, `watch clear` and `watch print`, invoking without arguments prints the watches.
+/// Mimics the watch window of a debugger when the application prints the [`Watches`] after every console interaction.
+///
+/// ```
+/// let mut fov = 90;
+/// let mut watches = cvar::commands::Watches::new();
+/// let mut writer = String::new();
+///
+/// let mut settings = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("fov", &mut fov, &90));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("settings", &mut settings));
+/// 	f(&mut cvar::commands::WatchAction("watch", &mut settings, &mut watches));
+/// });
+/// cvar::console::invoke(&mut root, "watch", "add fov", &mut writer);
+/// cvar::console::set(&mut root, "settings.fov", "100", &mut writer);
+/// cvar::console::invoke(&mut root, "watch", "", &mut writer);
+/// assert_eq!(writer, "fov is \"100\"\n");
+/// assert_eq!(cvar::console::complete(&mut root, "watch r"), ["watch remove"]);
+/// assert_eq!(cvar::console::complete(&mut root, "watch remove f"), ["watch remove fov"]);
+/// ```
+#[derive(Debug)]
+pub struct WatchAction<'a, 'x> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	root: &'x mut dyn IVisit,
+	watches: &'x mut Watches,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn WatchAction<'a, 'x>(name: &'a str, root: &'x mut dyn IVisit, watches: &'x mut Watches) -> WatchAction<'a, 'x> {
+	WatchAction { name, desc: "Watches the values of properties.", label: "", id: None, menu_hint: None, root, watches }
+}
+
+impl<'a, 'x> WatchAction<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, root: &'x mut dyn IVisit, watches: &'x mut Watches) -> WatchAction<'a, 'x> {
+		WatchAction { name, desc: "Watches the values of properties.", label: "", id: None, menu_hint: None, root, watches }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+}
+
+impl<'a, 'x> INode for WatchAction<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Action(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IAction for WatchAction<'a, 'x> {
+	fn invoke(&mut self, args: &str, writer: &mut dyn IWrite) -> BoxResult {
+		let args = args.trim();
+		let (cmd, path) = args.split_once(char::is_whitespace).map(|(cmd, path)| (cmd, path.trim())).unwrap_or((args, ""));
+		match (cmd, path) {
+			("add", path) if !path.is_empty() => {
+				if !console::find(self.root, path, |_node| ()) {
+					return Err(format!("unknown: {path}").into());
+				}
+				if !self.watches.add(path) {
+					return Err(format!("already watching {path}").into());
+				}
+			},
+			("remove", path) if !path.is_empty() => {
+				if !self.watches.remove(path) {
+					return Err(format!("not watching {path}").into());
+				}
+			},
+			("clear", "") => self.watches.clear(),
+			("print", "") | ("", "") => self.watches.print(self.root, writer),
+			_ => return Err(Box::new(InvalidArgs)),
+		}
+		Ok(())
+	}
+
+	fn usage(&self) -> &str {
+		"add <path> | remove <path> | clear | print"
+	}
+
+	fn complete(&mut self, partial_args: &str) -> Vec<String> {
+		match partial_args.split_once(' ') {
+			Some(("add", path)) => console::complete(self.root, path).into_iter().map(|path| format!("add {path}")).collect(),
+			Some(("remove", path)) => self.watches.paths.iter().filter(|watch| watch.starts_with(path)).map(|watch| format!("remove {watch}")).collect(),
+			Some(_) => Vec::new(),
+			None => ["add", "clear", "print", "remove"].iter().filter(|cmd| cmd.starts_with(partial_args)).map(|cmd| cmd.to_string()).collect(),
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Toggle action.
+///
+/// Toggles the property at the path when invoked, see [`console::toggle`].
+/// The path is resolved every time the action is invoked, bind keys and menu buttons to it without reading and writing the property yourself.
+///
+/// ```
+/// use cvar::IVisit;
+///
+/// let mut fullscreen = false;
+/// let mut video = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("fullscreen", &mut fullscreen, &false));
+/// });
+/// let mut settings = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("video", &mut video));
+/// });
+///
+/// let mut writer = String::new();
+/// let mut root = cvar::Visit(|f| {
+/// 	settings.visit(f);
+/// 	f(&mut cvar::commands::ToggleAction("fullscreen!", &mut settings, "video.fullscreen"));
+/// });
+/// assert!(cvar::console::invoke(&mut root, "fullscreen!", "", &mut writer));
+/// assert_eq!(writer, "video.fullscreen is \"true\"\n");
+/// # drop(root);
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::commands::ToggleAction("fullscreen!", &mut settings, "video"));
+/// });
+/// writer.clear();
+/// assert!(!cvar::console::invoke(&mut root, "fullscreen!", "", &mut writer));
+/// assert_eq!(writer, "error: fullscreen!: video: cannot toggle\n");
+/// ```
+#[derive(Debug)]
+pub struct ToggleAction<'a, 'x> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	root: &'x mut dyn IVisit,
+	path: &'a str,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn ToggleAction<'a, 'x>(name: &'a str, root: &'x mut dyn IVisit, path: &'a str) -> ToggleAction<'a, 'x> {
+	ToggleAction { name, desc: "Toggles a property.", label: "", id: None, menu_hint: None, root, path }
+}
+
+impl<'a, 'x> ToggleAction<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, root: &'x mut dyn IVisit, path: &'a str) -> ToggleAction<'a, 'x> {
+		ToggleAction { name, desc: "Toggles a property.", label: "", id: None, menu_hint: None, root, path }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+}
+
+impl<'a, 'x> INode for ToggleAction<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Action(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IAction for ToggleAction<'a, 'x> {
+	fn invoke(&mut self, _args: &str, writer: &mut dyn IWrite) -> BoxResult {
+		let path = self.path;
+		let mut result: BoxResult = Err(format!("{path}: cannot toggle").into());
+		let found = console::find(self.root, path, |node| {
+			if let Node::Prop(prop) = node.as_node() {
+				let mut err = String::new();
+				result = if console::toggle_prop(prop, &mut err) {
+					let value = prop.get_value().to_string();
+					// cvar.prop is "true"
+					let _ = writeln!(writer, "{path} is {value:?}");
+					console::write_note(path, &err, writer);
+					Ok(())
+				}
+				else {
+					Err(format!("{path}: {err}").into())
+				};
+			}
+		});
+		if !found {
+			return Err(format!("unknown: {path}").into());
+		}
+		result
+	}
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::ops;
+use super::*;
+
+/// Prefix index for path completion.
+///
+/// Completing paths with [`console::complete`] walks the entire tree for every query.
+/// For very large trees this index stores all the paths in a trie instead.
+///
+/// The tree has no notifications for nodes being created or destroyed, the host is responsible for keeping the index up to date:
+/// [`insert_node`](Self::insert_node) and [`remove`](Self::remove) nodes as they are attached to and detached from the tree, eg. a [`DynamicList`],
+/// or [`invalidate`](Self::invalidate) the index and [`rebuild`](Self::rebuild) it when convenient.
+///
+/// ```
+/// let mut width = 1920;
+/// let mut height = 1080;
+/// let mut video = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("width", &mut width, &1920));
+/// 	f(&mut cvar::Property("height", &mut height, &1080));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("video", &mut video));
+/// });
+///
+/// let mut index = cvar::CompletionIndex::new();
+/// index.rebuild(&mut root);
+/// assert_eq!(index.complete("video.h"), ["video.height"]);
+/// assert_eq!(index.complete("video."), ["video.height", "video.width"]);
+///
+/// index.remove("video.height");
+/// assert_eq!(index.complete("video."), ["video.width"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CompletionIndex {
+	root: Trie,
+	valid: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Trie {
+	children: BTreeMap<String, Trie>,
+	// This path exists as a node, parts may exist only as a prefix of a node name containing `.`
+	node: bool,
+}
+
+impl CompletionIndex {
+	/// Creates a new empty index.
+	#[inline]
+	pub fn new() -> CompletionIndex {
+		CompletionIndex { root: Trie::default(), valid: true }
+	}
+
+	/// Rebuilds the index by walking the tree.
+	pub fn rebuild(&mut self, root: &mut dyn IVisit) {
+		self.root = Trie::default();
+		console::walk(root, |path, _node| self.insert(path));
+		self.valid = true;
+	}
+
+	/// Rebuilds the index only if it has been invalidated.
+	#[inline]
+	pub fn update(&mut self, root: &mut dyn IVisit) {
+		if !self.valid {
+			self.rebuild(root);
+		}
+	}
+
+	/// Marks the index as out of date.
+	///
+	/// The index keeps answering queries with the stale paths until it is rebuilt.
+	#[inline]
+	pub fn invalidate(&mut self) {
+		self.valid = false;
+	}
+
+	/// Returns `false` if the index has been invalidated since it was last rebuilt.
+	#[inline]
+	pub fn is_valid(&self) -> bool {
+		self.valid
+	}
+
+	/// Adds a path to the index.
+	pub fn insert(&mut self, path: &str) {
+		let mut trie = &mut self.root;
+		for part in path.split('.') {
+			trie = trie.children.entry(part.to_string()).or_default();
+		}
+		trie.node = true;
+	}
+
+	/// Adds the node at the path and every node nested under it to the index.
+	///
+	/// ```
+	/// let mut plugins = cvar::DynamicList::new();
+	/// let mut index = cvar::CompletionIndex::new();
+	/// index.rebuild(&mut cvar::Visit(|f| f(&mut cvar::List("plugins", &mut plugins))));
+	///
+	/// let mut physics = cvar::DynamicList::new();
+	/// physics.insert("gravity", Box::new(cvar::OwnedProp(String::from("gravity"), 9.81, 9.81)));
+	/// plugins.insert("physics", Box::new(cvar::OwnedList(String::from("physics"), physics)));
+	/// index.insert_node("plugins.physics", plugins.get_mut("physics").unwrap());
+	/// assert_eq!(index.complete("plugins.physics.g"), ["plugins.physics.gravity"]);
+	/// ```
+	pub fn insert_node(&mut self, path: &str, node: &mut dyn INode) {
+		self.insert(path);
+		if let Node::List(list) = node.as_node() {
+			console::walk(list.as_ivisit(), |child, _node| self.insert(&format!("{path}.{child}")));
+		}
+	}
+
+	/// Removes a path and all the paths nested under it from the index.
+	pub fn remove(&mut self, path: &str) {
+		fn remove_rec(trie: &mut Trie, path: &str) {
+			let (part, rest) = match path.split_once('.') {
+				Some((part, rest)) => (part, Some(rest)),
+				None => (path, None),
+			};
+			if let Some(rest) = rest {
+				if let Some(child) = trie.children.get_mut(part) {
+					remove_rec(child, rest);
+					if !child.node && child.children.is_empty() {
+						trie.children.remove(part);
+					}
+				}
+			}
+			else {
+				trie.children.remove(part);
+			}
+		}
+		remove_rec(&mut self.root, path);
+	}
+
+	/// Completes a partial path.
+	///
+	/// Completes paths like [`console::complete`] without walking the tree, the arguments of actions are not completed.
+	pub fn complete(&self, partial: &str) -> Vec<String> {
+		let (parent, part) = match partial.rfind('.') {
+			Some(index) => (&partial[..index + 1], &partial[index + 1..]),
+			None => ("", partial),
+		};
+		let mut trie = &self.root;
+		if !parent.is_empty() {
+			for name in parent[..parent.len() - 1].split('.') {
+				match trie.children.get(name) {
+					Some(child) => trie = child,
+					None => return Vec::new(),
+				}
+			}
+		}
+		trie.children.range::<str, _>((ops::Bound::Included(part), ops::Bound::Unbounded))
+			.take_while(|(name, _)| name.starts_with(part))
+			.map(|(name, _)| format!("{parent}{name}"))
+			.collect()
+	}
+}
@@ -0,0 +1,430 @@
+use super::*;
+
+/// Node visitor which hides properties based on the values of other properties.
+///
+/// Wraps a visitor and adds the [`HIDDEN`](flags::HIDDEN) flag to the properties whose condition is false.
+/// The table maps the path of a node relative to the wrapped visitor to its condition, a condition on a list node applies to all the properties in the list.
+/// Hidden properties can still be set, they are only left out of listings.
+///
+/// Conditions are evaluated against the wrapped visitor every time it is visited, see [`eval_condition`] for the syntax.
+/// Conditions which fail to evaluate leave the nodes visible.
+///
+/// ```
+/// let mut api = String::from("opengl");
+/// let mut async_compute = false;
+/// let mut video = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("api", &mut api, &String::from("opengl")));
+/// 	f(&mut cvar::Property("async_compute", &mut async_compute, &false));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("video", &mut video));
+/// });
+/// let mut root = cvar::Conditional(&mut root, &[("video.async_compute", "video.api == vulkan")]);
+///
+/// let mut writer = String::new();
+/// cvar::console::poke(&mut root, "video", None, &mut writer);
+/// assert_eq!(writer, "video.api is \"opengl\"\n");
+///
+/// writer.clear();
+/// cvar::console::set_silent(&mut root, "video.api", "vulkan");
+/// cvar::console::poke(&mut root, "video", None, &mut writer);
+/// assert_eq!(writer, "video.api is \"vulkan\"\nvideo.async_compute is \"false\"\n");
+/// ```
+pub struct Conditional<'a, 'x> {
+	root: &'x mut dyn IVisit,
+	conditions: &'a [(&'a str, &'a str)],
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn Conditional<'a, 'x>(root: &'x mut dyn IVisit, conditions: &'a [(&'a str, &'a str)]) -> Conditional<'a, 'x> {
+	Conditional { root, conditions }
+}
+
+impl<'a, 'x> Conditional<'a, 'x> {
+	#[inline]
+	pub fn new(root: &'x mut dyn IVisit, conditions: &'a [(&'a str, &'a str)]) -> Conditional<'a, 'x> {
+		Conditional { root, conditions }
+	}
+}
+
+impl<'a, 'x> fmt::Debug for Conditional<'a, 'x> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Conditional")
+			.field("conditions", &self.conditions)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a, 'x> IVisit for Conditional<'a, 'x> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let mut hidden = Vec::new();
+		for &(path, condition) in self.conditions {
+			if eval_condition(self.root, condition) == Ok(false) {
+				hidden.push(path);
+			}
+		}
+		visit_conditional(self.root, &mut String::new(), &hidden, false, f);
+	}
+}
+
+fn visit_conditional(root: &mut dyn IVisit, path: &mut String, hidden: &[&str], parent_hidden: bool, f: &mut dyn FnMut(&mut dyn INode)) {
+	root.visit(&mut |node| {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(node.name());
+		let is_hidden = parent_hidden || hidden.contains(&path.as_str());
+		match node.as_node() {
+			Node::Prop(prop) => {
+				if is_hidden {
+					f(&mut HiddenProp { prop });
+				}
+				else {
+					f(prop.as_inode());
+				}
+			},
+			Node::List(list) => f(&mut ConditionalList { list, path, hidden, is_hidden }),
+			Node::Action(_) => f(node),
+		}
+		path.truncate(len);
+	});
+}
+
+struct HiddenProp<'a> {
+	prop: &'a mut dyn IProperty,
+}
+
+impl<'a> INode for HiddenProp<'a> {
+	fn name(&self) -> &str {
+		self.prop.name()
+	}
+
+	fn description(&self) -> &str {
+		self.prop.description()
+	}
+
+	fn label(&self) -> &str {
+		self.prop.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.prop.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.prop.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IProperty for HiddenProp<'a> {
+	fn get_value(&self) -> &dyn IValue {
+		self.prop.get_value()
+	}
+
+	fn persist_value(&self) -> &dyn IValue {
+		self.prop.persist_value()
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		self.prop.set_value(val, writer)
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.set(val, writer)
+	}
+
+	fn set_layer(&mut self, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.set_layer(layer, val, writer)
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.validate(val, writer)
+	}
+
+	fn reset(&mut self) {
+		self.prop.reset()
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.prop.default_value()
+	}
+
+	fn set_default(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.set_default(val, writer)
+	}
+
+	fn state(&self) -> PropState {
+		self.prop.state()
+	}
+
+	fn flags(&self) -> u32 {
+		self.prop.flags() | flags::HIDDEN
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.prop.epsilon()
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.prop.min_value()
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.prop.max_value()
+	}
+
+	fn provenance(&self) -> Layer {
+		self.prop.provenance()
+	}
+
+	#[cfg(feature = "type_name")]
+	fn type_name(&self) -> &str {
+		self.prop.type_name()
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		self.prop.values()
+	}
+}
+
+struct ConditionalList<'a> {
+	list: &'a mut dyn IList,
+	path: &'a str,
+	hidden: &'a [&'a str],
+	is_hidden: bool,
+}
+
+impl<'a> INode for ConditionalList<'a> {
+	fn name(&self) -> &str {
+		self.list.name()
+	}
+
+	fn description(&self) -> &str {
+		self.list.description()
+	}
+
+	fn label(&self) -> &str {
+		self.list.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.list.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.list.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IList for ConditionalList<'a> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a> IVisit for ConditionalList<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let mut path = self.path.to_string();
+		visit_conditional(self.list.as_ivisit(), &mut path, self.hidden, self.is_hidden, f);
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Evaluates a condition against the values of the properties.
+///
+/// The condition consists of comparisons joined by `&&` and `||`, where `&&` binds tighter.
+/// A comparison is a property path, optionally preceded by `!`, followed by an operator and a value: `path == value`.
+/// The operators are `==`, `!=`, `<`, `<=`, `>` and `>=`, values are compared as numbers if both parse as numbers.
+/// Values containing spaces or operators must be quoted with `"`.
+/// A path without an operator is true unless its value is empty, `0` or `false`.
+///
+/// Returns an error if the condition is malformed or a path does not lead to a property.
+///
+/// ```
+/// let mut developer = 1;
+/// let mut api = String::from("vulkan");
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("developer", &mut developer, &0));
+/// 	f(&mut cvar::Property("video.api", &mut api, &String::new()));
+/// });
+///
+/// assert_eq!(cvar::eval_condition(&mut root, "developer != 0 && video.api == vulkan"), Ok(true));
+/// assert_eq!(cvar::eval_condition(&mut root, "!developer || video.api == \"opengl\""), Ok(false));
+/// assert!(cvar::eval_condition(&mut root, "fps > 60").is_err());
+/// ```
+pub fn eval_condition(root: &mut dyn IVisit, condition: &str) -> Result<bool, String> {
+	let tokens = tokenize(condition)?;
+	let mut parser = Parser { root, tokens: &tokens, pos: 0 };
+	let result = parser.parse_or()?;
+	match parser.tokens.get(parser.pos) {
+		Some(token) => Err(format!("unexpected {token}")),
+		None => Ok(result),
+	}
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+	And,
+	Or,
+	Not,
+	Op(&'static str),
+	Word(String),
+}
+
+impl fmt::Display for Token {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Token::And => f.write_str("&&"),
+			Token::Or => f.write_str("||"),
+			Token::Not => f.write_str("!"),
+			Token::Op(op) => f.write_str(op),
+			Token::Word(word) => write!(f, "{word:?}"),
+		}
+	}
+}
+
+fn tokenize(condition: &str) -> Result<Vec<Token>, String> {
+	const OPS: [&str; 6] = ["==", "!=", "<=", ">=", "<", ">"];
+	let mut tokens = Vec::new();
+	let mut rest = condition.trim_start();
+	while !rest.is_empty() {
+		if let Some(tail) = rest.strip_prefix("&&") {
+			tokens.push(Token::And);
+			rest = tail;
+		}
+		else if let Some(tail) = rest.strip_prefix("||") {
+			tokens.push(Token::Or);
+			rest = tail;
+		}
+		else if let Some(op) = OPS.iter().find(|op| rest.starts_with(*op)) {
+			tokens.push(Token::Op(op));
+			rest = &rest[op.len()..];
+		}
+		else if let Some(tail) = rest.strip_prefix('!') {
+			tokens.push(Token::Not);
+			rest = tail;
+		}
+		else if let Some(tail) = rest.strip_prefix('"') {
+			let end = tail.find('"').ok_or_else(|| "unterminated string".to_string())?;
+			tokens.push(Token::Word(tail[..end].to_string()));
+			rest = &tail[end + 1..];
+		}
+		else {
+			let end = rest.find(|chr: char| chr.is_whitespace() || "&|!=<>\"".contains(chr)).unwrap_or(rest.len());
+			if end == 0 {
+				return Err(format!("unexpected {:?}", &rest[..1]));
+			}
+			tokens.push(Token::Word(rest[..end].to_string()));
+			rest = &rest[end..];
+		}
+		rest = rest.trim_start();
+	}
+	Ok(tokens)
+}
+
+struct Parser<'a> {
+	root: &'a mut dyn IVisit,
+	tokens: &'a [Token],
+	pos: usize,
+}
+
+impl<'a> Parser<'a> {
+	fn next(&mut self) -> Option<&'a Token> {
+		let token = self.tokens.get(self.pos)?;
+		self.pos += 1;
+		Some(token)
+	}
+
+	fn eat(&mut self, token: &Token) -> bool {
+		if self.tokens.get(self.pos) == Some(token) {
+			self.pos += 1;
+			true
+		}
+		else {
+			false
+		}
+	}
+
+	fn parse_or(&mut self) -> Result<bool, String> {
+		let mut result = self.parse_and()?;
+		while self.eat(&Token::Or) {
+			// Evaluate every comparison so malformed conditions are always reported
+			result |= self.parse_and()?;
+		}
+		Ok(result)
+	}
+
+	fn parse_and(&mut self) -> Result<bool, String> {
+		let mut result = self.parse_cmp()?;
+		while self.eat(&Token::And) {
+			result &= self.parse_cmp()?;
+		}
+		Ok(result)
+	}
+
+	fn parse_cmp(&mut self) -> Result<bool, String> {
+		let not = self.eat(&Token::Not);
+		let path = match self.next() {
+			Some(Token::Word(path)) => path,
+			Some(token) => return Err(format!("unexpected {token}")),
+			None => return Err("unexpected end of condition".to_string()),
+		};
+		let value = console::get(self.root, path).ok_or_else(|| format!("unknown: {path}"))?;
+		let result = match self.tokens.get(self.pos) {
+			Some(&Token::Op(op)) => {
+				self.pos += 1;
+				let rhs = match self.next() {
+					Some(Token::Word(rhs)) => rhs,
+					Some(token) => return Err(format!("unexpected {token}")),
+					None => return Err("unexpected end of condition".to_string()),
+				};
+				compare(&value, op, rhs)
+			},
+			_ => !matches!(value.as_str(), "" | "0" | "false"),
+		};
+		Ok(result != not)
+	}
+}
+
+fn compare(lhs: &str, op: &str, rhs: &str) -> bool {
+	let ordering = match (lhs.parse::<f64>(), rhs.parse::<f64>()) {
+		(Ok(lhs), Ok(rhs)) => lhs.partial_cmp(&rhs),
+		_ => Some(lhs.cmp(rhs)),
+	};
+	let Some(ordering) = ordering else { return op == "!=" };
+	match op {
+		"==" => ordering.is_eq(),
+		"!=" => ordering.is_ne(),
+		"<" => ordering.is_lt(),
+		"<=" => ordering.is_le(),
+		">" => ordering.is_gt(),
+		_ => ordering.is_ge(),
+	}
+}
+
+#[test]
+fn test_tokenize() {
+	assert_eq!(tokenize("a.b!=1&&!c"), Ok(vec![Token::Word("a.b".into()), Token::Op("!="), Token::Word("1".into()), Token::And, Token::Not, Token::Word("c".into())]));
+	assert_eq!(tokenize("name == \"a b\" || x"), Ok(vec![Token::Word("name".into()), Token::Op("=="), Token::Word("a b".into()), Token::Or, Token::Word("x".into())]));
+	assert!(tokenize("name == \"a").is_err());
+	assert!(tokenize("a & b").is_err());
+}
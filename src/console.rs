@@ -2,10 +2,13 @@
 Interact with the configuration variables.
 
 The design of this library makes recursive depth-first pre-order traversal the only feasable method to walk the cvars.
+The traversal order is the order in which the visitors visit their nodes, which is deterministic for all the visitors in this crate.
 
 This trade-off allows the hierarchy to be constructed lazily with very convenient stack-allocated resources.
 */
 
+use std::borrow::Cow;
+use std::ops::ControlFlow;
 use super::*;
 
 /// Pokes the cvar tree.
@@ -13,18 +16,24 @@ use super::*;
 /// Returns `false` if there was an error, the path does not exist or the args were not valid.
 ///
 /// This function combines the behavior of [`get`], [`set`], and [`invoke`].
+#[inline]
 pub fn poke(root: &mut dyn IVisit, path: &str, args: Option<&str>, writer: &mut dyn IWrite) -> bool {
+	_poke(root, path, args, &Options::DEFAULT, writer)
+}
+
+fn _poke(root: &mut dyn IVisit, path: &str, args: Option<&str>, opts: &Options, writer: &mut dyn IWrite) -> bool {
 	let mut result = false;
-	if path.len() > 0 {
-		if !find(root, path, |node| {
+	if !path.is_empty() {
+		let matches = find_opts(root, path, opts, &mut |node| {
 			match node.as_node() {
 				Node::Prop(prop) => {
 					if let Some(val) = args {
 						let mut err = String::new();
-						if prop.set(val, &mut err) {
+						if prop_set(prop, val, &mut err) {
 							let value = prop.get_value().to_string();
 							// cvar.prop is "true"
 							let _ = writeln!(writer, "{path} is {value:?}");
+							write_note(path, &err, writer);
 							result = true;
 						}
 						else {
@@ -34,41 +43,86 @@ pub fn poke(root: &mut dyn IVisit, path: &str, args: Option<&str>, writer: &mut
 					}
 					else {
 						let value = prop.get_value().to_string();
-						// cvar.prop is "true"
-						let _ = writeln!(writer, "{path} is {value:?}");
+						// cvar.prop is "true" [file]
+						let _ = write!(writer, "{path} is {value:?}");
+						let _ = write_provenance(prop, opts, writer);
+						let _ = writer.write_str("\n");
 						result = true;
 					}
 				},
 				Node::List(list) => {
-					_print_nodes(list.as_ivisit(), Some(path), writer);
+					_print_nodes(list.as_ivisit(), Some(path), opts, writer);
 					result = true;
 				},
 				Node::Action(act) => {
-					act.invoke(args.unwrap_or(""), writer);
-					result = true;
+					result = invoke_action(path, act, args.unwrap_or(""), writer);
 				},
 			}
-		}) {
-			let _ = writeln!(writer, "unknown: {path}");
-		}
+		});
+		write_matches(matches, opts.multi, path, writer);
 	}
 	else {
-		_print_nodes(root, None, writer);
+		_print_nodes(root, None, opts, writer);
 		result = true;
 	}
 	result
 }
 
-fn _print_node(node: &mut dyn INode, path: Option<&str>, writer: &mut dyn IWrite) -> fmt::Result {
+// Invokes the action and writes its error, returns false if it failed
+fn invoke_action(path: &str, act: &mut dyn IAction, args: &str, writer: &mut dyn IWrite) -> bool {
+	let Err(err) = act.invoke(args, writer) else {
+		return true;
+	};
+	let usage = act.usage();
+	if err.is::<InvalidArgs>() && !usage.is_empty() {
+		// usage: cvar.action <args>
+		let _ = writeln!(writer, "usage: {path} {usage}");
+	}
+	else {
+		// error: cvar.action: invalid arguments
+		let _ = writeln!(writer, "error: {path}: {err}");
+	}
+	false
+}
+
+// Properties may write a message even when successfully set
+pub(crate) fn write_note(path: &str, note: &str, writer: &mut dyn IWrite) {
+	if !note.is_empty() {
+		// cvar.prop: value will take effect after restart
+		let _ = writeln!(writer, "{path}: {note}");
+	}
+}
+
+// Sets the property while honoring the read-only flag
+fn prop_set(prop: &mut dyn IProperty, val: &str, writer: &mut dyn IWrite) -> bool {
+	prop_set_layer(prop, Layer::Runtime, val, writer)
+}
+fn prop_set_layer(prop: &mut dyn IProperty, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+	if prop.flags() & flags::READONLY != 0 {
+		let _ = writer.write_str("cannot set read-only property");
+		return false;
+	}
+	prop.set_layer(layer, val, writer)
+}
+fn prop_set_value(prop: &mut dyn IProperty, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+	if prop.flags() & flags::READONLY != 0 {
+		let _ = writer.write_str("cannot set read-only property");
+		return false;
+	}
+	prop.set_value(val, writer)
+}
+
+fn _print_node(node: &mut dyn INode, path: Option<&str>, opts: &Options, writer: &mut dyn IWrite) -> fmt::Result {
 	if let Some(path) = path {
 		writer.write_str(path)?;
 		writer.write_str(".")?;
 	}
 	match node.as_node() {
 		Node::Prop(prop) => {
-			let value = prop.get_value().to_string();
+			let value = display_value(prop);
 			let name = prop.name();
 			write!(writer, "{name} is {value:?}")?;
+			write_provenance(prop, opts, writer)?;
 		},
 		Node::List(list) => {
 			let name = list.name();
@@ -80,14 +134,47 @@ fn _print_node(node: &mut dyn INode, path: Option<&str>, writer: &mut dyn IWrite
 			writer.write_str(name)?;
 		},
 	}
+	// Show the display label if it is different from the name
+	let label = node.label();
+	if label != node.name() {
+		write!(writer, " ({label})")?;
+	}
 	writer.write_str("\n")?;
 	Ok(())
 }
-fn _print_nodes(root: &mut dyn IVisit, path: Option<&str>, writer: &mut dyn IWrite) {
+fn _print_nodes(root: &mut dyn IVisit, path: Option<&str>, opts: &Options, writer: &mut dyn IWrite) {
 	root.visit(&mut move |node| {
-		let _ = _print_node(node, path, writer);
+		if opts.show_hidden || !is_hidden(node) {
+			let _ = _print_node(node, path, opts, writer);
+		}
 	});
 }
+// Formats the value to print, secret values are masked
+fn display_value(prop: &dyn IProperty) -> String {
+	match prop.flags() & flags::SECRET != 0 {
+		true => SECRET_MASK.to_string(),
+		false => prop.get_value().to_string(),
+	}
+}
+// Writes where a non-default value came from if enabled in the options
+fn write_provenance(prop: &dyn IProperty, opts: &Options, writer: &mut dyn IWrite) -> fmt::Result {
+	if opts.show_provenance {
+		let layer = prop.provenance();
+		if layer != Layer::Default {
+			write!(writer, " [{layer}]")?;
+		}
+	}
+	Ok(())
+}
+
+/// Returns `true` if the node is a property with the [`HIDDEN`](flags::HIDDEN) flag.
+#[inline]
+pub fn is_hidden(node: &mut dyn INode) -> bool {
+	match node.as_node() {
+		Node::Prop(prop) => prop.flags() & flags::HIDDEN != 0,
+		_ => false,
+	}
+}
 
 //----------------------------------------------------------------
 
@@ -96,37 +183,89 @@ fn _print_nodes(root: &mut dyn IVisit, path: Option<&str>, writer: &mut dyn IWri
 /// If the path is an action it is invoked with the value as the argument.
 #[inline]
 pub fn set(root: &mut dyn IVisit, path: &str, val: &str, writer: &mut dyn IWrite) -> bool {
+	set_with(root, path, val, MultiMatch::All, writer)
+}
+
+/// Sets a property's value parsed from a string with control over duplicate paths.
+///
+/// See [`set`] and [`MultiMatch`] for more information.
+#[inline]
+pub fn set_with(root: &mut dyn IVisit, path: &str, val: &str, multi: MultiMatch, writer: &mut dyn IWrite) -> bool {
+	_set(root, path, val, &Options { multi, ..Options::DEFAULT }, writer)
+}
+
+fn _set(root: &mut dyn IVisit, path: &str, val: &str, opts: &Options, writer: &mut dyn IWrite) -> bool {
+	set_in_layer(root, path, Layer::Runtime, val, opts, writer)
+}
+
+fn set_in_layer(root: &mut dyn IVisit, path: &str, layer: Layer, val: &str, opts: &Options, writer: &mut dyn IWrite) -> bool {
 	let mut result = false;
-	if !find(root, path, |node| {
+	let matches = find_opts(root, path, opts, &mut |node| {
 		match node.as_node() {
-			Node::Prop(prop) => {
-				let mut err = String::new();
-				if prop.set(val, &mut err) {
-					result = true;
-				}
-				else {
-					// error: cvar.prop "true": not a number
-					let _ = writeln!(writer, "error: {path} {val:?}: {err}");
-				}
-			},
+			Node::Prop(prop) => result |= set_prop(path, prop, layer, val, writer),
 			Node::List(_) => {},
 			Node::Action(act) => {
-				act.invoke(val, writer);
+				invoke_action(path, act, val, writer);
 			},
 		}
-	}) {
-		let _ = writeln!(writer, "unknown: {path}");
+	});
+	write_matches(matches, opts.multi, path, writer);
+	result
+}
+
+// Sets the property in the layer and writes its note or error
+fn set_prop(path: &str, prop: &mut dyn IProperty, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+	let mut err = String::new();
+	if prop_set_layer(prop, layer, val, &mut err) {
+		write_note(path, &err, writer);
+		true
+	}
+	else {
+		// error: cvar.prop "true": not a number
+		let _ = writeln!(writer, "error: {path} {val:?}: {err}");
+		false
 	}
+}
+
+// Sets a property loaded from a configuration in the layer, actions are reported instead of invoked
+pub(crate) fn set_loaded(root: &mut dyn IVisit, path: &str, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+	let mut result = false;
+	let matches = find_opts(root, path, &Options::DEFAULT, &mut |node| {
+		match node.as_node() {
+			Node::Prop(prop) => result |= set_prop(path, prop, layer, val, writer),
+			Node::List(_) => {},
+			Node::Action(_) => {
+				// error: cvar.action!: cannot set an action
+				let _ = writeln!(writer, "error: {path}: cannot set an action");
+			},
+		}
+	});
+	write_matches(matches, MultiMatch::All, path, writer);
 	result
 }
 
+// Reports unknown and ambiguous paths
+fn write_matches(matches: usize, multi: MultiMatch, path: &str, writer: &mut dyn IWrite) {
+	if matches == 0 {
+		let _ = writeln!(writer, "unknown: {path}");
+	}
+	else if matches > 1 && multi == MultiMatch::Error {
+		// error: cvar.prop: ambiguous path matches 2 nodes
+		let _ = writeln!(writer, "error: {path}: ambiguous path matches {matches} nodes");
+	}
+}
+
 /// Gets a property's value as a string.
 ///
 /// Returns `None` if the path does not lead to a property.
 #[inline]
 pub fn get(root: &mut dyn IVisit, path: &str) -> Option<String> {
+	_get(root, path, &Options::DEFAULT)
+}
+
+fn _get(root: &mut dyn IVisit, path: &str, opts: &Options) -> Option<String> {
 	let mut result = None;
-	find(root, path, |node| {
+	find_opts(root, path, opts, &mut |node| {
 		if let Node::Prop(prop) = node.as_node() {
 			result = Some(prop.get_value().to_string());
 		}
@@ -134,17 +273,54 @@ pub fn get(root: &mut dyn IVisit, path: &str) -> Option<String> {
 	result
 }
 
+/// Formats a property's value into the destination without allocating.
+///
+/// Returns `false` if the path does not lead to a property or formatting failed.
+/// If the path matches multiple properties only the first one is formatted.
+///
+/// ```
+/// let mut fps = 144;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("fps", &mut fps, &60));
+/// });
+///
+/// let mut line = String::from("fps: ");
+/// assert!(cvar::console::get_into(&mut root, "fps", &mut line));
+/// assert_eq!(line, "fps: 144");
+/// ```
+#[inline]
+pub fn get_into(root: &mut dyn IVisit, path: &str, dest: &mut dyn fmt::Write) -> bool {
+	_get_into(root, path, &Options::DEFAULT, dest)
+}
+
+fn _get_into(root: &mut dyn IVisit, path: &str, opts: &Options, dest: &mut dyn fmt::Write) -> bool {
+	let mut result = None;
+	find_opts(root, path, opts, &mut |node| {
+		if result.is_none() {
+			if let Node::Prop(prop) = node.as_node() {
+				result = Some(write!(dest, "{}", prop.get_value()).is_ok());
+			}
+		}
+	});
+	result.unwrap_or(false)
+}
+
 /// Sets a property's value directly.
 ///
 /// If the path is an action it is invoked with the value as the argument.
 #[inline]
 pub fn set_value(root: &mut dyn IVisit, path: &str, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+	_set_value(root, path, val, &Options::DEFAULT, writer)
+}
+
+fn _set_value(root: &mut dyn IVisit, path: &str, val: &dyn IValue, opts: &Options, writer: &mut dyn IWrite) -> bool {
 	let mut result = false;
-	if !find(root, path, |node| {
+	let matches = find_opts(root, path, opts, &mut |node| {
 		match node.as_node() {
 			Node::Prop(prop) => {
 				let mut err = String::new();
-				if prop.set_value(val, &mut err) {
+				if prop_set_value(prop, val, &mut err) {
+					write_note(path, &err, writer);
 					result = true;
 				}
 				else {
@@ -154,22 +330,89 @@ pub fn set_value(root: &mut dyn IVisit, path: &str, val: &dyn IValue, writer: &m
 			},
 			Node::List(_) => {},
 			Node::Action(act) => {
-				act.invoke(&val.to_string(), writer);
+				invoke_action(path, act, &val.to_string(), writer);
 			},
 		}
-	}) {
-		let _ = writeln!(writer, "unknown: {path}");
-	}
+	});
+	write_matches(matches, opts.multi, path, writer);
+	result
+}
+
+/// Toggles a property's value.
+///
+/// Boolean properties are flipped, properties with a list of [valid values](IProperty::values) are cycled to the next value.
+/// The new value is written to the writer.
+///
+/// Returns `false` if the path does not lead to a property which can be toggled.
+///
+/// ```
+/// let mut fullscreen = false;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("fullscreen", &mut fullscreen, &false));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(cvar::console::toggle(&mut root, "fullscreen", &mut writer));
+/// assert_eq!(writer, "fullscreen is \"true\"\n");
+/// ```
+#[inline]
+pub fn toggle(root: &mut dyn IVisit, path: &str, writer: &mut dyn IWrite) -> bool {
+	_toggle(root, path, &Options::DEFAULT, writer)
+}
+
+fn _toggle(root: &mut dyn IVisit, path: &str, opts: &Options, writer: &mut dyn IWrite) -> bool {
+	let mut result = false;
+	let matches = find_opts(root, path, opts, &mut |node| {
+		if let Node::Prop(prop) = node.as_node() {
+			let mut err = String::new();
+			if toggle_prop(prop, &mut err) {
+				let value = prop.get_value().to_string();
+				// cvar.prop is "true"
+				let _ = writeln!(writer, "{path} is {value:?}");
+				write_note(path, &err, writer);
+				result = true;
+			}
+			else {
+				// error: cvar.prop: cannot toggle
+				let _ = writeln!(writer, "error: {path}: {err}");
+			}
+		}
+	});
+	write_matches(matches, opts.multi, path, writer);
 	result
 }
 
+// Flips a boolean property or cycles to its next valid value
+pub(crate) fn toggle_prop(prop: &mut dyn IProperty, err: &mut String) -> bool {
+	if let Some(&value) = prop.get_value().downcast_ref::<bool>() {
+		prop_set_value(prop, &!value, err)
+	}
+	else if let Some(values) = prop.values() {
+		let value = prop.get_value().to_string();
+		let next = match values.iter().position(|&v| v == value) {
+			Some(index) => values[(index + 1) % values.len()],
+			None => values.first().copied().unwrap_or(""),
+		};
+		let next = next.to_string();
+		prop_set(prop, &next, err)
+	}
+	else {
+		err.push_str("cannot toggle");
+		false
+	}
+}
+
 /// Gets a property's value directly.
 ///
 /// Returns `None` if the path does not lead to a property of the expected type.
 #[inline]
 pub fn get_value<T: Clone + 'static>(root: &mut dyn IVisit, path: &str) -> Option<T> {
+	_get_value(root, path, &Options::DEFAULT)
+}
+
+fn _get_value<T: Clone + 'static>(root: &mut dyn IVisit, path: &str, opts: &Options) -> Option<T> {
 	let mut value = None;
-	find(root, path, |node| {
+	find_opts(root, path, opts, &mut |node| {
 		if let Node::Prop(prop) = node.as_node() {
 			if let Some(any) = prop.get_value().downcast_ref::<T>() {
 				value = Some(any.clone());
@@ -179,109 +422,694 @@ pub fn get_value<T: Clone + 'static>(root: &mut dyn IVisit, path: &str) -> Optio
 	value
 }
 
+/// Gets the configuration layer a property's value came from.
+///
+/// Returns `None` if the path does not lead to a property, see [`IProperty::provenance`].
+#[inline]
+pub fn provenance(root: &mut dyn IVisit, path: &str) -> Option<Layer> {
+	_provenance(root, path, &Options::DEFAULT)
+}
+
+fn _provenance(root: &mut dyn IVisit, path: &str, opts: &Options) -> Option<Layer> {
+	let mut result = None;
+	find_opts(root, path, opts, &mut |node| {
+		if let Node::Prop(prop) = node.as_node() {
+			result = Some(prop.provenance());
+		}
+	});
+	result
+}
+
+/// Localization callback given the full path of a node.
+pub type Localize<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Gets a node's display name.
+///
+/// The localization callback is given the full path of the node, if it returns a name it takes precedence over the node's [label](INode::label).
+///
+/// Returns `None` if the path does not lead to a node.
+///
+/// ```
+/// let mut width = 1920;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("width", &mut width, &1920).with_label("Width"));
+/// });
+///
+/// assert_eq!(cvar::console::display_name(&mut root, "width", None).as_deref(), Some("Width"));
+///
+/// let localize = |path: &str| if path == "width" { Some(String::from("Breite")) } else { None };
+/// assert_eq!(cvar::console::display_name(&mut root, "width", Some(&localize)).as_deref(), Some("Breite"));
+/// ```
+#[inline]
+pub fn display_name(root: &mut dyn IVisit, path: &str, localize: Option<Localize<'_>>) -> Option<String> {
+	_display_name(root, path, &Options { localize, ..Options::DEFAULT })
+}
+
+fn _display_name(root: &mut dyn IVisit, path: &str, opts: &Options) -> Option<String> {
+	let localize = opts.localize;
+	let mut result = None;
+	find_opts(root, path, opts, &mut |node| {
+		if result.is_none() {
+			result = localize.and_then(|localize| localize(path)).or_else(|| Some(node.label().to_string()));
+		}
+	});
+	result
+}
+
 /// Resets properties to their default.
 ///
 /// Given a list node will reset all its children to their default. Ignores action nodes.
+/// Given a pattern, see [`find_matching`], resets all the matching properties.
+/// Properties with the [`READONLY`](flags::READONLY) flag are skipped.
+///
+/// Returns false if no node was found or if any read-only property was skipped.
 #[inline]
 pub fn reset(root: &mut dyn IVisit, path: &str) -> bool {
-	find(root, path, |node| {
-		match node.as_node() {
-			Node::Prop(prop) => prop.reset(),
-			Node::List(list) => reset_all(list.as_ivisit()),
-			Node::Action(_) => (),
+	_reset(root, path, &Options::DEFAULT)
+}
+
+fn _reset(root: &mut dyn IVisit, path: &str, opts: &Options) -> bool {
+	let mut result = true;
+	let found = if is_pattern(path) {
+		find_matching(root, path, |_path, node| {
+			if let Node::Prop(prop) = node.as_node() {
+				result &= reset_prop(prop);
+			}
+		})
+	}
+	else {
+		find_opts(root, path, opts, &mut |node| {
+			match node.as_node() {
+				Node::Prop(prop) => result &= reset_prop(prop),
+				Node::List(list) => result &= _reset_all(list.as_ivisit()),
+				Node::Action(_) => (),
+			}
+		}) > 0
+	};
+	found && result
+}
+
+// Read-only properties are never reset
+fn reset_prop(prop: &mut dyn IProperty) -> bool {
+	if prop.flags() & flags::READONLY != 0 {
+		return false;
+	}
+	prop.reset();
+	true
+}
+
+/// Resets the properties matching the pattern and writes every change.
+///
+/// A property matches if its path or the path of any of its parent lists matches the pattern, see [`find_matching`] for the syntax.
+/// Every property whose value changes is reported as `path: old -> new`, properties with the [`READONLY`](flags::READONLY) flag are skipped.
+///
+/// Returns the number of properties which changed.
+///
+/// ```
+/// let mut volume = 0.3;
+/// let mut music = 1.0;
+/// let mut fov = 100;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("audio.volume", &mut volume, &1.0));
+/// 	f(&mut cvar::Property("audio.music", &mut music, &1.0));
+/// 	f(&mut cvar::Property("fov", &mut fov, &90));
+/// });
+///
+/// let mut writer = String::new();
+/// assert_eq!(cvar::console::reset_matching_dry_run(&mut root, "audio.*", &mut writer), 1);
+/// assert_eq!(cvar::console::reset_matching(&mut root, "audio.*", &mut writer), 1);
+/// assert_eq!(writer, "would reset audio.volume: 0.3 -> 1\naudio.volume: 0.3 -> 1\n");
+/// assert_eq!(cvar::console::get(&mut root, "audio.volume").as_deref(), Some("1"));
+/// assert_eq!(cvar::console::get(&mut root, "fov").as_deref(), Some("100"));
+/// ```
+#[inline]
+pub fn reset_matching(root: &mut dyn IVisit, pattern: &str, writer: &mut dyn IWrite) -> usize {
+	_reset_matching(root, pattern, false, writer)
+}
+
+/// Reports the changes [`reset_matching`] would make without resetting any properties.
+///
+/// Every change is reported as `would reset path: old -> new`.
+#[inline]
+pub fn reset_matching_dry_run(root: &mut dyn IVisit, pattern: &str, writer: &mut dyn IWrite) -> usize {
+	_reset_matching(root, pattern, true, writer)
+}
+
+fn _reset_matching(root: &mut dyn IVisit, pattern: &str, dry_run: bool, writer: &mut dyn IWrite) -> usize {
+	let mut changes = 0;
+	walk(root, |path, node| {
+		let Node::Prop(prop) = node.as_node() else { return };
+		let matches = glob_match(pattern, path) || path.match_indices('.').any(|(index, _)| glob_match(pattern, &path[..index]));
+		// Read-only properties are never reset
+		if !matches || prop.flags() & flags::READONLY != 0 {
+			return;
 		}
-	})
+		let old = prop.get_value().to_string();
+		let new = if dry_run {
+			prop.default_value().to_string()
+		}
+		else {
+			prop.reset();
+			prop.get_value().to_string()
+		};
+		if old != new {
+			// cvar.prop: 0.3 -> 1
+			let prefix = if dry_run { "would reset " } else { "" };
+			let _ = writeln!(writer, "{prefix}{path}: {old} -> {new}");
+			changes += 1;
+		}
+	});
+	changes
 }
+
 /// Resets all properties to their default.
+///
+/// Properties with the [`READONLY`](flags::READONLY) flag are skipped.
 #[inline]
 pub fn reset_all(root: &mut dyn IVisit) {
+	_reset_all(root);
+}
+
+// Returns false if any read-only property was skipped
+fn _reset_all(root: &mut dyn IVisit) -> bool {
+	let mut result = true;
 	root.visit(&mut |node| {
 		match node.as_node() {
-			Node::Prop(prop) => prop.reset(),
-			Node::List(list) => reset_all(list.as_ivisit()),
+			Node::Prop(prop) => result &= reset_prop(prop),
+			Node::List(list) => result &= _reset_all(list.as_ivisit()),
 			Node::Action(_) => (),
 		}
 	});
+	result
 }
 
 /// Lists all properties and actions in the visitor.
+///
+/// Properties with the [`HIDDEN`](flags::HIDDEN) flag are not listed, see [`print_all`].
+/// Values of properties with the [`SECRET`](flags::SECRET) flag are printed masked.
+/// Given a pattern, see [`find_matching`], lists all the matching nodes with their full path.
+///
+/// ```
+/// let mut min_latency = 10;
+/// let mut max_latency = 200;
+/// let mut rate = 30;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("net.min_latency", &mut min_latency, &0));
+/// 	f(&mut cvar::Property("net.max_latency", &mut max_latency, &0));
+/// 	f(&mut cvar::Property("net.rate", &mut rate, &30));
+/// });
+///
+/// let mut writer = String::new();
+/// cvar::console::print(&mut root, "net.*latency*", &mut writer);
+/// assert_eq!(writer, "net.min_latency is \"10\"\nnet.max_latency is \"200\"\n");
+/// ```
 #[inline]
 pub fn print(root: &mut dyn IVisit, path: &str, writer: &mut dyn IWrite) {
-	if path.len() > 0 {
-		if !find(root, path, |node| {
-			let _ = _print_node(node, Some(path), writer);
-		}) {
+	_print(root, path, &Options::DEFAULT, writer)
+}
+
+/// Lists all properties and actions in the visitor, including hidden properties.
+#[inline]
+pub fn print_all(root: &mut dyn IVisit, path: &str, writer: &mut dyn IWrite) {
+	_print(root, path, &Options { show_hidden: true, ..Options::DEFAULT }, writer)
+}
+
+fn _print(root: &mut dyn IVisit, path: &str, opts: &Options, writer: &mut dyn IWrite) {
+	if is_pattern(path) {
+		let found = find_matching(root, path, |path, node| {
+			if opts.show_hidden || !is_hidden(node) {
+				let parent = path[..path.len() - node.name().len()].strip_suffix('.');
+				let _ = _print_node(node, parent, opts, writer);
+			}
+		});
+		if !found {
 			let _ = writeln!(writer, "unknown: {path}");
 		}
 	}
+	else if !path.is_empty() {
+		let matches = find_opts(root, path, opts, &mut |node| {
+			if opts.show_hidden || !is_hidden(node) {
+				let _ = _print_node(node, Some(path), opts, writer);
+			}
+		});
+		write_matches(matches, opts.multi, path, writer);
+	}
 	else {
-		_print_nodes(root, None, writer);
+		_print_nodes(root, None, opts, writer);
 	}
 }
 
-//----------------------------------------------------------------
-
-#[inline]
-fn split_at<'a>(path: &'a str, index: usize) -> Option<(&str, &u8, &str)> {
-	let at = path.as_bytes().get(index)?;
-	let prefix = path.get(..index)?;
-	let suffix = path.get(index + 1..)?;
-	Some((prefix, at, suffix))
-}
-
-/// Compares a path to a node name.
+/// Lists all properties and actions under the path sorted by their full path.
 ///
-/// * `ComparePath::True` if they compare equal.
-/// * `ComparePath::Part` if the path starts with name and is followed by `.`.
-/// * `ComparePath::False` in all other cases.
+/// Unlike [`print`] nested lists are expanded, the values are aligned in a column.
+/// Properties with the [`HIDDEN`](flags::HIDDEN) flag are not listed.
 ///
-/// Node names are allowed to contain `.`, eg: `Action::new("foo.bar", "desc", |ctx| Ok(()))`.
-/// This does not create a `foo` list node, but merely allows the node to pretend to be part of one.
+/// ```
+/// let mut width = 1920;
+/// let mut fullscreen = false;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("video.width", &mut width, &1920));
+/// 	f(&mut cvar::Property("video.fullscreen", &mut fullscreen, &false));
+/// 	f(&mut cvar::Action("quit", |_args, _writer| ()));
+/// });
 ///
-/// Node names are allowed to be the empty string, while confusing there's nothing special about it.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum ComparePath<'a> {
-	False,
-	True,
-	Part(&'a str),
+/// let mut writer = String::new();
+/// cvar::console::print_sorted(&mut root, "", &mut writer);
+/// assert_eq!(writer, "quit\nvideo.fullscreen \"false\"\nvideo.width      \"1920\"\n");
+/// ```
+#[inline]
+pub fn print_sorted(root: &mut dyn IVisit, path: &str, writer: &mut dyn IWrite) {
+	_print_sorted(root, path, &Options::DEFAULT, writer)
 }
-impl<'a> ComparePath<'a> {
-	#[inline]
-	fn cmp(path: &'a str, name: &str) -> ComparePath<'a> {
-		match split_at(path, name.len()) {
-			Some((prefix, &b'.', suffix)) => {
-				if prefix == name {
-					ComparePath::Part(suffix)
-				}
-				else {
-					ComparePath::False
-				}
-			},
-			Some(_) => ComparePath::False,
-			None => if path == name { ComparePath::True } else { ComparePath::False },
+
+fn _print_sorted(root: &mut dyn IVisit, path: &str, opts: &Options, writer: &mut dyn IWrite) {
+	let mut rows = Vec::new();
+	let mut add_row = |path: String, node: &mut dyn INode| {
+		if opts.show_hidden || !is_hidden(node) {
+			match node.as_node() {
+				Node::Prop(prop) => {
+					let mut value = format!("{:?}", display_value(prop));
+					let _ = write_provenance(prop, opts, &mut value);
+					rows.push((path, Some(value)));
+				},
+				Node::List(_) => {},
+				Node::Action(_) => rows.push((path, None)),
+			}
 		}
+	};
+	if !path.is_empty() {
+		let matches = find_opts(root, path, opts, &mut |node| {
+			if let Node::List(list) = node.as_node() {
+				walk(list.as_ivisit(), |child, node| add_row(format!("{path}.{child}"), node));
+			}
+			else {
+				add_row(path.to_string(), node);
+			}
+		});
+		write_matches(matches, opts.multi, path, writer);
+	}
+	else {
+		walk(root, |path, node| add_row(path.to_string(), node));
+	}
+	rows.sort();
+	let width = rows.iter().filter(|(_, value)| value.is_some()).map(|(path, _)| path.chars().count()).max().unwrap_or(0);
+	for (path, value) in &rows {
+		let _ = match value {
+			Some(value) => writeln!(writer, "{path:<width$} {value}"),
+			None => writeln!(writer, "{path}"),
+		};
 	}
-}
-#[test]
-fn test_compare_id() {
-	assert_eq!(ComparePath::cmp("foo", "foo"), ComparePath::True);
-	assert_eq!(ComparePath::cmp("foo.bar", "foo"), ComparePath::Part("bar"));
-	assert_eq!(ComparePath::cmp("foo.bar", "foo.bar"), ComparePath::True);
-	assert_eq!(ComparePath::cmp("foo.bar.baz", "foo.bar"), ComparePath::Part("baz"));
-	assert_eq!(ComparePath::cmp("fooz", "foo"), ComparePath::False);
-	assert_eq!(ComparePath::cmp("foo.bar.baz", "bar.baz"), ComparePath::False);
-	assert_eq!(ComparePath::cmp("foo", "foo.bar"), ComparePath::False);
-	// Degenerate cases: empty string node names
-	assert_eq!(ComparePath::cmp("foo.", "foo"), ComparePath::Part(""));
-	assert_eq!(ComparePath::cmp("foo.", "foo."), ComparePath::True);
-	assert_eq!(ComparePath::cmp("foo", "foo."), ComparePath::False);
-	assert_eq!(ComparePath::cmp("", ""), ComparePath::True);
-	assert_eq!(ComparePath::cmp(".", ""), ComparePath::Part(""));
-	assert_eq!(ComparePath::cmp(".", "."), ComparePath::True);
-	assert_eq!(ComparePath::cmp("", "."), ComparePath::False);
-	assert_eq!(ComparePath::cmp(".foo", "foo"), ComparePath::False);
-	assert_eq!(ComparePath::cmp(".foo", ".foo"), ComparePath::True);
-	assert_eq!(ComparePath::cmp("foo", ".foo"), ComparePath::False);
+}
+
+//----------------------------------------------------------------
+
+/// Sets properties from environment variables.
+///
+/// The name of the environment variable of a property is its path prefixed with `prefix`, uppercased and with all non-alphanumeric characters replaced by `_`.
+/// The property `net.max_rate` with the prefix `APP_` is set by the environment variable `APP_NET_MAX_RATE`.
+///
+/// Errors are written to the writer. Returns `false` if any property failed to set.
+///
+/// ```
+/// let mut port = 8080;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("server.port", &mut port, &8080));
+/// });
+///
+/// std::env::set_var("APP_SERVER_PORT", "3000");
+/// assert!(cvar::console::load_env(&mut root, "APP_", &mut cvar::NullWriter));
+/// assert_eq!(cvar::console::get(&mut root, "server.port").as_deref(), Some("3000"));
+/// ```
+pub fn load_env(root: &mut dyn IVisit, prefix: &str, writer: &mut dyn IWrite) -> bool {
+	let mut result = true;
+	let mut key = String::new();
+	walk(root, |path, node| {
+		if let Node::Prop(prop) = node.as_node() {
+			key.clear();
+			key.push_str(prefix);
+			key.extend(path.chars().map(|chr| if chr.is_ascii_alphanumeric() { chr.to_ascii_uppercase() } else { '_' }));
+			let Some(val) = std::env::var_os(&key) else { return };
+			let Some(val) = val.to_str() else {
+				// error: APP_FOO: not valid unicode
+				let _ = writeln!(writer, "error: {key}: not valid unicode");
+				result = false;
+				return;
+			};
+			let mut err = String::new();
+			if prop_set(prop, val, &mut err) {
+				write_note(path, &err, writer);
+			}
+			else {
+				// error: cvar.prop "true": not a number
+				let _ = writeln!(writer, "error: {path} {val:?}: {err}");
+				result = false;
+			}
+		}
+	});
+	result
+}
+
+/// Sets properties from command-line arguments.
+///
+/// Supports two styles of overrides:
+///
+/// * `--path=value` sets the property to the value.
+/// * `+path value` sets the property to the next argument. If the next argument is missing or starts with `+` or `--` the path is invoked as an action without arguments.
+///
+/// Properties are set in the [`CommandLine`](Layer::CommandLine) layer, see [`IProperty::set_layer`].
+/// Overrides of unknown paths are reported as `unknown: path` and count as failed.
+/// All other arguments, such as `--verbose` without a value, are ignored so they can be parsed by the application. Errors are written to the writer.
+///
+/// Returns `false` if any override failed to apply.
+///
+/// ```
+/// let mut port = 8080;
+/// let mut map = String::new();
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("server.port", &mut port, &8080));
+/// 	f(&mut cvar::Property("map", &mut map, &String::new()));
+/// });
+///
+/// let args = ["--verbose", "--server.port=3000", "+map", "dust"].map(String::from);
+/// assert!(cvar::console::load_args(&mut root, args, &mut cvar::NullWriter));
+/// assert_eq!(cvar::console::get(&mut root, "server.port").as_deref(), Some("3000"));
+/// assert_eq!(cvar::console::get(&mut root, "map").as_deref(), Some("dust"));
+/// ```
+pub fn load_args<I: IntoIterator<Item = String>>(root: &mut dyn IVisit, args: I, writer: &mut dyn IWrite) -> bool {
+	let mut result = true;
+	let mut args = args.into_iter().peekable();
+	while let Some(arg) = args.next() {
+		if let Some(arg) = arg.strip_prefix("--") {
+			if let Some((path, val)) = arg.split_once('=') {
+				result &= set_in_layer(root, path, Layer::CommandLine, val, &Options::DEFAULT, writer);
+			}
+		}
+		else if let Some(path) = arg.strip_prefix('+') {
+			match args.next_if(|next| !next.starts_with('+') && !next.starts_with("--")) {
+				Some(val) => result &= set_in_layer(root, path, Layer::CommandLine, &val, &Options::DEFAULT, writer),
+				None => result &= invoke_with(root, path, "", MultiMatch::All, writer),
+			}
+		}
+	}
+	result
+}
+
+/// Captures the values of all properties.
+///
+/// ```
+/// let mut volume = 0.5;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0));
+/// });
+///
+/// let snapshot = cvar::console::snapshot(&mut root);
+/// cvar::console::set_silent(&mut root, "volume", "0.25");
+///
+/// assert!(snapshot.restore(&mut root, &mut cvar::NullWriter));
+/// assert_eq!(cvar::console::get(&mut root, "volume").as_deref(), Some("0.5"));
+/// ```
+pub fn snapshot(root: &mut dyn IVisit) -> Snapshot {
+	let mut entries = Vec::new();
+	walk(root, |path, node| {
+		if let Node::Prop(prop) = node.as_node() {
+			entries.push(SnapshotEntry {
+				path: path.to_string(),
+				value: prop.get_value().to_string(),
+				default: prop.default_value().to_string(),
+				flags: prop.flags(),
+			});
+		}
+	});
+	Snapshot { entries }
+}
+
+/// Compares the property values of two trees.
+///
+/// Returns the path and both values of every property whose value differs, in the visitation order of `a`.
+/// Properties which exist in only one of the trees are not reported.
+///
+/// Diff a [`Snapshot`] against the live tree to find the unsaved changes:
+///
+/// ```
+/// let mut volume = 0.5;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0));
+/// });
+///
+/// let mut saved = cvar::console::snapshot(&mut root);
+/// cvar::console::set_silent(&mut root, "volume", "0.25");
+///
+/// let changes = cvar::console::diff(&mut saved, &mut root);
+/// assert_eq!(changes, [("volume".to_string(), "0.5".to_string(), "0.25".to_string())]);
+/// ```
+pub fn diff(a: &mut dyn IVisit, b: &mut dyn IVisit) -> Vec<(String, String, String)> {
+	let mut entries = Vec::new();
+	walk(a, |path, node| {
+		if let Node::Prop(prop) = node.as_node() {
+			entries.push((path.to_string(), prop.get_value().to_string(), prop.epsilon()));
+		}
+	});
+	let b = snapshot(b);
+	let b: std::collections::HashMap<&str, &str> = b.entries.iter().map(|entry| (entry.path.as_str(), entry.value.as_str())).collect();
+	entries.into_iter()
+		.filter_map(|(path, value_a, epsilon)| {
+			let value_b = *b.get(path.as_str())?;
+			if value_a != value_b && !approx_eq(&value_a, value_b, epsilon) {
+				Some((path, value_a, value_b.to_string()))
+			}
+			else {
+				None
+			}
+		})
+		.collect()
+}
+
+/// Merges two snapshots.
+///
+/// The merged snapshot contains the properties of `dst` followed by the properties only captured in `src`.
+/// Properties captured in both snapshots with different values are resolved by the strategy and reported as conflicts with the path, the `dst` value and the `src` value.
+///
+/// ```
+/// use cvar::console::Strategy;
+///
+/// let mut volume = 1.0;
+/// let mut width = 1920;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0));
+/// 	f(&mut cvar::Property("width", &mut width, &1920));
+/// });
+///
+/// cvar::console::set_silent(&mut root, "width", "1280");
+/// let machine = cvar::console::snapshot(&mut root);
+/// cvar::console::reset_all(&mut root);
+/// cvar::console::set_silent(&mut root, "volume", "0.5");
+/// let user = cvar::console::snapshot(&mut root);
+///
+/// let (merged, conflicts) = cvar::console::merge(&machine, &user, Strategy::PreferNonDefault);
+/// assert_eq!(merged.get("volume"), Some("0.5"));
+/// assert_eq!(merged.get("width"), Some("1280"));
+/// assert_eq!(conflicts.len(), 2);
+/// ```
+pub fn merge(dst: &Snapshot, src: &Snapshot, strategy: Strategy) -> (Snapshot, Vec<(String, String, String)>) {
+	let mut entries = dst.entries.clone();
+	let mut conflicts = Vec::new();
+	for src_entry in &src.entries {
+		match entries.iter_mut().find(|entry| entry.path == src_entry.path) {
+			Some(entry) => {
+				if entry.value != src_entry.value {
+					conflicts.push((entry.path.clone(), entry.value.clone(), src_entry.value.clone()));
+					let prefer_src = match strategy {
+						Strategy::PreferSrc => true,
+						Strategy::PreferDst => false,
+						Strategy::PreferNonDefault => src_entry.value != src_entry.default || entry.value == entry.default,
+					};
+					if prefer_src {
+						entry.value.clone_from(&src_entry.value);
+					}
+				}
+			},
+			None => entries.push(src_entry.clone()),
+		}
+	}
+	(Snapshot { entries }, conflicts)
+}
+
+/// Conflict resolution strategy for [`merge`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Strategy {
+	/// Take the value from the source snapshot.
+	#[default]
+	PreferSrc,
+	/// Keep the value from the destination snapshot.
+	PreferDst,
+	/// Take the value which is not the default, the source wins if both are not the default.
+	PreferNonDefault,
+}
+
+// Compares formatted float values within the tolerance
+fn approx_eq(a: &str, b: &str, epsilon: f64) -> bool {
+	if epsilon <= 0.0 {
+		return false;
+	}
+	match (a.parse::<f64>(), b.parse::<f64>()) {
+		(Ok(a), Ok(b)) => (a - b).abs() <= epsilon,
+		_ => false,
+	}
+}
+
+/// Snapshot of property values.
+///
+/// Created by [`snapshot`], stores the values of the properties in visitation order.
+///
+/// The snapshot is itself a visitor exposing every entry as a string property named by its full path.
+/// The properties carry the flags of the captured properties, so [`SECRET`](flags::SECRET) values stay masked when printed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+	entries: Vec<SnapshotEntry>,
+}
+
+/// Property captured in a [`Snapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotEntry {
+	pub path: String,
+	pub value: String,
+	pub default: String,
+	pub flags: u32,
+}
+
+impl Snapshot {
+	/// Returns the number of captured properties.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Returns `true` if no properties were captured.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Returns the captured properties in visitation order.
+	#[inline]
+	pub fn entries(&self) -> &[SnapshotEntry] {
+		&self.entries
+	}
+
+	/// Returns the captured value of the property at the path.
+	pub fn get(&self, path: &str) -> Option<&str> {
+		self.entries.iter().find(|entry| entry.path == path).map(|entry| entry.value.as_str())
+	}
+
+	/// Restores the captured values.
+	///
+	/// Only properties whose value differs from the snapshot are set, errors are written to the writer.
+	/// Properties which no longer exist are ignored.
+	///
+	/// Returns `false` if any property failed to restore.
+	pub fn restore(&self, root: &mut dyn IVisit, writer: &mut dyn IWrite) -> bool {
+		let mut result = true;
+		for entry in &self.entries {
+			let SnapshotEntry { path, value: val, .. } = entry;
+			find(root, path, |node| {
+				if let Node::Prop(prop) = node.as_node() {
+					if prop.get_value().to_string() != *val {
+						let mut err = String::new();
+						if !prop_set(prop, val, &mut err) {
+							// error: cvar.prop "true": not a number
+							let _ = writeln!(writer, "error: {path} {val:?}: {err}");
+							result = false;
+						}
+					}
+				}
+			});
+		}
+		result
+	}
+}
+
+impl IVisit for Snapshot {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		for entry in &mut self.entries {
+			f(&mut Property::new(&entry.path, &mut entry.value, &entry.default).with_flags(entry.flags));
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+#[inline]
+fn split_at(path: &str, index: usize) -> Option<(&str, &u8, &str)> {
+	let at = path.as_bytes().get(index)?;
+	let prefix = path.get(..index)?;
+	let suffix = path.get(index + 1..)?;
+	Some((prefix, at, suffix))
+}
+
+/// Compares a path to a node name.
+///
+/// * `ComparePath::True` if they compare equal.
+/// * `ComparePath::Part` if the path starts with name and is followed by `.`.
+/// * `ComparePath::False` in all other cases.
+///
+/// Node names are allowed to contain `.`, eg: `Action::new("foo.bar", "desc", |ctx| Ok(()))`.
+/// This does not create a `foo` list node, but merely allows the node to pretend to be part of one.
+///
+/// Node names are allowed to be the empty string, while confusing there's nothing special about it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum ComparePath<'a> {
+	False,
+	True,
+	Part(&'a str),
+}
+impl<'a> ComparePath<'a> {
+	#[cfg(test)]
+	#[inline]
+	fn cmp(path: &'a str, name: &str) -> ComparePath<'a> {
+		ComparePath::cmp_with(path, name, false)
+	}
+	#[inline]
+	fn cmp_with(path: &'a str, name: &str, ignore_case: bool) -> ComparePath<'a> {
+		let eq = |a: &str, b: &str| if ignore_case { a.eq_ignore_ascii_case(b) } else { a == b };
+		match split_at(path, name.len()) {
+			Some((prefix, &b'.', suffix)) => {
+				if eq(prefix, name) {
+					ComparePath::Part(suffix)
+				}
+				else {
+					ComparePath::False
+				}
+			},
+			Some(_) => ComparePath::False,
+			None => if eq(path, name) { ComparePath::True } else { ComparePath::False },
+		}
+	}
+}
+#[test]
+fn test_compare_id() {
+	assert_eq!(ComparePath::cmp("foo", "foo"), ComparePath::True);
+	assert_eq!(ComparePath::cmp("foo.bar", "foo"), ComparePath::Part("bar"));
+	assert_eq!(ComparePath::cmp("foo.bar", "foo.bar"), ComparePath::True);
+	assert_eq!(ComparePath::cmp("foo.bar.baz", "foo.bar"), ComparePath::Part("baz"));
+	assert_eq!(ComparePath::cmp("fooz", "foo"), ComparePath::False);
+	assert_eq!(ComparePath::cmp("foo.bar.baz", "bar.baz"), ComparePath::False);
+	assert_eq!(ComparePath::cmp("foo", "foo.bar"), ComparePath::False);
+	// Degenerate cases: empty string node names
+	assert_eq!(ComparePath::cmp("foo.", "foo"), ComparePath::Part(""));
+	assert_eq!(ComparePath::cmp("foo.", "foo."), ComparePath::True);
+	assert_eq!(ComparePath::cmp("foo", "foo."), ComparePath::False);
+	assert_eq!(ComparePath::cmp("", ""), ComparePath::True);
+	assert_eq!(ComparePath::cmp(".", ""), ComparePath::Part(""));
+	assert_eq!(ComparePath::cmp(".", "."), ComparePath::True);
+	assert_eq!(ComparePath::cmp("", "."), ComparePath::False);
+	assert_eq!(ComparePath::cmp(".foo", "foo"), ComparePath::False);
+	assert_eq!(ComparePath::cmp(".foo", ".foo"), ComparePath::True);
+	assert_eq!(ComparePath::cmp("foo", ".foo"), ComparePath::False);
+	// Case insensitive comparison
+	assert_eq!(ComparePath::cmp("FOO.bar", "foo"), ComparePath::False);
+	assert_eq!(ComparePath::cmp_with("FOO.bar", "foo", true), ComparePath::Part("bar"));
+	assert_eq!(ComparePath::cmp_with("Foo.Bar", "foo.bar", true), ComparePath::True);
+	assert_eq!(ComparePath::cmp_with("fooz", "FOO", true), ComparePath::False);
 }
 
 //----------------------------------------------------------------
@@ -294,20 +1122,82 @@ fn test_compare_id() {
 /// Returns false if no nodes were found with this path, the closure has not been called.
 #[inline]
 pub fn find<F: FnMut(&mut dyn INode)>(root: &mut dyn IVisit, path: &str, mut f: F) -> bool {
-	find_rec(root, path, &mut f)
+	find_rec(root, path, false, &mut f)
+}
+
+/// How to treat multiple nodes with the same path.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum MultiMatch {
+	/// Only the first node with the path is used.
+	First,
+	/// All the nodes with the path are used, in visitation order.
+	#[default]
+	All,
+	/// Multiple nodes with the same path is an error, none of them are used.
+	Error,
+}
+
+/// Finds a cvar by its path with control over duplicate paths.
+///
+/// Returns the number of nodes found with this path, regardless of how many times the closure has been called.
+///
+/// ```
+/// let mut value = 0;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Action("value", |_args, _writer| ()));
+/// 	f(&mut cvar::Property("value", &mut value, &0));
+/// });
+///
+/// let mut calls = 0;
+/// assert_eq!(cvar::console::find_with(&mut root, "value", cvar::console::MultiMatch::First, |_node| calls += 1), 2);
+/// assert_eq!(calls, 1);
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set_with(&mut root, "value", "42", cvar::console::MultiMatch::Error, &mut writer));
+/// assert_eq!(writer, "error: value: ambiguous path matches 2 nodes\n");
+/// ```
+#[inline]
+pub fn find_with<F: FnMut(&mut dyn INode)>(root: &mut dyn IVisit, path: &str, multi: MultiMatch, mut f: F) -> usize {
+	find_opts(root, path, &Options { multi, ..Options::DEFAULT }, &mut f)
+}
+fn find_opts(root: &mut dyn IVisit, path: &str, opts: &Options, f: &mut dyn FnMut(&mut dyn INode)) -> usize {
+	let mut matches = 0;
+	match opts.multi {
+		MultiMatch::First => {
+			find_rec(root, path, opts.ignore_case, &mut |node| {
+				if matches == 0 {
+					f(node);
+				}
+				matches += 1;
+			});
+		},
+		MultiMatch::All => {
+			find_rec(root, path, opts.ignore_case, &mut |node| {
+				f(node);
+				matches += 1;
+			});
+		},
+		MultiMatch::Error => {
+			find_rec(root, path, opts.ignore_case, &mut |_node| matches += 1);
+			if matches == 1 {
+				find_rec(root, path, opts.ignore_case, f);
+			}
+		},
+	}
+	matches
 }
 #[inline]
-fn find_rec(list: &mut dyn IVisit, path: &str, f: &mut dyn FnMut(&mut dyn INode)) -> bool {
+fn find_rec(list: &mut dyn IVisit, path: &str, ignore_case: bool, f: &mut dyn FnMut(&mut dyn INode)) -> bool {
 	let mut found = false;
 	list.visit(&mut |node| {
-		match ComparePath::cmp(path, node.name()) {
+		match ComparePath::cmp_with(path, node.name(), ignore_case) {
 			ComparePath::True => {
 				f(node);
 				found = true;
 			},
 			ComparePath::Part(tail) => {
 				if let Node::List(list) = node.as_node() {
-					found |= find_rec(list.as_ivisit(), tail, f);
+					found |= find_rec(list.as_ivisit(), tail, ignore_case, f);
 				}
 			},
 			ComparePath::False => {},
@@ -316,45 +1206,1365 @@ fn find_rec(list: &mut dyn IVisit, path: &str, f: &mut dyn FnMut(&mut dyn INode)
 	found
 }
 
-/// Walks all nodes in the cvar hierarchy and call the closure with the node along its full path.
-#[inline]
-pub fn walk<F: FnMut(&str, &mut dyn INode)>(root: &mut dyn IVisit, mut f: F) {
-	let mut path = String::new();
-	walk_rec(root, &mut path, &mut f);
+/// Resolves a path to a handle for repeated lookups.
+///
+/// Returns `None` if the path does not lead to a node, if multiple nodes match the path the handle refers to the first one.
+///
+/// ```
+/// let mut fov = 90;
+/// let mut camera = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("fov", &mut fov, &90));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("camera", &mut camera));
+/// });
+///
+/// let handle = cvar::console::resolve(&mut root, "camera.fov").unwrap();
+/// assert!(handle.set(&mut root, "100", &mut cvar::NullWriter));
+/// assert_eq!(handle.get(&mut root).as_deref(), Some("100"));
+/// ```
+pub fn resolve(root: &mut dyn IVisit, path: &str) -> Option<PathHandle> {
+	let mut steps = Vec::new();
+	if resolve_rec(root, path, 0, &mut steps) {
+		Some(PathHandle { path: path.to_string(), steps })
+	}
+	else {
+		None
+	}
 }
-#[inline]
-fn walk_rec(list: &mut dyn IVisit, path: &mut String, f: &mut dyn FnMut(&str, &mut dyn INode)) {
+fn resolve_rec(list: &mut dyn IVisit, path: &str, offset: usize, steps: &mut Vec<(usize, usize)>) -> bool {
+	let mut found = false;
+	let mut ordinal = 0;
 	list.visit(&mut |node| {
-		// Construct the path
-		let len = path.len();
-		if len > 0 {
-			path.push('.');
-		}
-		path.push_str(node.name());
-		// Tell our caller about the node
-		f(&path, node);
-		// Recursively visit list nodes
-		if let Node::List(list) = node.as_node() {
-			walk_rec(list.as_ivisit(), path, f);
+		if !found {
+			let name_len = node.name().len();
+			match ComparePath::cmp_with(&path[offset..], node.name(), false) {
+				ComparePath::True => {
+					steps.push((ordinal, path.len()));
+					found = true;
+				},
+				ComparePath::Part(_) => {
+					if let Node::List(list) = node.as_node() {
+						steps.push((ordinal, offset + name_len));
+						found = resolve_rec(list.as_ivisit(), path, offset + name_len + 1, steps);
+						if !found {
+							steps.pop();
+						}
+					}
+				},
+				ComparePath::False => {},
+			}
 		}
-		// Pop off the name so we can reuse this string
-		path.truncate(len);
+		ordinal += 1;
 	});
+	found
 }
 
-//----------------------------------------------------------------
+/// Resolved path for repeated lookups.
+///
+/// Created by [`resolve`], remembers the position of the node at every level of the hierarchy.
+/// Lookups only compare the name of a single node per level instead of every node along the way.
+///
+/// The handle becomes stale if the hierarchy changes shape, lookups then fail as if the path does not exist and the path should be resolved again.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PathHandle {
+	pub(crate) path: String,
+	// Ordinal of the node and the end of its name in the path for every level
+	pub(crate) steps: Vec<(usize, usize)>,
+}
 
-/// Invokes an action.
+impl PathHandle {
+	/// Returns the resolved path.
+	#[inline]
+	pub fn path(&self) -> &str {
+		&self.path
+	}
+
+	/// Finds the node and invokes the closure with it.
+	///
+	/// Returns false if the handle is stale, the closure has not been called.
+	pub fn find<F: FnMut(&mut dyn INode)>(&self, root: &mut dyn IVisit, mut f: F) -> bool {
+		self.find_rec(root, 0, 0, &mut f)
+	}
+	fn find_rec(&self, list: &mut dyn IVisit, level: usize, start: usize, f: &mut dyn FnMut(&mut dyn INode)) -> bool {
+		let Some(&(target, end)) = self.steps.get(level) else { return false };
+		let mut found = false;
+		let mut ordinal = 0;
+		list.visit(&mut |node| {
+			if ordinal == target && node.name() == &self.path[start..end] {
+				if level + 1 == self.steps.len() {
+					f(node);
+					found = true;
+				}
+				else if let Node::List(list) = node.as_node() {
+					found = self.find_rec(list.as_ivisit(), level + 1, end + 1, f);
+				}
+			}
+			ordinal += 1;
+		});
+		found
+	}
+
+	/// Gets the property's value as a string.
+	///
+	/// Returns `None` if the handle is stale or does not lead to a property.
+	pub fn get(&self, root: &mut dyn IVisit) -> Option<String> {
+		let mut result = None;
+		self.find(root, |node| {
+			if let Node::Prop(prop) = node.as_node() {
+				result = Some(prop.get_value().to_string());
+			}
+		});
+		result
+	}
+
+	/// Sets the property's value parsed from a string.
+	///
+	/// If the handle leads to an action it is invoked with the value as the argument.
+	/// See [`set`] for more information.
+	pub fn set(&self, root: &mut dyn IVisit, val: &str, writer: &mut dyn IWrite) -> bool {
+		let result = self.try_set(root, val, writer);
+		if result.is_none() {
+			write_matches(0, MultiMatch::First, &self.path, writer);
+		}
+		result.unwrap_or(false)
+	}
+
+	// Returns None without writing anything if the handle is stale
+	pub(crate) fn try_set(&self, root: &mut dyn IVisit, val: &str, writer: &mut dyn IWrite) -> Option<bool> {
+		let path = self.path.as_str();
+		let mut result = false;
+		let found = self.find(root, |node| {
+			match node.as_node() {
+				Node::Prop(prop) => {
+					let mut err = String::new();
+					if prop_set(prop, val, &mut err) {
+						write_note(path, &err, writer);
+						result = true;
+					}
+					else {
+						// error: cvar.prop "true": not a number
+						let _ = writeln!(writer, "error: {path} {val:?}: {err}");
+					}
+				},
+				Node::List(_) => {},
+				Node::Action(act) => {
+					invoke_action(path, act, val, writer);
+				},
+			}
+		});
+		found.then_some(result)
+	}
+}
+
+/// Finds all nodes whose full path matches the pattern and invokes the closure with the node along its full path.
 ///
-/// Returns false if no action node was found at the given path.
-#[inline]
-pub fn invoke(root: &mut dyn IVisit, path: &str, args: &str, writer: &mut dyn IWrite) -> bool {
+/// In the pattern `*` matches any sequence of characters including `.` and `?` matches any single character.
+/// List nodes and their children are matched independently.
+///
+/// Returns false if no nodes were found matching the pattern, the closure has not been called.
+///
+/// ```
+/// let mut width = 1920;
+/// let mut height = 1080;
+/// let mut volume = 1.0;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("render.width", &mut width, &1920));
+/// 	f(&mut cvar::Property("render.height", &mut height, &1080));
+/// 	f(&mut cvar::Property("audio.volume", &mut volume, &1.0));
+/// });
+///
+/// let mut paths = Vec::new();
+/// assert!(cvar::console::find_matching(&mut root, "render.*", |path, _node| paths.push(path.to_string())));
+/// assert_eq!(paths, ["render.width", "render.height"]);
+/// ```
+pub fn find_matching<F: FnMut(&str, &mut dyn INode)>(root: &mut dyn IVisit, pattern: &str, mut f: F) -> bool {
 	let mut found = false;
-	find(root, path, |node| {
-		if let Node::Action(act) = node.as_node() {
+	walk(root, |path, node| {
+		if glob_match(pattern, path) {
+			f(path, node);
 			found = true;
-			act.invoke(args, writer);
 		}
 	});
 	found
 }
+
+// Returns true if the path contains glob operators
+#[inline]
+fn is_pattern(path: &str) -> bool {
+	path.contains(['*', '?'])
+}
+
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+	let pattern: Vec<char> = pattern.chars().collect();
+	let text: Vec<char> = text.chars().collect();
+	let (mut p, mut t) = (0, 0);
+	// Position of the last `*` and the text position it is currently matched up to
+	let mut backtrack = None;
+	while t < text.len() {
+		match pattern.get(p) {
+			Some('*') => {
+				backtrack = Some((p, t));
+				p += 1;
+			},
+			Some(&chr) if chr == '?' || chr == text[t] => {
+				p += 1;
+				t += 1;
+			},
+			_ => match backtrack {
+				Some((star, end)) => {
+					p = star + 1;
+					t = end + 1;
+					backtrack = Some((star, end + 1));
+				},
+				None => return false,
+			},
+		}
+	}
+	pattern[p..].iter().all(|&chr| chr == '*')
+}
+
+#[test]
+fn test_glob_match() {
+	assert!(glob_match("render.*", "render.width"));
+	assert!(glob_match("render.*", "render.shadows.quality"));
+	assert!(!glob_match("render.*", "render"));
+	assert!(glob_match("net.*latency*", "net.max_latency_ms"));
+	assert!(!glob_match("net.*latency*", "net.rate"));
+	assert!(glob_match("a?c", "abc"));
+	assert!(!glob_match("a?c", "ac"));
+	assert!(glob_match("*", ""));
+	assert!(glob_match("**a", "bba"));
+	assert!(!glob_match("", "a"));
+}
+
+/// Searches the tree and prints every node whose path, description or value contains the text, ignoring case.
+///
+/// Long descriptions are shortened to a snippet of the line around the match.
+/// Properties with the [`HIDDEN`](flags::HIDDEN) flag are not searched.
+/// Returns false if no nodes were found.
+///
+/// ```
+/// let mut volume = 0.5;
+/// let mut name = String::from("Player");
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0).with_desc("Master audio level"));
+/// 	f(&mut cvar::Property("name", &mut name, &String::new()));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(cvar::console::grep(&mut root, "AUDIO", &mut writer));
+/// assert!(cvar::console::grep(&mut root, "play", &mut writer));
+/// assert_eq!(writer, "volume is \"0.5\" - Master audio level\nname is \"Player\"\n");
+/// ```
+#[inline]
+pub fn grep(root: &mut dyn IVisit, text: &str, writer: &mut dyn IWrite) -> bool {
+	_grep(root, text, &Options::DEFAULT, writer)
+}
+
+fn _grep(root: &mut dyn IVisit, text: &str, opts: &Options, writer: &mut dyn IWrite) -> bool {
+	let mut found = false;
+	walk(root, |path, node| {
+		if !opts.show_hidden && is_hidden(node) {
+			return;
+		}
+		let value = match node.as_node() {
+			Node::Prop(prop) => Some(display_value(prop)),
+			_ => None,
+		};
+		let desc_match = find_ignore_case(node.description(), text);
+		let matches = desc_match.is_some()
+			|| find_ignore_case(path, text).is_some()
+			|| value.as_deref().is_some_and(|value| find_ignore_case(value, text).is_some());
+		if matches {
+			found = true;
+			let _ = print_match(path, node, desc_match, writer);
+		}
+	});
+	found
+}
+
+// Prints the node with the first line of its description around the match
+fn print_match(path: &str, node: &mut dyn INode, desc_match: Option<usize>, writer: &mut dyn IWrite) -> fmt::Result {
+	match node.as_node() {
+		Node::Prop(prop) => {
+			let value = display_value(prop);
+			write!(writer, "{path} is {value:?}")?;
+		},
+		Node::List(_) => write!(writer, "{path}...")?,
+		Node::Action(_) => writer.write_str(path)?,
+	}
+	let desc = node.description();
+	if !desc.is_empty() {
+		writer.write_str(" - ")?;
+		write_snippet(writer, desc, desc_match.unwrap_or(0))?;
+	}
+	writer.write_str("\n")
+}
+
+/// Writes at most `SNIPPET_LEN` characters of the first line of text around the byte position.
+fn write_snippet(writer: &mut dyn IWrite, text: &str, pos: usize) -> fmt::Result {
+	const SNIPPET_LEN: usize = 60;
+	let line_start = text[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0);
+	let line_end = text[pos..].find('\n').map(|i| pos + i).unwrap_or(text.len());
+	let line = &text[line_start..line_end];
+	let pos = pos - line_start;
+	// Keep some context before the match
+	let start = line[..pos].char_indices().rev().nth(SNIPPET_LEN / 4).map(|(i, _)| i).unwrap_or(0);
+	let end = line[start..].char_indices().nth(SNIPPET_LEN).map(|(i, _)| start + i).unwrap_or(line.len());
+	if start > 0 {
+		writer.write_str("...")?;
+	}
+	writer.write_str(&line[start..end])?;
+	if end < line.len() || line_end < text.len() {
+		writer.write_str("...")?;
+	}
+	Ok(())
+}
+
+/// Returns the byte position of the needle in the haystack, ignoring case.
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+	haystack.char_indices().map(|(i, _)| i).find(|&i| {
+		let mut hay = haystack[i..].chars().flat_map(char::to_lowercase);
+		needle.chars().flat_map(char::to_lowercase).all(|chr| hay.next() == Some(chr))
+	})
+}
+
+#[test]
+fn test_find_ignore_case() {
+	assert_eq!(find_ignore_case("Master Volume", "volume"), Some(7));
+	assert_eq!(find_ignore_case("master volume", "VOL"), Some(7));
+	assert_eq!(find_ignore_case("Ärger", "är"), Some(0));
+	assert_eq!(find_ignore_case("volume", "volumes"), None);
+	assert_eq!(find_ignore_case("", "a"), None);
+}
+
+/// Walks all nodes in the cvar hierarchy and call the closure with the node along its full path.
+#[inline]
+pub fn walk<F: FnMut(&str, &mut dyn INode)>(root: &mut dyn IVisit, mut f: F) {
+	let mut path = String::new();
+	walk_rec(root, &mut path, &mut f);
+}
+#[inline]
+fn walk_rec(list: &mut dyn IVisit, path: &mut String, f: &mut dyn FnMut(&str, &mut dyn INode)) {
+	list.visit(&mut |node| {
+		// Construct the path
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(node.name());
+		// Tell our caller about the node
+		f(path, node);
+		// Recursively visit list nodes
+		if let Node::List(list) = node.as_node() {
+			walk_rec(list.as_ivisit(), path, f);
+		}
+		// Pop off the name so we can reuse this string
+		path.truncate(len);
+	});
+}
+
+/// Computes a fingerprint of the shape of the tree.
+///
+/// Hashes the path and kind of every node in visitation order, values are not included.
+/// Visitation order is deterministic for the visitors in this crate, including the adapters for slices, vectors and maps, so peers with the same tree shape compute the same fingerprint.
+/// Compare fingerprints to detect when the trees of a replay or replication stream have diverged.
+///
+/// The hash is FNV-1a and is stable across platforms and releases.
+///
+/// ```
+/// let mut a = 1;
+/// let mut b = 2;
+/// let fingerprint = cvar::console::ordering_fingerprint(&mut cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("a", &mut a, &1));
+/// 	f(&mut cvar::Property("b", &mut b, &2));
+/// }));
+/// let reordered = cvar::console::ordering_fingerprint(&mut cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("b", &mut b, &2));
+/// 	f(&mut cvar::Property("a", &mut a, &1));
+/// }));
+/// assert_ne!(fingerprint, reordered);
+/// ```
+pub fn ordering_fingerprint(root: &mut dyn IVisit) -> u64 {
+	const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+	const FNV_PRIME: u64 = 0x100000001b3;
+	let mut hash = FNV_OFFSET;
+	let mut write = |bytes: &[u8]| {
+		for &byte in bytes {
+			hash = (hash ^ byte as u64).wrapping_mul(FNV_PRIME);
+		}
+	};
+	walk(root, |path, node| {
+		let kind: &[u8] = match node.as_node() {
+			Node::Prop(_) => b"p",
+			Node::List(_) => b"l",
+			Node::Action(_) => b"a",
+		};
+		write(kind);
+		write(path.as_bytes());
+		write(b"\0");
+	});
+	hash
+}
+
+/// Walks the nodes in the cvar hierarchy until the closure breaks.
+///
+/// See [`walk_depth`] for more information.
+#[inline]
+pub fn walk_until<B, F: FnMut(&str, &mut dyn INode) -> ControlFlow<B>>(root: &mut dyn IVisit, f: F) -> ControlFlow<B> {
+	walk_depth(root, usize::MAX, f)
+}
+
+/// Walks the nodes up to a maximum depth until the closure breaks.
+///
+/// A depth of `1` only walks the nodes of the root, use it to lazily expand one level of a large tree.
+/// The nodes visited after the closure breaks are skipped, returns the break value of the closure.
+///
+/// ```
+/// use std::ops::ControlFlow;
+///
+/// let mut width = 1920;
+/// let mut video = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("width", &mut width, &1920));
+/// });
+/// let mut volume = 1.0;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("video", &mut video));
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0));
+/// });
+///
+/// let mut paths = Vec::new();
+/// let _ = cvar::console::walk_depth(&mut root, 1, |path, _node| {
+/// 	paths.push(path.to_string());
+/// 	ControlFlow::<()>::Continue(())
+/// });
+/// assert_eq!(paths, ["video", "volume"]);
+///
+/// let found = cvar::console::walk_until(&mut root, |path, node| {
+/// 	if node.name() == "width" { ControlFlow::Break(path.to_string()) } else { ControlFlow::Continue(()) }
+/// });
+/// assert_eq!(found, ControlFlow::Break(String::from("video.width")));
+/// ```
+pub fn walk_depth<B, F: FnMut(&str, &mut dyn INode) -> ControlFlow<B>>(root: &mut dyn IVisit, max_depth: usize, mut f: F) -> ControlFlow<B> {
+	let mut path = String::new();
+	let mut result = ControlFlow::Continue(());
+	walk_depth_rec(root, &mut path, max_depth, &mut result, &mut f);
+	result
+}
+fn walk_depth_rec<B>(list: &mut dyn IVisit, path: &mut String, depth: usize, result: &mut ControlFlow<B>, f: &mut dyn FnMut(&str, &mut dyn INode) -> ControlFlow<B>) {
+	if depth == 0 {
+		return;
+	}
+	list.visit(&mut |node| {
+		// The visitor cannot be stopped, skip the remaining nodes
+		if result.is_break() {
+			return;
+		}
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(node.name());
+		*result = f(path, node);
+		if result.is_continue() {
+			if let Node::List(list) = node.as_node() {
+				walk_depth_rec(list.as_ivisit(), path, depth - 1, result, f);
+			}
+		}
+		path.truncate(len);
+	});
+}
+
+/// Finds a node by its [stable identifier](INode::id) and invokes the closure with the node along its full path.
+///
+/// Returns false if no nodes were found with this identifier, the closure has not been called.
+///
+/// ```
+/// let mut volume = 1.0;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("audio.volume", &mut volume, &1.0).with_id(0x1001));
+/// });
+///
+/// let mut path = String::new();
+/// assert!(cvar::console::find_id(&mut root, 0x1001, |p, _node| path = p.to_string()));
+/// assert_eq!(path, "audio.volume");
+/// ```
+pub fn find_id<F: FnMut(&str, &mut dyn INode)>(root: &mut dyn IVisit, id: u64, mut f: F) -> bool {
+	let mut found = false;
+	walk(root, |path, node| {
+		if node.id() == Some(id) {
+			f(path, node);
+			found = true;
+		}
+	});
+	found
+}
+
+/// Reports all nodes with names which cannot be addressed from the console.
+///
+/// See [`is_valid_name`] for the rules. Returns `true` if all names are valid.
+pub fn check_names(root: &mut dyn IVisit, writer: &mut dyn IWrite) -> bool {
+	let mut result = true;
+	walk(root, |path, node| {
+		let name = node.name();
+		if !is_valid_name(name) {
+			// invalid name: cvar.list."foo bar"
+			let parent = &path[..path.len() - name.len()];
+			let _ = writeln!(writer, "invalid name: {parent}{name:?}");
+			result = false;
+		}
+	});
+	result
+}
+
+/// Statistics about the shape of the tree, see [`stats`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct TreeStats {
+	/// Number of property nodes.
+	pub props: usize,
+	/// Number of list nodes.
+	pub lists: usize,
+	/// Number of action nodes.
+	pub actions: usize,
+	/// Maximum nesting depth, the nodes of the root are at depth `1`.
+	pub max_depth: usize,
+	/// Total length in bytes of the full paths of all nodes.
+	pub path_bytes: usize,
+}
+
+impl TreeStats {
+	/// Returns the total number of nodes.
+	#[inline]
+	pub fn nodes(&self) -> usize {
+		self.props + self.lists + self.actions
+	}
+}
+
+/// Collects statistics about the shape of the tree.
+///
+/// ```
+/// let mut width = 1920;
+/// let mut video = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("width", &mut width, &1920));
+/// 	f(&mut cvar::Action("restart!", |_args, _writer| ()));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("video", &mut video));
+/// });
+///
+/// let stats = cvar::console::stats(&mut root);
+/// assert_eq!((stats.props, stats.lists, stats.actions), (1, 1, 1));
+/// assert_eq!(stats.max_depth, 2);
+/// assert_eq!(stats.path_bytes, "video".len() + "video.width".len() + "video.restart!".len());
+/// ```
+pub fn stats(root: &mut dyn IVisit) -> TreeStats {
+	let mut stats = TreeStats::default();
+	stats_rec(root, &mut String::new(), 1, &mut stats);
+	stats
+}
+fn stats_rec(list: &mut dyn IVisit, path: &mut String, depth: usize, stats: &mut TreeStats) {
+	list.visit(&mut |node| {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(node.name());
+		stats.path_bytes += path.len();
+		stats.max_depth = stats.max_depth.max(depth);
+		match node.as_node() {
+			Node::Prop(_) => stats.props += 1,
+			Node::List(list) => {
+				stats.lists += 1;
+				stats_rec(list.as_ivisit(), path, depth + 1, stats);
+			},
+			Node::Action(_) => stats.actions += 1,
+		}
+		path.truncate(len);
+	});
+}
+
+/// Reports mistakes in the shape of the tree.
+///
+/// Reports invalid names (see [`is_valid_name`]), multiple properties or actions with the same path and nodes of different kinds with the same path.
+/// Such nodes shadow each other or cannot be addressed reliably from the console.
+/// Multiple lists with the same path are not reported, their children are merged by the console.
+///
+/// Returns `true` if nothing was reported.
+///
+/// ```
+/// let mut a = 1;
+/// let mut b = 2;
+/// let mut c = 3;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut a, &1));
+/// 	f(&mut cvar::Property("volume", &mut b, &2));
+/// 	f(&mut cvar::Property("audio.mute", &mut c, &3));
+/// 	f(&mut cvar::Action("", |_args, _writer| ()));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::lint(&mut root, &mut writer));
+/// assert_eq!(writer, "invalid name: \"\"\nduplicate: volume (2 props)\n");
+/// ```
+pub fn lint(root: &mut dyn IVisit, writer: &mut dyn IWrite) -> bool {
+	let mut result = true;
+	let mut paths: Vec<(String, Vec<NodeKind>)> = Vec::new();
+	let mut index = std::collections::HashMap::new();
+	walk(root, |path, node| {
+		let name = node.name();
+		if !is_valid_name(name) {
+			// invalid name: cvar.list."foo bar"
+			let parent = &path[..path.len() - name.len()];
+			let _ = writeln!(writer, "invalid name: {parent}{name:?}");
+			result = false;
+		}
+		let kind = match node.as_node() {
+			Node::Prop(_) => NodeKind::Prop,
+			Node::List(_) => NodeKind::List,
+			Node::Action(_) => NodeKind::Action,
+		};
+		let i = *index.entry(path.to_string()).or_insert_with(|| {
+			paths.push((path.to_string(), Vec::new()));
+			paths.len() - 1
+		});
+		paths[i].1.push(kind);
+	});
+	for (path, kinds) in &paths {
+		if kinds.iter().any(|&kind| kind != kinds[0]) {
+			// conflicting kinds: cvar.node (prop, action)
+			let _ = write!(writer, "conflicting kinds: {path} (");
+			for (i, kind) in kinds.iter().enumerate() {
+				let _ = write!(writer, "{}{kind}", if i > 0 { ", " } else { "" });
+			}
+			let _ = writeln!(writer, ")");
+			result = false;
+		}
+		else if kinds.len() > 1 && kinds[0] != NodeKind::List {
+			// duplicate: cvar.prop (2 props)
+			let _ = writeln!(writer, "duplicate: {path} ({} {}s)", kinds.len(), kinds[0]);
+			result = false;
+		}
+	}
+	result
+}
+
+/// Reports properties which need attention after loading the configuration.
+///
+/// Reports properties in the [`Invalid`](PropState::Invalid) state, properties with the [`DEPRECATED`](flags::DEPRECATED) flag and properties with the [`PENDING`](flags::PENDING) flag.
+/// Call once after loading the configuration so misconfiguration is noticed at startup rather than mid-session.
+///
+/// Returns `true` if nothing was reported.
+///
+/// ```
+/// let mut width = cvar::Latched::new(1920);
+/// let mut sensitivity = 1.0;
+/// let mut input = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("sensitivity", &mut sensitivity, &1.0));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::LatchedProp("width", &mut width, &1920));
+/// 	f(&mut cvar::List("input", &mut input));
+/// });
+/// let mut root = cvar::Deprecated(&mut root, &[("m_sensitivity", "input.sensitivity")]);
+/// cvar::console::set_silent(&mut root, "width", "1280");
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::startup_report(&mut root, &mut writer));
+/// assert_eq!(writer, "pending: width\ndeprecated: m_sensitivity\n");
+/// ```
+pub fn startup_report(root: &mut dyn IVisit, writer: &mut dyn IWrite) -> bool {
+	let mut result = true;
+	walk(root, |path, node| {
+		if let Node::Prop(prop) = node.as_node() {
+			let flags = prop.flags();
+			if prop.state() == PropState::Invalid {
+				// invalid: cvar.prop "value"
+				let _ = writeln!(writer, "invalid: {path} \"{}\"", prop.get_value());
+				result = false;
+			}
+			if flags & flags::DEPRECATED != 0 {
+				// deprecated: cvar.prop
+				let _ = writeln!(writer, "deprecated: {path}");
+				result = false;
+			}
+			if flags & flags::PENDING != 0 {
+				// pending: cvar.prop
+				let _ = writeln!(writer, "pending: {path}");
+				result = false;
+			}
+		}
+	});
+	result
+}
+
+/// Describes every node in the tree for external tooling.
+///
+/// Returns a [`NodeSchema`] per node in visitation order, the `json` feature adds `json::schema` for a machine-readable format.
+///
+/// ```
+/// let mut fov = 90;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ClampedProp("fov", &mut fov, &90, Some(&60), Some(&120)).with_desc("Field of view."));
+/// });
+///
+/// let schema = cvar::console::schema(&mut root);
+/// assert_eq!(schema[0].path, "fov");
+/// assert_eq!(schema[0].kind, cvar::console::NodeKind::Prop);
+/// assert_eq!(schema[0].default.as_deref(), Some("90"));
+/// assert_eq!(schema[0].min.as_deref(), Some("60"));
+/// assert_eq!(schema[0].max.as_deref(), Some("120"));
+/// assert_eq!(schema[0].description, "Field of view.");
+/// ```
+pub fn schema(root: &mut dyn IVisit) -> Vec<NodeSchema> {
+	let mut nodes = Vec::new();
+	walk(root, |path, node| {
+		let description = node.description().to_string();
+		let schema = match node.as_node() {
+			Node::Prop(prop) => {
+				NodeSchema {
+					path: path.to_string(),
+					kind: NodeKind::Prop,
+					#[cfg(feature = "type_name")]
+					type_name: prop.type_name().to_string(),
+					default: Some(prop.default_value().to_string()),
+					min: prop.min_value().map(|min| min.to_string()),
+					max: prop.max_value().map(|max| max.to_string()),
+					flags: prop.flags(),
+					values: prop.values().map(|values| values.iter().map(|value| value.to_string()).collect()),
+					description,
+				}
+			},
+			Node::List(_) => other_schema(path, NodeKind::List, description),
+			Node::Action(_) => other_schema(path, NodeKind::Action, description),
+		};
+		nodes.push(schema);
+	});
+	nodes
+}
+fn other_schema(path: &str, kind: NodeKind, description: String) -> NodeSchema {
+	NodeSchema {
+		path: path.to_string(),
+		kind,
+		#[cfg(feature = "type_name")]
+		type_name: String::new(),
+		default: None,
+		min: None,
+		max: None,
+		flags: 0,
+		values: None,
+		description,
+	}
+}
+
+/// Kind of node described by a [`NodeSchema`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NodeKind {
+	Prop,
+	List,
+	Action,
+}
+
+impl fmt::Display for NodeKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match self {
+			NodeKind::Prop => "prop",
+			NodeKind::List => "list",
+			NodeKind::Action => "action",
+		})
+	}
+}
+
+/// Node description created by [`schema`].
+///
+/// Values are formatted as strings, fields which do not apply to the kind of node are `None` or empty.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NodeSchema {
+	/// Full path of the node.
+	pub path: String,
+	pub kind: NodeKind,
+	/// Name of the concrete property type, see [`IProperty::type_name`].
+	#[cfg(feature = "type_name")]
+	pub type_name: String,
+	pub default: Option<String>,
+	/// Inclusive bounds, see [`IProperty::min_value`] and [`IProperty::max_value`].
+	pub min: Option<String>,
+	pub max: Option<String>,
+	/// Property flags, see the [`flags`] module.
+	pub flags: u32,
+	/// Valid values, see [`IProperty::values`].
+	pub values: Option<Vec<String>>,
+	pub description: String,
+}
+
+/// Completes a partial path.
+///
+/// Returns the sorted and deduplicated paths which start with the partial path, up to the end of the name being completed.
+/// Completing `video.w` yields `video.width` but not `video.width.max`, completing `vid` yields `video`.
+///
+/// If the partial path is followed by a space the arguments of the action at the path are completed instead, see [`IAction::complete`].
+/// Completing `exec a` yields `exec autoexec.cfg` if the action returns `autoexec.cfg` as a candidate.
+/// For properties the [valid values](IProperty::values) starting with the argument are completed.
+///
+/// This walks the entire tree, see [`CompletionIndex`] for large trees.
+#[inline]
+pub fn complete(root: &mut dyn IVisit, partial: &str) -> Vec<String> {
+	_complete(root, partial, &Options::DEFAULT)
+}
+
+fn _complete(root: &mut dyn IVisit, partial: &str, opts: &Options) -> Vec<String> {
+	if let Some((path, args)) = partial.split_once(' ') {
+		let mut candidates = std::collections::BTreeSet::new();
+		find_opts(root, path, opts, &mut |node| {
+			match node.as_node() {
+				Node::Action(act) => candidates.extend(act.complete(args).into_iter().map(|candidate| format!("{path} {candidate}"))),
+				Node::Prop(prop) => if let Some(values) = prop.values() {
+					candidates.extend(values.iter().filter(|value| value.starts_with(args)).map(|value| format!("{path} {value}")));
+				},
+				Node::List(_) => (),
+			}
+		});
+		return candidates.into_iter().collect();
+	}
+	let parent_len = partial.rfind('.').map(|index| index + 1).unwrap_or(0);
+	let mut paths = std::collections::BTreeSet::new();
+	walk(root, |path, _node| {
+		let matches = match opts.ignore_case {
+			true => path.get(..partial.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(partial)),
+			false => path.starts_with(partial),
+		};
+		if matches {
+			let end = path[parent_len..].find('.').map(|index| parent_len + index).unwrap_or(path.len());
+			if !paths.contains(&path[..end]) {
+				paths.insert(path[..end].to_string());
+			}
+		}
+	});
+	paths.into_iter().collect()
+}
+
+//----------------------------------------------------------------
+
+/// Invokes an action.
+///
+/// If the action fails its error is written to the writer, if its arguments are invalid its usage is written instead.
+///
+/// Returns false if no action node was found at the given path or any of the actions failed.
+#[inline]
+pub fn invoke(root: &mut dyn IVisit, path: &str, args: &str, writer: &mut dyn IWrite) -> bool {
+	let mut found = false;
+	let mut result = true;
+	find(root, path, |node| {
+		if let Node::Action(act) = node.as_node() {
+			found = true;
+			result &= invoke_action(path, act, args, writer);
+		}
+	});
+	found && result
+}
+
+/// Invokes an action with control over duplicate paths.
+///
+/// Unlike [`invoke`] only action nodes are considered when counting the matches.
+/// Unknown and ambiguous paths are reported to the writer.
+/// Returns false if the path is unknown or ambiguous, or an action failed.
+#[inline]
+pub fn invoke_with(root: &mut dyn IVisit, path: &str, args: &str, multi: MultiMatch, writer: &mut dyn IWrite) -> bool {
+	_invoke(root, path, args, &Options { multi, ..Options::DEFAULT }, writer)
+}
+
+fn _invoke(root: &mut dyn IVisit, path: &str, args: &str, opts: &Options, writer: &mut dyn IWrite) -> bool {
+	let multi = opts.multi;
+	let mut matches = 0;
+	find_rec(root, path, opts.ignore_case, &mut |node| {
+		if let Node::Action(_) = node.as_node() {
+			matches += 1;
+		}
+	});
+	if matches == 0 || matches > 1 && multi == MultiMatch::Error {
+		write_matches(matches, multi, path, writer);
+		return false;
+	}
+	let mut invoked = false;
+	let mut result = true;
+	find_rec(root, path, opts.ignore_case, &mut |node| {
+		if let Node::Action(act) = node.as_node() {
+			if !invoked || multi == MultiMatch::All {
+				result &= invoke_action(path, act, args, writer);
+			}
+			invoked = true;
+		}
+	});
+	result
+}
+
+//----------------------------------------------------------------
+// Variants of the console functions without a writer.
+
+/// Pokes the cvar tree, discarding any output.
+///
+/// See [`poke`] for more information.
+#[inline]
+pub fn poke_silent(root: &mut dyn IVisit, path: &str, args: Option<&str>) -> bool {
+	poke(root, path, args, &mut NullWriter)
+}
+
+/// Sets a property's value parsed from a string, discarding any output.
+///
+/// See [`set`] for more information.
+#[inline]
+pub fn set_silent(root: &mut dyn IVisit, path: &str, val: &str) -> bool {
+	set(root, path, val, &mut NullWriter)
+}
+
+/// Sets a property's value directly, discarding any output.
+///
+/// See [`set_value`] for more information.
+#[inline]
+pub fn set_value_silent(root: &mut dyn IVisit, path: &str, val: &dyn IValue) -> bool {
+	set_value(root, path, val, &mut NullWriter)
+}
+
+/// Toggles a property's value, discarding any output.
+///
+/// See [`toggle`] for more information.
+#[inline]
+pub fn toggle_silent(root: &mut dyn IVisit, path: &str) -> bool {
+	toggle(root, path, &mut NullWriter)
+}
+
+/// Invokes an action, discarding any output.
+///
+/// See [`invoke`] for more information.
+#[inline]
+pub fn invoke_silent(root: &mut dyn IVisit, path: &str, args: &str) -> bool {
+	invoke(root, path, args, &mut NullWriter)
+}
+
+/// Pokes the cvar tree, writing any output to stdout.
+///
+/// See [`poke`] for more information.
+#[inline]
+pub fn poke_stdout(root: &mut dyn IVisit, path: &str, args: Option<&str>) -> bool {
+	poke(root, path, args, &mut IoWriter::stdout())
+}
+
+/// Sets a property's value parsed from a string, writing any output to stdout.
+///
+/// See [`set`] for more information.
+#[inline]
+pub fn set_stdout(root: &mut dyn IVisit, path: &str, val: &str) -> bool {
+	set(root, path, val, &mut IoWriter::stdout())
+}
+
+/// Sets a property's value directly, writing any output to stdout.
+///
+/// See [`set_value`] for more information.
+#[inline]
+pub fn set_value_stdout(root: &mut dyn IVisit, path: &str, val: &dyn IValue) -> bool {
+	set_value(root, path, val, &mut IoWriter::stdout())
+}
+
+/// Toggles a property's value, writing any output to stdout.
+///
+/// See [`toggle`] for more information.
+#[inline]
+pub fn toggle_stdout(root: &mut dyn IVisit, path: &str) -> bool {
+	toggle(root, path, &mut IoWriter::stdout())
+}
+
+/// Invokes an action, writing any output to stdout.
+///
+/// See [`invoke`] for more information.
+#[inline]
+pub fn invoke_stdout(root: &mut dyn IVisit, path: &str, args: &str) -> bool {
+	invoke(root, path, args, &mut IoWriter::stdout())
+}
+
+/// Lists all properties and actions in the visitor to stdout.
+///
+/// See [`print`] for more information.
+#[inline]
+pub fn print_stdout(root: &mut dyn IVisit, path: &str) {
+	print(root, path, &mut IoWriter::stdout())
+}
+
+//----------------------------------------------------------------
+
+/// Console options.
+///
+/// Configures the behavior of the methods of a [`Console`].
+/// Paths are always separated by `.`, the separator is not configurable.
+#[derive(Copy, Clone)]
+pub struct Options<'a> {
+	/// How to treat multiple nodes with the same path.
+	pub multi: MultiMatch,
+	/// Compare paths to node names ignoring ASCII case.
+	pub ignore_case: bool,
+	/// List properties with the [`HIDDEN`](flags::HIDDEN) flag.
+	pub show_hidden: bool,
+	/// List where non-default values came from, see [`IProperty::provenance`].
+	pub show_provenance: bool,
+	/// Guards access to the nodes, see [`Gate`].
+	pub gate: Option<&'a dyn IGate>,
+	/// Localization callback used for display names, see [`display_name`].
+	pub localize: Option<Localize<'a>>,
+}
+
+impl<'a> Options<'a> {
+	/// The options used by the free functions in this module.
+	pub const DEFAULT: Options<'static> = Options {
+		multi: MultiMatch::All,
+		ignore_case: false,
+		show_hidden: false,
+		show_provenance: false,
+		gate: None,
+		localize: None,
+	};
+}
+
+impl<'a> Default for Options<'a> {
+	#[inline]
+	fn default() -> Self {
+		Options::DEFAULT
+	}
+}
+
+impl<'a> fmt::Debug for Options<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Options")
+			.field("multi", &self.multi)
+			.field("ignore_case", &self.ignore_case)
+			.field("show_hidden", &self.show_hidden)
+			.field("show_provenance", &self.show_provenance)
+			.field("gate", &self.gate.is_some())
+			.field("localize", &self.localize.is_some())
+			.finish()
+	}
+}
+
+/// Console with options.
+///
+/// The methods mirror the free functions in this module, configured by the [`Options`].
+///
+/// ```
+/// let mut volume = 0.5;
+/// let mut gravity = 800;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("Volume", &mut volume, &1.0));
+/// 	f(&mut cvar::Property("gravity", &mut gravity, &800).with_flags(cvar::flags::CHEAT));
+/// });
+///
+/// let console = cvar::console::Console::new(cvar::console::Options {
+/// 	ignore_case: true,
+/// 	gate: Some(&cvar::CheatGate(false)),
+/// 	..Default::default()
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(console.set(&mut root, "volume", "0.25", &mut writer));
+/// assert!(!console.set(&mut root, "gravity", "100", &mut writer));
+/// assert_eq!(writer, "error: gravity \"100\": cheats are not enabled\n");
+/// assert_eq!(console.get(&mut root, "VOLUME").as_deref(), Some("0.25"));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Console<'a> {
+	pub options: Options<'a>,
+}
+
+impl<'a> Console<'a> {
+	/// Creates a console with the given options.
+	#[inline]
+	pub const fn new(options: Options<'a>) -> Console<'a> {
+		Console { options }
+	}
+
+	// Wraps the root in the gate if there is one
+	#[inline]
+	fn with_root<R>(&self, root: &mut dyn IVisit, f: impl FnOnce(&mut dyn IVisit) -> R) -> R {
+		match self.options.gate {
+			Some(gate) => f(&mut Gate(root, gate)),
+			None => f(root),
+		}
+	}
+
+	/// Pokes the cvar tree.
+	///
+	/// See [`poke`] for more information.
+	#[inline]
+	pub fn poke(&self, root: &mut dyn IVisit, path: &str, args: Option<&str>, writer: &mut dyn IWrite) -> bool {
+		self.with_root(root, |root| _poke(root, path, args, &self.options, writer))
+	}
+
+	/// Sets a property's value parsed from a string.
+	///
+	/// See [`set`] for more information.
+	#[inline]
+	pub fn set(&self, root: &mut dyn IVisit, path: &str, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.with_root(root, |root| _set(root, path, val, &self.options, writer))
+	}
+
+	/// Gets a property's value as a string.
+	///
+	/// See [`get`] for more information.
+	#[inline]
+	pub fn get(&self, root: &mut dyn IVisit, path: &str) -> Option<String> {
+		self.with_root(root, |root| _get(root, path, &self.options))
+	}
+
+	/// Formats a property's value into the destination without allocating.
+	///
+	/// See [`get_into`] for more information.
+	#[inline]
+	pub fn get_into(&self, root: &mut dyn IVisit, path: &str, dest: &mut dyn fmt::Write) -> bool {
+		self.with_root(root, |root| _get_into(root, path, &self.options, dest))
+	}
+
+	/// Sets a property's value directly.
+	///
+	/// See [`set_value`] for more information.
+	#[inline]
+	pub fn set_value(&self, root: &mut dyn IVisit, path: &str, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		self.with_root(root, |root| _set_value(root, path, val, &self.options, writer))
+	}
+
+	/// Gets a property's value directly.
+	///
+	/// See [`get_value`] for more information.
+	#[inline]
+	pub fn get_value<T: Clone + 'static>(&self, root: &mut dyn IVisit, path: &str) -> Option<T> {
+		self.with_root(root, |root| _get_value(root, path, &self.options))
+	}
+
+	/// Toggles a property's value.
+	///
+	/// See [`toggle`] for more information.
+	#[inline]
+	pub fn toggle(&self, root: &mut dyn IVisit, path: &str, writer: &mut dyn IWrite) -> bool {
+		self.with_root(root, |root| _toggle(root, path, &self.options, writer))
+	}
+
+	/// Invokes an action.
+	///
+	/// Unknown and ambiguous paths are reported to the writer, see [`invoke_with`] for more information.
+	#[inline]
+	pub fn invoke(&self, root: &mut dyn IVisit, path: &str, args: &str, writer: &mut dyn IWrite) -> bool {
+		self.with_root(root, |root| _invoke(root, path, args, &self.options, writer))
+	}
+
+	/// Gets the configuration layer a property's value came from.
+	///
+	/// See [`provenance`] for more information.
+	#[inline]
+	pub fn provenance(&self, root: &mut dyn IVisit, path: &str) -> Option<Layer> {
+		self.with_root(root, |root| _provenance(root, path, &self.options))
+	}
+
+	/// Gets a node's display name.
+	///
+	/// See [`display_name`] for more information.
+	#[inline]
+	pub fn display_name(&self, root: &mut dyn IVisit, path: &str) -> Option<String> {
+		self.with_root(root, |root| _display_name(root, path, &self.options))
+	}
+
+	/// Resets properties to their default.
+	///
+	/// See [`reset`] for more information.
+	#[inline]
+	pub fn reset(&self, root: &mut dyn IVisit, path: &str) -> bool {
+		self.with_root(root, |root| _reset(root, path, &self.options))
+	}
+
+	/// Resets all properties to their default.
+	///
+	/// See [`reset_all`] for more information.
+	#[inline]
+	pub fn reset_all(&self, root: &mut dyn IVisit) {
+		self.with_root(root, |root| reset_all(root))
+	}
+
+	/// Lists all properties and actions in the visitor.
+	///
+	/// See [`print`] for more information.
+	#[inline]
+	pub fn print(&self, root: &mut dyn IVisit, path: &str, writer: &mut dyn IWrite) {
+		self.with_root(root, |root| _print(root, path, &self.options, writer))
+	}
+
+	/// Lists all properties and actions in the visitor including hidden properties.
+	///
+	/// See [`print_all`] for more information.
+	#[inline]
+	pub fn print_all(&self, root: &mut dyn IVisit, path: &str, writer: &mut dyn IWrite) {
+		self.with_root(root, |root| _print(root, path, &Options { show_hidden: true, ..self.options }, writer))
+	}
+
+	/// Lists all properties and actions under the path sorted by their full path.
+	///
+	/// See [`print_sorted`] for more information.
+	#[inline]
+	pub fn print_sorted(&self, root: &mut dyn IVisit, path: &str, writer: &mut dyn IWrite) {
+		self.with_root(root, |root| _print_sorted(root, path, &self.options, writer))
+	}
+
+	/// Finds a cvar by its path.
+	///
+	/// Returns the number of nodes found with this path, see [`find_with`] for more information.
+	#[inline]
+	pub fn find<F: FnMut(&mut dyn INode)>(&self, root: &mut dyn IVisit, path: &str, mut f: F) -> usize {
+		self.with_root(root, |root| find_opts(root, path, &self.options, &mut f))
+	}
+
+	/// Completes a partial path.
+	///
+	/// See [`complete`] for more information.
+	#[inline]
+	pub fn complete(&self, root: &mut dyn IVisit, partial: &str) -> Vec<String> {
+		self.with_root(root, |root| _complete(root, partial, &self.options))
+	}
+
+	/// Searches the tree and prints every node whose path, description or value contains the text.
+	///
+	/// See [`grep`] for more information.
+	#[inline]
+	pub fn grep(&self, root: &mut dyn IVisit, text: &str, writer: &mut dyn IWrite) -> bool {
+		self.with_root(root, |root| _grep(root, text, &self.options, writer))
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Splits an argument string into whitespace separated tokens.
+///
+/// Tokens wrapped in double quotes may contain whitespace, the quotes are not part of the token.
+/// Inside quotes a backslash escapes the next character so `\"` does not end the token.
+/// Escape sequences are not processed, the returned slice contains them verbatim, see [`Tokenize::next_unescaped`].
+/// Backslashes outside quotes are not escapes so paths like `C:\dir\file` are kept as is.
+///
+/// ```
+/// let tokens: Vec<&str> = cvar::console::tokenize(r#"  say "hello world"   "a \"b\"" c"#).collect();
+/// assert_eq!(tokens, ["say", "hello world", r#"a \"b\""#, "c"]);
+///
+/// let mut tokens = cvar::console::tokenize(r#"C:\dir\file "a \"b\"""#);
+/// assert_eq!(tokens.next_unescaped().as_deref(), Some(r"C:\dir\file"));
+/// assert_eq!(tokens.next_unescaped().as_deref(), Some(r#"a "b""#));
+/// ```
+#[inline]
+pub fn tokenize(args: &str) -> Tokenize<'_> {
+	Tokenize { args, quoted: false }
+}
+
+/// Iterator over the tokens of an argument string.
+///
+/// See [`tokenize`] for more information.
+#[derive(Clone, Debug)]
+pub struct Tokenize<'a> {
+	args: &'a str,
+	quoted: bool,
+}
+
+impl<'a> Tokenize<'a> {
+	/// Returns the remaining unparsed argument string.
+	#[inline]
+	pub fn remainder(&self) -> &'a str {
+		self.args.trim_start()
+	}
+
+	/// Returns true if the last token returned was wrapped in double quotes.
+	#[inline]
+	pub fn quoted(&self) -> bool {
+		self.quoted
+	}
+
+	/// Returns the next token with its escape sequences removed.
+	///
+	/// Only quoted tokens are unescaped, see [`unescape`].
+	pub fn next_unescaped(&mut self) -> Option<Cow<'a, str>> {
+		let token = self.next()?;
+		Some(if self.quoted { unescape(token) } else { Cow::Borrowed(token) })
+	}
+}
+
+impl<'a> Iterator for Tokenize<'a> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<&'a str> {
+		let args = self.args.trim_start();
+		self.quoted = args.starts_with('"');
+		if args.is_empty() {
+			self.args = args;
+			return None;
+		}
+		if let Some(quoted) = args.strip_prefix('"') {
+			let mut escape = false;
+			for (i, chr) in quoted.char_indices() {
+				if escape {
+					escape = false;
+				}
+				else if chr == '\\' {
+					escape = true;
+				}
+				else if chr == '"' {
+					self.args = &quoted[i + 1..];
+					return Some(&quoted[..i]);
+				}
+			}
+			// Unterminated quotes extend to the end of the string
+			self.args = "";
+			Some(quoted)
+		}
+		else {
+			let end = args.find(char::is_whitespace).unwrap_or(args.len());
+			self.args = &args[end..];
+			Some(&args[..end])
+		}
+	}
+}
+
+/// Removes the backslashes escaping the characters of a quoted token returned by [`tokenize`].
+///
+/// Unquoted tokens contain no escape sequences and should not be unescaped, see [`Tokenize::next_unescaped`].
+/// Borrows the token if it contains no backslashes.
+pub fn unescape(token: &str) -> Cow<'_, str> {
+	if !token.contains('\\') {
+		return Cow::Borrowed(token);
+	}
+	let mut result = String::with_capacity(token.len());
+	let mut chars = token.chars();
+	while let Some(chr) = chars.next() {
+		match chr {
+			// A trailing backslash is kept as is
+			'\\' => result.push(chars.next().unwrap_or('\\')),
+			chr => result.push(chr),
+		}
+	}
+	Cow::Owned(result)
+}
+
+#[test]
+fn test_tokenize() {
+	let tokens = |args| tokenize(args).collect::<Vec<_>>();
+	assert_eq!(tokens(""), [""; 0]);
+	assert_eq!(tokens("  \t "), [""; 0]);
+	assert_eq!(tokens("a  b\tc "), ["a", "b", "c"]);
+	assert_eq!(tokens(r#""a b" c"#), ["a b", "c"]);
+	assert_eq!(tokens(r#""" x"#), ["", "x"]);
+	assert_eq!(tokens(r#""a \" b""#), [r#"a \" b"#]);
+	assert_eq!(tokens(r#""a \\" b"#), [r#"a \\"#, "b"]);
+	assert_eq!(tokens(r#"a "unterminated b"#), ["a", "unterminated b"]);
+	let mut iter = tokenize("first second third");
+	assert_eq!(iter.next(), Some("first"));
+	assert_eq!(iter.remainder(), "second third");
+	let mut iter = tokenize(r#"C:\dir\file "a \" b" \"x"#);
+	assert_eq!(iter.next_unescaped().as_deref(), Some(r"C:\dir\file"));
+	assert!(!iter.quoted());
+	assert_eq!(iter.next_unescaped().as_deref(), Some(r#"a " b"#));
+	assert!(iter.quoted());
+	assert_eq!(iter.next_unescaped().as_deref(), Some(r#"\"x"#));
+	assert_eq!(iter.next_unescaped(), None);
+	assert!(matches!(unescape("plain"), Cow::Borrowed("plain")));
+	assert_eq!(unescape(r#"a \" b"#), r#"a " b"#);
+	assert_eq!(unescape(r#"a \\"#), r#"a \"#);
+	assert_eq!(unescape(r#"trailing \"#), r#"trailing \"#);
+}
@@ -0,0 +1,251 @@
+use super::*;
+
+/// Node visitor which overrides the defaults of its properties.
+///
+/// Wraps a visitor and replaces the default value of the properties listed in the table.
+/// The table maps the path of the property relative to the wrapped visitor to its default value.
+/// The overridden default is used by [`reset`](IProperty::reset) and [`state`](IProperty::state) and is reported as a string by [`default_value`](IProperty::default_value).
+///
+/// ```
+/// struct Mouse {
+/// 	sensitivity: f32,
+/// }
+///
+/// impl cvar::IVisit for Mouse {
+/// 	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn cvar::INode)) {
+/// 		f(&mut cvar::Property("sensitivity", &mut self.sensitivity, &1.0));
+/// 	}
+/// }
+///
+/// let mut casual = Mouse { sensitivity: 2.0 };
+/// let mut competitive = Mouse { sensitivity: 2.0 };
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("casual", &mut casual));
+/// 	f(&mut cvar::List("competitive", &mut cvar::Defaults(&mut competitive, &[("sensitivity", "0.5")])));
+/// });
+///
+/// cvar::console::reset_all(&mut root);
+/// assert_eq!(cvar::console::get(&mut root, "casual.sensitivity").as_deref(), Some("1"));
+/// assert_eq!(cvar::console::get(&mut root, "competitive.sensitivity").as_deref(), Some("0.5"));
+/// ```
+pub struct Defaults<'a, 'x> {
+	root: &'x mut dyn IVisit,
+	defaults: &'a [(&'a str, &'a str)],
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn Defaults<'a, 'x>(root: &'x mut dyn IVisit, defaults: &'a [(&'a str, &'a str)]) -> Defaults<'a, 'x> {
+	Defaults { root, defaults }
+}
+
+impl<'a, 'x> Defaults<'a, 'x> {
+	#[inline]
+	pub fn new(root: &'x mut dyn IVisit, defaults: &'a [(&'a str, &'a str)]) -> Defaults<'a, 'x> {
+		Defaults { root, defaults }
+	}
+}
+
+impl<'a, 'x> fmt::Debug for Defaults<'a, 'x> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Defaults")
+			.field("defaults", &self.defaults)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a, 'x> IVisit for Defaults<'a, 'x> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_defaults(self.root, &mut String::new(), self.defaults, f);
+	}
+}
+
+fn visit_defaults(root: &mut dyn IVisit, path: &mut String, defaults: &[(&str, &str)], f: &mut dyn FnMut(&mut dyn INode)) {
+	root.visit(&mut |node| {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(node.name());
+		match node.as_node() {
+			Node::Prop(prop) => {
+				match defaults.iter().find(|&&(key, _)| key == path.as_str()) {
+					Some(&(_, default)) => f(&mut DefaultsProp { prop, default: default.to_string() }),
+					None => f(prop.as_inode()),
+				}
+			},
+			Node::List(list) => f(&mut DefaultsList { list, path, defaults }),
+			Node::Action(_) => f(node),
+		}
+		path.truncate(len);
+	});
+}
+
+struct DefaultsProp<'a> {
+	prop: &'a mut dyn IProperty,
+	default: String,
+}
+
+impl<'a> INode for DefaultsProp<'a> {
+	fn name(&self) -> &str {
+		self.prop.name()
+	}
+
+	fn description(&self) -> &str {
+		self.prop.description()
+	}
+
+	fn label(&self) -> &str {
+		self.prop.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.prop.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.prop.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IProperty for DefaultsProp<'a> {
+	fn get_value(&self) -> &dyn IValue {
+		self.prop.get_value()
+	}
+
+	fn persist_value(&self) -> &dyn IValue {
+		self.prop.persist_value()
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		self.prop.set_value(val, writer)
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.set(val, writer)
+	}
+
+	fn set_layer(&mut self, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.set_layer(layer, val, writer)
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.validate(val, writer)
+	}
+
+	fn reset(&mut self) {
+		// Properties whose default cannot be changed are set to the overridden default instead
+		if self.prop.set_default(&self.default, &mut NullWriter) {
+			self.prop.reset();
+		}
+		else {
+			self.prop.set(&self.default, &mut NullWriter);
+		}
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		&self.default
+	}
+
+	fn state(&self) -> PropState {
+		let value = self.prop.get_value().to_string();
+		let equal = value == self.default || match (value.parse::<f64>(), self.default.parse::<f64>()) {
+			(Ok(a), Ok(b)) => (a - b).abs() <= self.prop.epsilon(),
+			_ => false,
+		};
+		match equal {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.prop.flags()
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.prop.epsilon()
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.prop.min_value()
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.prop.max_value()
+	}
+
+	fn provenance(&self) -> Layer {
+		match self.state() {
+			PropState::Default => Layer::Default,
+			_ => self.prop.provenance(),
+		}
+	}
+
+	#[cfg(feature = "type_name")]
+	fn type_name(&self) -> &str {
+		self.prop.type_name()
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		self.prop.values()
+	}
+}
+
+struct DefaultsList<'a> {
+	list: &'a mut dyn IList,
+	path: &'a str,
+	defaults: &'a [(&'a str, &'a str)],
+}
+
+impl<'a> INode for DefaultsList<'a> {
+	fn name(&self) -> &str {
+		self.list.name()
+	}
+
+	fn description(&self) -> &str {
+		self.list.description()
+	}
+
+	fn label(&self) -> &str {
+		self.list.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.list.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.list.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IList for DefaultsList<'a> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a> IVisit for DefaultsList<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let mut path = self.path.to_string();
+		visit_defaults(self.list.as_ivisit(), &mut path, self.defaults, f);
+	}
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+/// Owned list of nodes which can be changed at runtime.
+///
+/// Nodes are inserted and removed by name, plugins loaded at runtime attach their nodes to it and detach them when unloaded.
+/// The name is expected to be the name of the node, the nodes are visited in insertion order.
+///
+/// ```
+/// let mut plugins = cvar::DynamicList::new();
+/// plugins.insert("gravity", Box::new(cvar::OwnedProp(String::from("gravity"), 9.81, 9.81)));
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("plugins", &mut plugins));
+/// });
+/// assert!(cvar::console::set_silent(&mut root, "plugins.gravity", "1.62"));
+/// # drop(root);
+///
+/// assert!(plugins.remove("gravity").is_some());
+/// assert!(plugins.is_empty());
+/// ```
+#[derive(Default)]
+pub struct DynamicList {
+	nodes: Vec<(String, Box<dyn INode + Send>)>,
+}
+
+impl DynamicList {
+	/// Creates an empty list.
+	#[inline]
+	pub const fn new() -> DynamicList {
+		DynamicList { nodes: Vec::new() }
+	}
+
+	/// Returns the number of nodes.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.nodes.len()
+	}
+
+	/// Returns `true` if the list has no nodes.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.nodes.is_empty()
+	}
+
+	/// Returns the names of the nodes in visitation order.
+	pub fn names(&self) -> impl Iterator<Item = &str> {
+		self.nodes.iter().map(|(name, _)| name.as_str())
+	}
+
+	/// Inserts a node.
+	///
+	/// Replaces the node with the same name in place and returns it.
+	pub fn insert(&mut self, name: &str, node: Box<dyn INode + Send>) -> Option<Box<dyn INode + Send>> {
+		match self.nodes.iter_mut().find(|(key, _)| key == name) {
+			Some((_, old)) => Some(std::mem::replace(old, node)),
+			None => {
+				self.nodes.push((name.to_string(), node));
+				None
+			},
+		}
+	}
+
+	/// Removes the node with the name and returns it.
+	pub fn remove(&mut self, name: &str) -> Option<Box<dyn INode + Send>> {
+		let index = self.nodes.iter().position(|(key, _)| key == name)?;
+		Some(self.nodes.remove(index).1)
+	}
+
+	/// Returns the node with the name.
+	pub fn get_mut(&mut self, name: &str) -> Option<&mut dyn INode> {
+		self.nodes.iter_mut().find(|(key, _)| key == name).map(|(_, node)| node.as_inode())
+	}
+
+	/// Removes all the nodes.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.nodes.clear();
+	}
+}
+
+impl fmt::Debug for DynamicList {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("DynamicList")
+			.field("names", &self.names().collect::<Vec<_>>())
+			.finish()
+	}
+}
+
+impl IVisit for DynamicList {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		for (_, node) in &mut self.nodes {
+			f(node.as_inode());
+		}
+	}
+}
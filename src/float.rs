@@ -0,0 +1,482 @@
+use super::*;
+
+/// How [`FloatValue`] formats its value.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum FloatFormat {
+	/// The shortest representation which parses back to the same value.
+	#[default]
+	Shortest,
+	/// Fixed number of decimals, eg. `0.300`.
+	Fixed(usize),
+	/// Fixed number of decimals with trailing zeros removed, eg. `0.3`.
+	Trimmed(usize),
+	/// Scientific notation with a fixed number of decimals, eg. `3.00e-1`.
+	Scientific(usize),
+}
+
+/// Float value with a display format.
+///
+/// ```
+/// use cvar::{FloatFormat, FloatValue};
+///
+/// let value = 0.1 + 0.2;
+/// assert_eq!(FloatValue { value, format: FloatFormat::Shortest }.to_string(), "0.30000000000000004");
+/// assert_eq!(FloatValue { value, format: FloatFormat::Fixed(3) }.to_string(), "0.300");
+/// assert_eq!(FloatValue { value, format: FloatFormat::Trimmed(3) }.to_string(), "0.3");
+/// assert_eq!(FloatValue { value, format: FloatFormat::Scientific(2) }.to_string(), "3.00e-1");
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FloatValue<T> {
+	pub value: T,
+	pub format: FloatFormat,
+}
+
+impl<T: fmt::Display + fmt::LowerExp> fmt::Display for FloatValue<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.format {
+			FloatFormat::Shortest => write!(f, "{}", self.value),
+			FloatFormat::Fixed(decimals) => write!(f, "{:.*}", decimals, self.value),
+			FloatFormat::Trimmed(decimals) => {
+				let fixed = format!("{:.*}", decimals, self.value);
+				let trimmed = match fixed.contains('.') {
+					true => fixed.trim_end_matches('0').trim_end_matches('.'),
+					false => &fixed,
+				};
+				// Avoid displaying negative zero after rounding
+				f.write_str(if trimmed == "-0" { "0" } else { trimmed })
+			},
+			FloatFormat::Scientific(decimals) => write!(f, "{:.*e}", decimals, self.value),
+		}
+	}
+}
+
+/// Float property node with a display format.
+///
+/// The value is stored with full precision, only its display is formatted, see [`FloatFormat`].
+/// Configuration files save the displayed value, choose enough decimals for the value to round-trip.
+///
+/// ```
+/// let mut friction = 0.1 + 0.2;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::FloatProp("friction", &mut friction, &0.3).with_precision(3));
+/// });
+///
+/// assert_eq!(cvar::console::get(&mut root, "friction").as_deref(), Some("0.300"));
+/// ```
+pub struct FloatProp<'a, 'x, T: 'static> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	epsilon: f64,
+	variable: &'x mut T,
+	default: &'a T,
+	finite: bool,
+	// The displayed value and default value
+	display: FloatValue<T>,
+	default_display: FloatValue<T>,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn FloatProp<'a, 'x, T: Copy>(name: &'a str, variable: &'x mut T, default: &'a T) -> FloatProp<'a, 'x, T> {
+	FloatProp::new(name, variable, default)
+}
+
+impl<'a, 'x, T: Copy> FloatProp<'a, 'x, T> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut T, default: &'a T) -> FloatProp<'a, 'x, T> {
+		let display = FloatValue { value: *variable, format: FloatFormat::Shortest };
+		let default_display = FloatValue { value: *default, format: FloatFormat::Shortest };
+		FloatProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, epsilon: 0.0, variable, default, finite: false, display, default_display }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+
+	/// Sets the tolerance for comparing float values.
+	///
+	/// The value is considered the default if it differs less than the tolerance, see [`IProperty::epsilon`].
+	#[inline]
+	pub fn with_epsilon(self, epsilon: f64) -> Self {
+		Self { epsilon, ..self }
+	}
+
+	/// Sets the display format.
+	#[inline]
+	pub fn with_format(mut self, format: FloatFormat) -> Self {
+		self.display.format = format;
+		self.default_display.format = format;
+		self
+	}
+
+	/// Displays the value with a fixed number of decimals.
+	#[inline]
+	pub fn with_precision(self, decimals: usize) -> Self {
+		self.with_format(FloatFormat::Fixed(decimals))
+	}
+
+	/// Rejects NaN and infinite values.
+	///
+	/// ```
+	/// let mut friction = 0.3;
+	/// let mut root = cvar::Visit(|f| {
+	/// 	f(&mut cvar::FloatProp("friction", &mut friction, &0.3).with_finite(true));
+	/// });
+	///
+	/// let mut writer = String::new();
+	/// assert!(!cvar::console::set(&mut root, "friction", "inf", &mut writer));
+	/// assert_eq!(writer, "error: friction \"inf\": value inf is not finite\n");
+	/// ```
+	#[inline]
+	pub fn with_finite(self, finite: bool) -> Self {
+		Self { finite, ..self }
+	}
+}
+
+impl<'a, 'x, T: Copy + fmt::Display + 'static> FloatProp<'a, 'x, T> {
+	// Stores the value unless it is rejected as non-finite
+	fn store(&mut self, val: T, writer: &mut dyn IWrite) -> bool {
+		if !self.accept(&val, writer) {
+			return false;
+		}
+		*self.variable = val;
+		self.display.value = val;
+		true
+	}
+
+	// Writes the reason the value is rejected
+	fn accept(&self, val: &T, writer: &mut dyn IWrite) -> bool {
+		if self.finite && !is_finite(val) {
+			let _ = write_not_finite(writer, val);
+			return false;
+		}
+		true
+	}
+}
+
+impl<'a, 'x, T> INode for FloatProp<'a, 'x, T>
+	where T: Copy + PartialEq + fmt::Display + fmt::LowerExp + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x, T> IProperty for FloatProp<'a, 'x, T>
+	where T: Copy + PartialEq + fmt::Display + fmt::LowerExp + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn get_value(&self) -> &dyn IValue {
+		&self.display
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(&val) = val.downcast_ref::<T>() {
+			self.store(val, writer)
+		}
+		else {
+			let _ = write_mismatched_types::<T>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => self.store(val, writer),
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => self.accept(&val, writer),
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn reset(&mut self) {
+		*self.variable = *self.default;
+		self.display.value = *self.default;
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		&self.default_display
+	}
+
+	fn state(&self) -> PropState {
+		match values_eq(&*self.variable, self.default, self.epsilon) {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.epsilon
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Percentage value.
+///
+/// Holds a fraction where `1.0` is 100%.
+/// Parses a percentage such as `75%` or a fraction such as `0.75`, always formats as a percentage.
+///
+/// ```
+/// let mut volume = 0.8;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::PercentProp("volume", &mut volume, &0.8));
+/// });
+///
+/// assert_eq!(cvar::console::get(&mut root, "volume").as_deref(), Some("80%"));
+/// assert!(cvar::console::set_silent(&mut root, "volume", "0.255"));
+/// assert_eq!(cvar::console::get(&mut root, "volume").as_deref(), Some("25.5%"));
+///
+/// let mut writer = String::new();
+/// assert!(cvar::console::set(&mut root, "volume", "150%", &mut writer));
+/// assert_eq!(writer, "volume: value 150% clamped to 100%\n");
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct PercentValue(pub f32);
+
+impl PercentValue {
+	/// Transmutes to a `&PercentValue`.
+	#[inline]
+	pub fn from_ref(value: &f32) -> &Self {
+		unsafe { &*(value as *const f32 as *const Self) }
+	}
+	/// Transmutes to a `&mut PercentValue`.
+	#[inline]
+	pub fn from_mut(value: &mut f32) -> &mut Self {
+		unsafe { &mut *(value as *mut f32 as *mut Self) }
+	}
+}
+
+impl From<f32> for PercentValue {
+	#[inline]
+	fn from(value: f32) -> Self {
+		Self(value)
+	}
+}
+impl AsRef<f32> for PercentValue {
+	#[inline]
+	fn as_ref(&self) -> &f32 {
+		&self.0
+	}
+}
+impl AsMut<f32> for PercentValue {
+	#[inline]
+	fn as_mut(&mut self) -> &mut f32 {
+		&mut self.0
+	}
+}
+
+impl fmt::Display for PercentValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Two decimals hide the rounding noise of scaling the fraction
+		let percent = FloatValue { value: self.0 * 100.0, format: FloatFormat::Trimmed(2) };
+		write!(f, "{percent}%")
+	}
+}
+
+impl FromStr for PercentValue {
+	type Err = ParsePercentError;
+	fn from_str(s: &str) -> Result<Self, ParsePercentError> {
+		let value = match s.strip_suffix('%') {
+			Some(percent) => percent.trim_end().parse::<f32>().map(|percent| percent / 100.0),
+			None => s.parse::<f32>(),
+		};
+		match value {
+			Ok(value) if value.is_finite() => Ok(PercentValue(value)),
+			_ => Err(ParsePercentError),
+		}
+	}
+}
+
+/// Error parsing a [`PercentValue`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParsePercentError;
+
+impl fmt::Display for ParsePercentError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("expected a percentage such as 75% or a fraction such as 0.75")
+	}
+}
+
+impl StdError for ParsePercentError {}
+
+/// Percentage property node clamped between 0% and 100%.
+///
+/// Values outside the bounds are clamped with a note, use [`ClampedProp`] with [`PercentValue`] for other bounds.
+#[allow(non_snake_case)]
+#[inline]
+pub fn PercentProp<'a, 'x>(name: &'a str, value: &'x mut f32, default: &'a f32) -> ClampedProp<'a, 'x, PercentValue> {
+	ClampedProp(name, PercentValue::from_mut(value), PercentValue::from_ref(default), Some(&PercentValue(0.0)), Some(&PercentValue(1.0)))
+		.with_out_of_range(OutOfRange::Clamp)
+}
+
+//----------------------------------------------------------------
+
+/// Angle value in radians, parsed and formatted in degrees.
+///
+/// Parses degrees with an optional `deg` or `°` suffix, or radians with a `rad` suffix.
+/// Formats in degrees with at most four decimals.
+///
+/// ```
+/// let mut fov = 90f32.to_radians();
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::AngleProp("fov", &mut fov, &90f32.to_radians()));
+/// });
+///
+/// assert_eq!(cvar::console::get(&mut root, "fov").as_deref(), Some("90"));
+/// assert!(cvar::console::set_silent(&mut root, "fov", "1rad"));
+/// assert_eq!(cvar::console::get(&mut root, "fov").as_deref(), Some("57.2958"));
+/// assert!(cvar::console::set_silent(&mut root, "fov", "110°"));
+/// # drop(root);
+/// assert_eq!(fov, 110f32.to_radians());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct AngleValue(pub f32);
+
+impl AngleValue {
+	/// Transmutes to a `&AngleValue`.
+	#[inline]
+	pub fn from_ref(value: &f32) -> &Self {
+		unsafe { &*(value as *const f32 as *const Self) }
+	}
+	/// Transmutes to a `&mut AngleValue`.
+	#[inline]
+	pub fn from_mut(value: &mut f32) -> &mut Self {
+		unsafe { &mut *(value as *mut f32 as *mut Self) }
+	}
+}
+
+impl From<f32> for AngleValue {
+	#[inline]
+	fn from(value: f32) -> Self {
+		Self(value)
+	}
+}
+impl AsRef<f32> for AngleValue {
+	#[inline]
+	fn as_ref(&self) -> &f32 {
+		&self.0
+	}
+}
+impl AsMut<f32> for AngleValue {
+	#[inline]
+	fn as_mut(&mut self) -> &mut f32 {
+		&mut self.0
+	}
+}
+
+impl fmt::Display for AngleValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let degrees = FloatValue { value: self.0.to_degrees(), format: FloatFormat::Trimmed(4) };
+		fmt::Display::fmt(&degrees, f)
+	}
+}
+
+impl FromStr for AngleValue {
+	type Err = ParseAngleError;
+	fn from_str(s: &str) -> Result<Self, ParseAngleError> {
+		let value = if let Some(radians) = s.strip_suffix("rad") {
+			radians.trim_end().parse::<f32>()
+		}
+		else {
+			let degrees = s.strip_suffix("deg").or_else(|| s.strip_suffix('°')).unwrap_or(s);
+			degrees.trim_end().parse::<f32>().map(f32::to_radians)
+		};
+		match value {
+			Ok(value) if value.is_finite() => Ok(AngleValue(value)),
+			_ => Err(ParseAngleError),
+		}
+	}
+}
+
+/// Error parsing an [`AngleValue`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParseAngleError;
+
+impl fmt::Display for ParseAngleError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("expected an angle in degrees such as 90 or in radians such as 1.57rad")
+	}
+}
+
+impl StdError for ParseAngleError {}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn AngleProp<'a, 'x>(name: &'a str, value: &'x mut f32, default: &'a f32) -> Property<'a, 'x, AngleValue> {
+	Property(name, AngleValue::from_mut(value), AngleValue::from_ref(default))
+}
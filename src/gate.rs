@@ -0,0 +1,550 @@
+use super::*;
+
+/// Operation requested on a node.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Access {
+	/// Discover the node and read its value.
+	Get,
+	/// Change the value of a property.
+	Set,
+	/// Invoke an action.
+	Invoke,
+}
+
+impl fmt::Display for Access {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Access::Get => "get",
+			Access::Set => "set",
+			Access::Invoke => "invoke",
+		})
+	}
+}
+
+impl FromStr for Access {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Access, String> {
+		match s {
+			"get" => Ok(Access::Get),
+			"set" => Ok(Access::Set),
+			"invoke" => Ok(Access::Invoke),
+			_ => Err(format!("unknown access: {s}")),
+		}
+	}
+}
+
+/// Access control for nodes.
+///
+/// Used by the [`Gate`] visitor to decide which operations are allowed.
+pub trait IGate {
+	/// Returns `true` if the operation on the node at the path is allowed.
+	///
+	/// The flags are the [property flags](IProperty::flags), zero for lists and actions.
+	/// When denied a message explaining why should be written to the writer.
+	///
+	/// Denying [`Access::Get`] hides the node, its message is discarded.
+	fn check(&self, path: &str, flags: u32, access: Access, writer: &mut dyn IWrite) -> bool;
+}
+
+impl<F: Fn(&str, u32, Access, &mut dyn IWrite) -> bool> IGate for F {
+	#[inline]
+	fn check(&self, path: &str, flags: u32, access: Access, writer: &mut dyn IWrite) -> bool {
+		self(path, flags, access, writer)
+	}
+}
+
+impl fmt::Debug for dyn IGate + '_ {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("IGate").finish_non_exhaustive()
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Refuses to set properties with the [`CHEAT`](flags::CHEAT) flag unless cheats are enabled.
+///
+/// ```
+/// let mut gravity = 800;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("gravity", &mut gravity, &800).with_flags(cvar::flags::CHEAT));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut cvar::Gate(&mut root, &cvar::CheatGate(false)), "gravity", "100", &mut writer));
+/// assert_eq!(writer, "error: gravity \"100\": cheats are not enabled\n");
+///
+/// assert!(cvar::console::set(&mut cvar::Gate(&mut root, &cvar::CheatGate(true)), "gravity", "100", &mut writer));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct CheatGate(pub bool);
+
+impl IGate for CheatGate {
+	fn check(&self, _path: &str, flags: u32, access: Access, writer: &mut dyn IWrite) -> bool {
+		let Self(enabled) = *self;
+		if access == Access::Set && flags & flags::CHEAT != 0 && !enabled {
+			let _ = writer.write_str("cheats are not enabled");
+			return false;
+		}
+		true
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Refuses operations which require a higher permission level.
+///
+/// Every rule is a path pattern, the operation and the level required, see [`console::find_matching`] for the pattern syntax.
+/// The highest level of all the matching rules is required, operations without matching rules are allowed.
+///
+/// Use a gate per privilege tier, eg. for the sessions of a remote admin console.
+/// See [`Permissions`] for an owned table of rules which can be loaded from a file.
+///
+/// ```
+/// use cvar::Access;
+///
+/// const RULES: &[(&str, Access, u32)] = &[
+/// 	("*", Access::Set, 1),
+/// 	("server.*", Access::Set, 2),
+/// 	("server.shutdown", Access::Invoke, 3),
+/// ];
+///
+/// let mut volume = 1.0;
+/// let mut max_players = 16;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0));
+/// 	f(&mut cvar::Property("server.max_players", &mut max_players, &16));
+/// 	f(&mut cvar::Action("server.shutdown", |_args, _writer| ()));
+/// });
+///
+/// let moderator = cvar::LevelGate { level: 2, rules: RULES };
+/// let mut writer = String::new();
+/// assert!(cvar::console::set(&mut cvar::Gate(&mut root, &moderator), "server.max_players", "32", &mut writer));
+/// cvar::console::invoke(&mut cvar::Gate(&mut root, &moderator), "server.shutdown", "", &mut writer);
+/// assert_eq!(writer, "error: server.shutdown: requires permission level 3\n");
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct LevelGate<'a> {
+	/// The permission level granted.
+	pub level: u32,
+	/// The path pattern, operation and required level.
+	pub rules: &'a [(&'a str, Access, u32)],
+}
+
+impl<'a> IGate for LevelGate<'a> {
+	fn check(&self, path: &str, _flags: u32, access: Access, writer: &mut dyn IWrite) -> bool {
+		check_level(required_level(self.rules.iter().copied(), path, access), self.level, writer)
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Owned table of permission levels per path pattern.
+///
+/// The owned counterpart of the [`LevelGate`] rules, for tables loaded from configuration files.
+/// The table is written and parsed as one rule per line: the path pattern, the operation and the required level.
+/// Empty lines and lines starting with `#` are ignored.
+///
+/// ```
+/// use cvar::Access;
+///
+/// const ADMIN: u32 = 2;
+/// const USER: u32 = 1;
+///
+/// let mut permissions = cvar::Permissions::new();
+/// permissions.add("sv.*", Access::Set, ADMIN);
+/// permissions.add("cl.*", Access::Set, USER);
+///
+/// let text = permissions.to_string();
+/// assert_eq!(text, "sv.* set 2\ncl.* set 1\n");
+/// assert_eq!(text.parse::<cvar::Permissions>(), Ok(permissions.clone()));
+///
+/// let mut cheats = 0;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("sv.cheats", &mut cheats, &0));
+/// });
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut cvar::Gate(&mut root, &permissions.gate(USER)), "sv.cheats", "1", &mut writer));
+/// assert_eq!(writer, "error: sv.cheats \"1\": requires permission level 2\n");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Permissions {
+	rules: Vec<(String, Access, u32)>,
+}
+
+impl Permissions {
+	/// Creates an empty table.
+	#[inline]
+	pub const fn new() -> Permissions {
+		Permissions { rules: Vec::new() }
+	}
+
+	/// Returns the rules as the path pattern, operation and required level.
+	#[inline]
+	pub fn rules(&self) -> &[(String, Access, u32)] {
+		&self.rules
+	}
+
+	/// Adds a rule requiring the level for the operation on the paths matching the pattern.
+	pub fn add(&mut self, pattern: &str, access: Access, level: u32) {
+		self.rules.push((pattern.to_string(), access, level));
+	}
+
+	/// Returns the highest level required by the matching rules.
+	///
+	/// Returns `None` if no rule matches.
+	pub fn required(&self, path: &str, access: Access) -> Option<u32> {
+		required_level(self.rules.iter().map(|(pattern, access, level)| (pattern.as_str(), *access, *level)), path, access)
+	}
+
+	/// Returns a gate which grants the permission level.
+	#[inline]
+	pub fn gate(&self, level: u32) -> PermissionsGate<'_> {
+		PermissionsGate { permissions: self, level }
+	}
+}
+
+impl fmt::Display for Permissions {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (pattern, access, level) in &self.rules {
+			writeln!(f, "{pattern} {access} {level}")?;
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for Permissions {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Permissions, String> {
+		let mut permissions = Permissions::new();
+		for (index, line) in s.lines().enumerate() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+			let mut parts = line.split_whitespace();
+			let (Some(pattern), Some(access), Some(level), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+				return Err(format!("line {}: expected a pattern, an access and a level", index + 1));
+			};
+			let access = access.parse().map_err(|err| format!("line {}: {err}", index + 1))?;
+			let level = level.parse().map_err(|err| format!("line {}: invalid level: {err}", index + 1))?;
+			permissions.add(pattern, access, level);
+		}
+		Ok(permissions)
+	}
+}
+
+/// Refuses operations which require a higher permission level than granted.
+///
+/// Created by [`Permissions::gate`].
+#[derive(Copy, Clone, Debug)]
+pub struct PermissionsGate<'a> {
+	permissions: &'a Permissions,
+	level: u32,
+}
+
+impl<'a> IGate for PermissionsGate<'a> {
+	fn check(&self, path: &str, _flags: u32, access: Access, writer: &mut dyn IWrite) -> bool {
+		check_level(self.permissions.required(path, access), self.level, writer)
+	}
+}
+
+// Returns the highest level of the rules matching the path and access
+fn required_level<'a>(rules: impl Iterator<Item = (&'a str, Access, u32)>, path: &str, access: Access) -> Option<u32> {
+	rules
+		.filter(|&(pattern, rule_access, _)| rule_access == access && console::glob_match(pattern, path))
+		.map(|(_, _, level)| level)
+		.max()
+}
+
+fn check_level(required: Option<u32>, level: u32, writer: &mut dyn IWrite) -> bool {
+	match required {
+		Some(required) if required > level => {
+			let _ = write!(writer, "requires permission level {required}");
+			false
+		},
+		_ => true,
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Node visitor which guards access to its nodes.
+///
+/// Wraps a visitor and consults the gate before every operation on its nodes.
+/// Nodes for which [`Access::Get`] is denied are hidden.
+pub struct Gate<'a, 'x> {
+	root: &'x mut dyn IVisit,
+	gate: &'a dyn IGate,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn Gate<'a, 'x>(root: &'x mut dyn IVisit, gate: &'a dyn IGate) -> Gate<'a, 'x> {
+	Gate { root, gate }
+}
+
+impl<'a, 'x> Gate<'a, 'x> {
+	#[inline]
+	pub fn new(root: &'x mut dyn IVisit, gate: &'a dyn IGate) -> Gate<'a, 'x> {
+		Gate { root, gate }
+	}
+}
+
+impl<'a, 'x> fmt::Debug for Gate<'a, 'x> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Gate").finish_non_exhaustive()
+	}
+}
+
+impl<'a, 'x> IVisit for Gate<'a, 'x> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_gated(self.root, &mut String::new(), self.gate, f);
+	}
+}
+
+fn visit_gated(root: &mut dyn IVisit, path: &mut String, gate: &dyn IGate, f: &mut dyn FnMut(&mut dyn INode)) {
+	root.visit(&mut |node| {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(node.name());
+		match node.as_node() {
+			Node::Prop(prop) => {
+				if gate.check(path, prop.flags(), Access::Get, &mut NullWriter) {
+					f(&mut GateProp { prop, path, gate });
+				}
+			},
+			Node::List(list) => {
+				if gate.check(path, 0, Access::Get, &mut NullWriter) {
+					f(&mut GateList { list, path, gate });
+				}
+			},
+			Node::Action(act) => {
+				if gate.check(path, 0, Access::Get, &mut NullWriter) {
+					f(&mut GateAction { act, path, gate });
+				}
+			},
+		}
+		path.truncate(len);
+	});
+}
+
+struct GateProp<'a> {
+	prop: &'a mut dyn IProperty,
+	path: &'a str,
+	gate: &'a dyn IGate,
+}
+
+impl<'a> INode for GateProp<'a> {
+	fn name(&self) -> &str {
+		self.prop.name()
+	}
+
+	fn description(&self) -> &str {
+		self.prop.description()
+	}
+
+	fn label(&self) -> &str {
+		self.prop.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.prop.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.prop.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IProperty for GateProp<'a> {
+	fn get_value(&self) -> &dyn IValue {
+		self.prop.get_value()
+	}
+
+	fn persist_value(&self) -> &dyn IValue {
+		self.prop.persist_value()
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		self.gate.check(self.path, self.prop.flags(), Access::Set, writer) && self.prop.set_value(val, writer)
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.gate.check(self.path, self.prop.flags(), Access::Set, writer) && self.prop.set(val, writer)
+	}
+
+	fn set_layer(&mut self, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.gate.check(self.path, self.prop.flags(), Access::Set, writer) && self.prop.set_layer(layer, val, writer)
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.gate.check(self.path, self.prop.flags(), Access::Set, writer) && self.prop.validate(val, writer)
+	}
+
+	fn reset(&mut self) {
+		if self.gate.check(self.path, self.prop.flags(), Access::Set, &mut NullWriter) {
+			self.prop.reset();
+		}
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.prop.default_value()
+	}
+
+	fn set_default(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.gate.check(self.path, self.prop.flags(), Access::Set, writer) && self.prop.set_default(val, writer)
+	}
+
+	fn state(&self) -> PropState {
+		self.prop.state()
+	}
+
+	fn flags(&self) -> u32 {
+		self.prop.flags()
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.prop.epsilon()
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.prop.min_value()
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.prop.max_value()
+	}
+
+	fn provenance(&self) -> Layer {
+		self.prop.provenance()
+	}
+
+	#[cfg(feature = "type_name")]
+	fn type_name(&self) -> &str {
+		self.prop.type_name()
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		self.prop.values()
+	}
+}
+
+struct GateList<'a> {
+	list: &'a mut dyn IList,
+	path: &'a str,
+	gate: &'a dyn IGate,
+}
+
+impl<'a> INode for GateList<'a> {
+	fn name(&self) -> &str {
+		self.list.name()
+	}
+
+	fn description(&self) -> &str {
+		self.list.description()
+	}
+
+	fn label(&self) -> &str {
+		self.list.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.list.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.list.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IList for GateList<'a> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a> IVisit for GateList<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let mut path = self.path.to_string();
+		visit_gated(self.list.as_ivisit(), &mut path, self.gate, f);
+	}
+}
+
+struct GateAction<'a> {
+	act: &'a mut dyn IAction,
+	path: &'a str,
+	gate: &'a dyn IGate,
+}
+
+impl<'a> INode for GateAction<'a> {
+	fn name(&self) -> &str {
+		self.act.name()
+	}
+
+	fn description(&self) -> &str {
+		self.act.description()
+	}
+
+	fn label(&self) -> &str {
+		self.act.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.act.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.act.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Action(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IAction for GateAction<'a> {
+	fn invoke(&mut self, args: &str, writer: &mut dyn IWrite) -> BoxResult {
+		let mut err = String::new();
+		if !self.gate.check(self.path, 0, Access::Invoke, &mut err) {
+			return Err(err.into());
+		}
+		self.act.invoke(args, writer)
+	}
+
+	fn usage(&self) -> &str {
+		self.act.usage()
+	}
+
+	fn complete(&mut self, partial_args: &str) -> Vec<String> {
+		if self.gate.check(self.path, 0, Access::Invoke, &mut String::new()) {
+			self.act.complete(partial_args)
+		}
+		else {
+			Vec::new()
+		}
+	}
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use super::*;
+
+/// Path index for fast lookups.
+///
+/// Every lookup through the [`console`] functions walks the tree comparing names along the way.
+/// For very large trees this index maps every path to a [`PathHandle`](console::PathHandle) built by walking the tree once.
+///
+/// The index does not observe the tree, the host is responsible for keeping it up to date:
+/// [`invalidate`](Self::invalidate) the index when nodes are created or destroyed and [`rebuild`](Self::rebuild) it when convenient.
+/// Lookups which find a stale handle fall back to walking the tree and invalidate the index.
+///
+/// ```
+/// let mut width = 1920;
+/// let mut video = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("width", &mut width, &1920));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("video", &mut video));
+/// });
+///
+/// let mut index = cvar::Index::new();
+/// index.rebuild(&mut root);
+/// assert_eq!(index.len(), 2);
+/// assert!(index.set(&mut root, "video.width", "1280", &mut cvar::NullWriter));
+/// assert_eq!(index.get(&mut root, "video.width").as_deref(), Some("1280"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Index {
+	handles: HashMap<String, console::PathHandle>,
+	valid: bool,
+}
+
+impl Index {
+	/// Creates a new empty index.
+	#[inline]
+	pub fn new() -> Index {
+		Index { handles: HashMap::new(), valid: true }
+	}
+
+	/// Rebuilds the index by walking the tree.
+	///
+	/// If multiple nodes have the same path the index refers to the first one.
+	pub fn rebuild(&mut self, root: &mut dyn IVisit) {
+		self.handles.clear();
+		build_rec(root, &mut String::new(), &mut Vec::new(), &mut self.handles);
+		self.valid = true;
+	}
+
+	/// Rebuilds the index only if it has been invalidated.
+	#[inline]
+	pub fn update(&mut self, root: &mut dyn IVisit) {
+		if !self.valid {
+			self.rebuild(root);
+		}
+	}
+
+	/// Marks the index as out of date.
+	///
+	/// The index keeps using the stale handles until it is rebuilt.
+	#[inline]
+	pub fn invalidate(&mut self) {
+		self.valid = false;
+	}
+
+	/// Returns `false` if the index has been invalidated since it was last rebuilt.
+	#[inline]
+	pub fn is_valid(&self) -> bool {
+		self.valid
+	}
+
+	/// Returns the number of indexed paths.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.handles.len()
+	}
+
+	/// Returns `true` if no paths are indexed.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.handles.is_empty()
+	}
+
+	/// Returns the handle for the path.
+	#[inline]
+	pub fn resolve(&self, path: &str) -> Option<&console::PathHandle> {
+		self.handles.get(path)
+	}
+
+	/// Finds a node by its path and invokes the closure with it.
+	///
+	/// Falls back to [`console::find_with`] with [`MultiMatch::First`](console::MultiMatch::First) if the path is not indexed or its handle is stale.
+	///
+	/// Returns false if no node was found with this path, the closure has not been called.
+	pub fn find<F: FnMut(&mut dyn INode)>(&mut self, root: &mut dyn IVisit, path: &str, mut f: F) -> bool {
+		if let Some(handle) = self.handles.get(path) {
+			if handle.find(root, &mut f) {
+				return true;
+			}
+			self.valid = false;
+		}
+		console::find_with(root, path, console::MultiMatch::First, f) > 0
+	}
+
+	/// Gets a property's value as a string.
+	///
+	/// See [`console::get`] for more information.
+	pub fn get(&mut self, root: &mut dyn IVisit, path: &str) -> Option<String> {
+		let mut result = None;
+		self.find(root, path, |node| {
+			if let Node::Prop(prop) = node.as_node() {
+				result = Some(prop.get_value().to_string());
+			}
+		});
+		result
+	}
+
+	/// Sets a property's value parsed from a string.
+	///
+	/// See [`console::set`] for more information.
+	pub fn set(&mut self, root: &mut dyn IVisit, path: &str, val: &str, writer: &mut dyn IWrite) -> bool {
+		if let Some(handle) = self.handles.get(path) {
+			if let Some(result) = handle.try_set(root, val, writer) {
+				return result;
+			}
+			self.valid = false;
+		}
+		console::set_with(root, path, val, console::MultiMatch::First, writer)
+	}
+}
+
+fn build_rec(list: &mut dyn IVisit, path: &mut String, steps: &mut Vec<(usize, usize)>, handles: &mut HashMap<String, console::PathHandle>) {
+	let mut ordinal = 0;
+	list.visit(&mut |node| {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(node.name());
+		steps.push((ordinal, path.len()));
+		if !handles.contains_key(path.as_str()) {
+			handles.insert(path.clone(), console::PathHandle { path: path.clone(), steps: steps.clone() });
+		}
+		if let Node::List(list) = node.as_node() {
+			build_rec(list.as_ivisit(), path, steps, handles);
+		}
+		steps.pop();
+		path.truncate(len);
+		ordinal += 1;
+	});
+}
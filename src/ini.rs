@@ -0,0 +1,209 @@
+/*!
+INI configuration files.
+
+Every top-level list node is written as a section, its properties are written as `key=value` lines with the key being the path relative to the list.
+Properties at the top-level are written before the first section.
+
+```
+let mut name = String::from("Player");
+let mut width = 1920;
+let mut fullscreen = false;
+let mut video = cvar::Visit(|f| {
+	f(&mut cvar::Property("width", &mut width, &1920));
+	f(&mut cvar::Property("fullscreen", &mut fullscreen, &false));
+});
+let mut root = cvar::Visit(|f| {
+	f(&mut cvar::Property("name", &mut name, &String::new()));
+	f(&mut cvar::List("video", &mut video));
+});
+
+let config = cvar::ini::save(&mut root);
+assert_eq!(config, "name=Player\n\n[video]\nwidth=1920\nfullscreen=false\n");
+
+let mut writer = String::new();
+assert!(cvar::ini::load(&mut root, "; comment\n[video]\nwidth = 1280\n", &mut writer));
+assert_eq!(cvar::console::get(&mut root, "video.width").as_deref(), Some("1280"));
+```
+*/
+
+use super::*;
+
+/// Saves the properties as an INI document.
+///
+/// Values with leading or trailing whitespace are quoted.
+/// Properties with the [`NO_PERSIST`](flags::NO_PERSIST) flag are not saved.
+#[inline]
+pub fn save(root: &mut dyn IVisit) -> String {
+	save_with(root, &SaveOptions::DEFAULT)
+}
+
+/// Saves the properties as an INI document with options.
+///
+/// ```
+/// let mut width = 1920;
+/// let mut video = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("width", &mut width, &1920).with_desc("Horizontal resolution in pixels."));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("video", &mut video));
+/// });
+///
+/// let options = cvar::ini::SaveOptions { comments: true };
+/// let config = cvar::ini::save_with(&mut root, &options);
+/// assert_eq!(config, "[video]\n; Horizontal resolution in pixels.\n; default: 1920\nwidth=1920\n");
+/// ```
+pub fn save_with(root: &mut dyn IVisit, options: &SaveOptions) -> String {
+	let mut globals = String::new();
+	let mut sections = String::new();
+	root.visit(&mut |node| {
+		match node.as_node() {
+			Node::Prop(prop) => {
+				if prop.flags() & flags::NO_PERSIST == 0 {
+					write_prop(&mut globals, prop.name(), prop, options);
+				}
+			},
+			Node::List(list) => {
+				let mut section = String::new();
+				console::walk(list.as_ivisit(), |path, node| {
+					if let Node::Prop(prop) = node.as_node() {
+						if prop.flags() & flags::NO_PERSIST == 0 {
+							write_prop(&mut section, path, prop, options);
+						}
+					}
+				});
+				if !section.is_empty() {
+					sections.push('\n');
+					sections.push('[');
+					sections.push_str(list.name());
+					sections.push_str("]\n");
+					sections.push_str(&section);
+				}
+			},
+			Node::Action(_) => {},
+		}
+	});
+	// Sections are separated by an empty line
+	if globals.is_empty() && sections.starts_with('\n') {
+		sections.remove(0);
+	}
+	globals + &sections
+}
+
+/// Options for [`save_with`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct SaveOptions {
+	/// Write the description and default value of every property as a comment above its line.
+	pub comments: bool,
+}
+
+impl SaveOptions {
+	/// The options used by [`save`].
+	pub const DEFAULT: SaveOptions = SaveOptions {
+		comments: false,
+	};
+}
+
+fn write_prop(buf: &mut String, key: &str, prop: &dyn IProperty, options: &SaveOptions) {
+	if options.comments {
+		for line in prop.description().lines() {
+			buf.push_str("; ");
+			buf.push_str(line);
+			buf.push('\n');
+		}
+		let default = prop.default_value().to_string();
+		buf.push_str("; default: ");
+		buf.push_str(&default.replace('\n', "\\n"));
+		buf.push('\n');
+	}
+	write_line(buf, key, &prop.persist_value().to_string());
+}
+
+fn write_line(buf: &mut String, key: &str, value: &str) {
+	buf.push_str(key);
+	buf.push('=');
+	if value.trim() != value || value.starts_with('"') || value.contains('\n') {
+		buf.push('"');
+		for chr in value.chars() {
+			match chr {
+				'"' => buf.push_str("\\\""),
+				'\\' => buf.push_str("\\\\"),
+				'\n' => buf.push_str("\\n"),
+				chr => buf.push(chr),
+			}
+		}
+		buf.push('"');
+	}
+	else {
+		buf.push_str(value);
+	}
+	buf.push('\n');
+}
+
+/// Loads the properties from an INI document.
+///
+/// Lines starting with `;` or `#` are comments. Keys in a section are prefixed with the section name.
+/// Every key is set in the [`File`](Layer::File) layer, see [`IProperty::set_layer`], malformed lines, unknown keys, invalid values and keys naming actions are reported to the writer and skipped.
+/// Actions are never invoked.
+///
+/// Returns `false` if any line failed to load.
+pub fn load(root: &mut dyn IVisit, text: &str, writer: &mut dyn IWrite) -> bool {
+	let mut result = true;
+	let mut section = "";
+	let mut path = String::new();
+	for (index, line) in text.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+			continue;
+		}
+		if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+			section = name.trim();
+			continue;
+		}
+		let Some((key, value)) = line.split_once('=') else {
+			// error: line 3: expected key=value
+			let _ = writeln!(writer, "error: line {}: expected key=value", index + 1);
+			result = false;
+			continue;
+		};
+		path.clear();
+		if !section.is_empty() {
+			path.push_str(section);
+			path.push('.');
+		}
+		path.push_str(key.trim());
+		let value = unquote(value.trim());
+		result &= console::set_loaded(root, &path, Layer::File, &value, writer);
+	}
+	result
+}
+
+fn unquote(value: &str) -> String {
+	let Some(quoted) = value.strip_prefix('"').and_then(|value| value.strip_suffix('"')) else {
+		return value.to_string();
+	};
+	let mut result = String::with_capacity(quoted.len());
+	let mut chars = quoted.chars();
+	while let Some(chr) = chars.next() {
+		if chr == '\\' {
+			match chars.next() {
+				Some('n') => result.push('\n'),
+				Some(chr) => result.push(chr),
+				None => result.push('\\'),
+			}
+		}
+		else {
+			result.push(chr);
+		}
+	}
+	result
+}
+
+#[test]
+fn test_quoting() {
+	let mut buf = String::new();
+	write_line(&mut buf, "key", " a \"b\" \\ \n");
+	assert_eq!(buf, "key=\" a \\\"b\\\" \\\\ \\n\"\n");
+	let (_, value) = buf.trim_end().split_once('=').unwrap();
+	assert_eq!(unquote(value), " a \"b\" \\ \n");
+	assert_eq!(unquote("plain"), "plain");
+}
@@ -0,0 +1,312 @@
+/*!
+JSON configuration files.
+
+List nodes map onto nested JSON objects.
+
+```
+let mut width = 1920;
+let mut fullscreen = false;
+let mut video = cvar::Visit(|f| {
+	f(&mut cvar::Property("width", &mut width, &1920));
+	f(&mut cvar::Property("fullscreen", &mut fullscreen, &false));
+});
+let mut root = cvar::Visit(|f| {
+	f(&mut cvar::List("video", &mut video));
+});
+
+let config = cvar::json::save(&mut root);
+assert_eq!(config, r#"{"video":{"fullscreen":false,"width":1920}}"#);
+
+let mut writer = String::new();
+assert!(cvar::json::load(&mut root, r#"{"video":{"width":1280,"height":720}}"#, &mut writer));
+assert_eq!(writer, "warning: unknown: video.height\n");
+assert_eq!(cvar::console::get(&mut root, "video.width").as_deref(), Some("1280"));
+```
+*/
+
+use serde_json::{Map, Number, Value};
+use super::*;
+
+/// Saves the properties as a JSON document.
+///
+/// See [`to_value`] for more information.
+#[inline]
+pub fn save(root: &mut dyn IVisit) -> String {
+	to_value(root).to_string()
+}
+
+/// Saves the properties as a JSON value.
+///
+/// List nodes become nested objects, actions and properties with the [`NO_PERSIST`](flags::NO_PERSIST) flag are not saved.
+/// Booleans, integers and floats are saved as their JSON types, all other values are saved as strings.
+pub fn to_value(root: &mut dyn IVisit) -> Value {
+	Value::Object(save_object(root))
+}
+
+fn save_object(root: &mut dyn IVisit) -> Map<String, Value> {
+	let mut map = Map::new();
+	root.visit(&mut |node| {
+		match node.as_node() {
+			Node::Prop(prop) => {
+				if prop.flags() & flags::NO_PERSIST == 0 {
+					map.insert(prop.name().to_string(), to_json(prop.persist_value()));
+				}
+			},
+			Node::List(list) => {
+				let name = list.name().to_string();
+				map.insert(name, Value::Object(save_object(list.as_ivisit())));
+			},
+			Node::Action(_) => {},
+		}
+	});
+	map
+}
+
+fn to_json(value: &dyn IValue) -> Value {
+	let string = value.to_string();
+	if value.is::<bool>() {
+		if let Ok(value) = string.parse() {
+			return Value::Bool(value);
+		}
+	}
+	if value.is::<i8>() || value.is::<i16>() || value.is::<i32>() || value.is::<i64>() || value.is::<isize>() {
+		if let Ok(value) = string.parse::<i64>() {
+			return Value::Number(value.into());
+		}
+	}
+	if value.is::<u8>() || value.is::<u16>() || value.is::<u32>() || value.is::<u64>() || value.is::<usize>() {
+		if let Ok(value) = string.parse::<u64>() {
+			return Value::Number(value.into());
+		}
+	}
+	if value.is::<f32>() || value.is::<f64>() || value.is::<FloatValue<f32>>() || value.is::<FloatValue<f64>>() {
+		if let Some(value) = string.parse().ok().and_then(Number::from_f64) {
+			return Value::Number(value);
+		}
+	}
+	Value::String(string)
+}
+
+/// Loads the properties from a JSON document.
+///
+/// See [`from_value`] for more information.
+pub fn load(root: &mut dyn IVisit, text: &str, writer: &mut dyn IWrite) -> bool {
+	match serde_json::from_str(text) {
+		Ok(value) => from_value(root, &value, writer),
+		Err(err) => {
+			let _ = writeln!(writer, "error: {err}");
+			false
+		},
+	}
+}
+
+/// Loads the properties from a JSON value.
+///
+/// Nested objects address the children of list nodes, every other value is set in the [`File`](Layer::File) layer, see [`IProperty::set_layer`].
+/// Unknown keys are reported to the writer as warnings and skipped, invalid values and keys naming actions are reported as errors.
+/// Actions are never invoked.
+///
+/// Returns `false` if the value is not an object or any property failed to set.
+pub fn from_value(root: &mut dyn IVisit, value: &Value, writer: &mut dyn IWrite) -> bool {
+	match value {
+		Value::Object(map) => load_object(root, map, &mut String::new(), writer),
+		_ => {
+			let _ = writeln!(writer, "error: expected an object");
+			false
+		},
+	}
+}
+
+fn load_object(root: &mut dyn IVisit, map: &Map<String, Value>, path: &mut String, writer: &mut dyn IWrite) -> bool {
+	let mut result = true;
+	for (key, value) in map {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(key);
+		if !console::find(root, path, |_node| ()) {
+			// warning: unknown: cvar.prop
+			let _ = writeln!(writer, "warning: unknown: {path}");
+		}
+		else {
+			result &= match value {
+				Value::Object(map) => load_object(root, map, path, writer),
+				Value::String(val) => console::set_loaded(root, path, Layer::File, val, writer),
+				value => console::set_loaded(root, path, Layer::File, &value.to_string(), writer),
+			};
+		}
+		path.truncate(len);
+	}
+	result
+}
+
+/// Describes every node in the tree as a JSON array.
+///
+/// Every element is an object with the fields of [`console::NodeSchema`], fields which do not apply are `null`.
+///
+/// ```
+/// let mut fov = 90;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ClampedProp("fov", &mut fov, &90, Some(&60), Some(&120)));
+/// });
+///
+/// let schema = cvar::json::schema(&mut root);
+/// assert_eq!(schema[0]["kind"], "prop");
+/// assert_eq!(schema[0]["max"], "120");
+/// ```
+pub fn schema(root: &mut dyn IVisit) -> Value {
+	let nodes = console::schema(root).into_iter().map(|node| {
+		let mut map = Map::new();
+		map.insert("path".to_string(), Value::String(node.path));
+		map.insert("kind".to_string(), Value::String(node.kind.to_string()));
+		#[cfg(feature = "type_name")]
+		map.insert("type".to_string(), Value::String(node.type_name));
+		map.insert("default".to_string(), node.default.map_or(Value::Null, Value::String));
+		map.insert("min".to_string(), node.min.map_or(Value::Null, Value::String));
+		map.insert("max".to_string(), node.max.map_or(Value::Null, Value::String));
+		map.insert("flags".to_string(), Value::Number(node.flags.into()));
+		map.insert("values".to_string(), node.values.map_or(Value::Null, |values| values.into_iter().map(Value::String).collect()));
+		map.insert("description".to_string(), Value::String(node.description));
+		Value::Object(map)
+	});
+	Value::Array(nodes.collect())
+}
+
+/// Kind of problem found by [`validate`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum IssueKind {
+	/// The key does not lead to a node.
+	Unknown,
+	/// An object addresses a node which is not a list, or a value addresses a list or an action.
+	Mismatch,
+	/// The property rejects the value, eg. it does not parse or is out of range.
+	Invalid,
+}
+
+/// Problem found by [`validate`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Issue {
+	/// Full path of the key.
+	pub path: String,
+	pub kind: IssueKind,
+	/// Explanation of the problem.
+	pub message: String,
+}
+
+impl fmt::Display for Issue {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}: {}", self.path, self.message)
+	}
+}
+
+/// Validates a JSON value against the tree without changing it.
+///
+/// Reports unknown keys, keys which address the wrong kind of node and values the properties reject.
+/// Every value is checked with [`IProperty::validate`], the properties and their observers are left untouched.
+///
+/// ```
+/// let mut width = 1920;
+/// let mut fov = 90;
+/// let mut video = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("width", &mut width, &1920));
+/// 	f(&mut cvar::ClampedProp("fov", &mut fov, &90, Some(&60), Some(&120)));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("video", &mut video));
+/// });
+///
+/// let config = serde_json::json!({"video": {"width": "wide", "fov": 100, "height": 720}});
+/// let issues = cvar::json::validate(&mut root, &config);
+/// let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+/// assert_eq!(issues, ["video.height: unknown key", "video.width: invalid digit found in string"]);
+/// assert_eq!(cvar::console::get(&mut root, "video.fov").as_deref(), Some("90"));
+/// ```
+pub fn validate(root: &mut dyn IVisit, value: &Value) -> Vec<Issue> {
+	let mut issues = Vec::new();
+	match value {
+		Value::Object(map) => validate_object(root, map, &mut String::new(), &mut issues),
+		_ => issues.push(Issue { path: String::new(), kind: IssueKind::Mismatch, message: "expected an object".to_string() }),
+	}
+	issues
+}
+
+fn validate_object(root: &mut dyn IVisit, map: &Map<String, Value>, path: &mut String, issues: &mut Vec<Issue>) {
+	for (key, value) in map {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(key);
+		let mut is_list = false;
+		let found = console::find(root, path, |node| {
+			match (node.as_node(), value) {
+				(Node::List(_), Value::Object(_)) => is_list = true,
+				(Node::List(_), _) => issues.push(Issue { path: path.clone(), kind: IssueKind::Mismatch, message: "expected an object".to_string() }),
+				(Node::Action(_), _) => issues.push(Issue { path: path.clone(), kind: IssueKind::Mismatch, message: "cannot set an action".to_string() }),
+				(Node::Prop(_), Value::Object(_)) => issues.push(Issue { path: path.clone(), kind: IssueKind::Mismatch, message: "expected a value, found an object".to_string() }),
+				(Node::Prop(prop), value) => {
+					let val = match value {
+						Value::String(val) => val.clone(),
+						value => value.to_string(),
+					};
+					let mut err = String::new();
+					if prop.flags() & flags::READONLY != 0 {
+						err.push_str("cannot set read-only property");
+					}
+					else if prop.validate(&val, &mut err) {
+						err.clear();
+					}
+					else if err.is_empty() {
+						err.push_str("invalid value");
+					}
+					if !err.is_empty() {
+						issues.push(Issue { path: path.clone(), kind: IssueKind::Invalid, message: err });
+					}
+				},
+			}
+		});
+		if !found {
+			issues.push(Issue { path: path.clone(), kind: IssueKind::Unknown, message: "unknown key".to_string() });
+		}
+		else if is_list {
+			if let Value::Object(map) = value {
+				validate_object(root, map, path, issues);
+			}
+		}
+		path.truncate(len);
+	}
+}
+
+/// Loads the properties from a JSON document only if it is valid.
+///
+/// The document is checked with [`validate`] first, if any issue is found every issue is reported to the writer as an error and nothing is applied.
+/// Otherwise the document is loaded with [`from_value`].
+///
+/// ```
+/// let mut width = 1920;
+/// let mut height = 1080;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("width", &mut width, &1920));
+/// 	f(&mut cvar::Property("height", &mut height, &1080));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::json::load_validated(&mut root, r#"{"width":1280,"height":"tall"}"#, &mut writer));
+/// assert_eq!(writer, "error: height: invalid digit found in string\n");
+/// assert_eq!(cvar::console::get(&mut root, "width").as_deref(), Some("1920"));
+/// ```
+pub fn load_validated(root: &mut dyn IVisit, text: &str, writer: &mut dyn IWrite) -> bool {
+	let value = match serde_json::from_str(text) {
+		Ok(value) => value,
+		Err(err) => {
+			let _ = writeln!(writer, "error: {err}");
+			return false;
+		},
+	};
+	let issues = validate(root, &value);
+	for issue in &issues {
+		let _ = writeln!(writer, "error: {issue}");
+	}
+	issues.is_empty() && from_value(root, &value, writer)
+}
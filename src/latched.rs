@@ -0,0 +1,247 @@
+use super::*;
+
+/// Value which only changes when explicitly applied.
+///
+/// Stores the active value and a pending value set through the console, see [`LatchedProp`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Latched<T> {
+	/// The active value.
+	pub value: T,
+	/// The value which takes effect when applied.
+	pub pending: Option<T>,
+}
+
+impl<T> Latched<T> {
+	/// Creates a new latched value without pending changes.
+	#[inline]
+	pub const fn new(value: T) -> Latched<T> {
+		Latched { value, pending: None }
+	}
+
+	/// Makes the pending value active.
+	///
+	/// Returns `true` if there was a pending value.
+	#[inline]
+	pub fn apply(&mut self) -> bool {
+		match self.pending.take() {
+			Some(value) => {
+				self.value = value;
+				true
+			},
+			None => false,
+		}
+	}
+
+	/// Discards the pending value.
+	#[inline]
+	pub fn discard(&mut self) {
+		self.pending = None;
+	}
+
+	/// Returns `true` if there is a pending value.
+	#[inline]
+	pub fn is_pending(&self) -> bool {
+		self.pending.is_some()
+	}
+}
+
+impl<T> From<T> for Latched<T> {
+	#[inline]
+	fn from(value: T) -> Self {
+		Latched::new(value)
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Latched property node.
+///
+/// Setting the property stores the value as pending, it takes effect when the application calls [`Latched::apply`], eg. after a restart.
+/// Resetting the property restores the default immediately.
+/// While a value is pending the property has the [`PENDING`](flags::PENDING) flag.
+///
+/// ```
+/// let mut width = cvar::Latched::new(1920);
+///
+/// let mut writer = String::new();
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::LatchedProp("width", &mut width, &1920));
+/// });
+/// cvar::console::set(&mut root, "width", "1280", &mut writer);
+/// assert_eq!(writer, "width: value will take effect after restart\n");
+/// assert_eq!(cvar::console::get(&mut root, "width").as_deref(), Some("1920"));
+///
+/// # drop(root);
+/// assert!(width.apply());
+/// assert_eq!(width.value, 1280);
+/// ```
+pub struct LatchedProp<'a, 'x, T: 'static> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	variable: &'x mut Latched<T>,
+	default: &'a T,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn LatchedProp<'a, 'x, T>(name: &'a str, variable: &'x mut Latched<T>, default: &'a T) -> LatchedProp<'a, 'x, T> {
+	LatchedProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default }
+}
+
+impl<'a, 'x, T> LatchedProp<'a, 'x, T> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut Latched<T>, default: &'a T) -> LatchedProp<'a, 'x, T> {
+		LatchedProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+}
+
+impl<'a, 'x, T> LatchedProp<'a, 'x, T>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn latch(&mut self, val: T, writer: &mut dyn IWrite) {
+		if val == self.variable.value {
+			self.variable.pending = None;
+		}
+		else {
+			self.variable.pending = Some(val);
+			let _ = writer.write_str("value will take effect after restart");
+		}
+	}
+}
+
+impl<'a, 'x, T> INode for LatchedProp<'a, 'x, T>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x, T> IProperty for LatchedProp<'a, 'x, T>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn get_value(&self) -> &dyn IValue {
+		&self.variable.value
+	}
+
+	fn persist_value(&self) -> &dyn IValue {
+		match &self.variable.pending {
+			Some(pending) => pending,
+			None => &self.variable.value,
+		}
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(val) = val.downcast_ref::<T>() {
+			self.latch(val.clone(), writer);
+			true
+		}
+		else {
+			let _ = write_mismatched_types::<T>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => {
+				self.latch(val, writer);
+				true
+			},
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		validate_parse::<T>(val, writer)
+	}
+
+	fn reset(&mut self) {
+		self.variable.value.clone_from(self.default);
+		self.variable.pending = None;
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.default
+	}
+
+	fn state(&self) -> PropState {
+		match self.variable.value == *self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		match self.variable.pending {
+			Some(_) => self.flags | flags::PENDING,
+			None => self.flags,
+		}
+	}
+}
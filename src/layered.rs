@@ -0,0 +1,317 @@
+use super::*;
+
+/// Configuration layer a value came from.
+///
+/// Layers are ordered by precedence, a value in a later layer overrides the values in the earlier layers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Layer {
+	/// The default value.
+	Default,
+	/// Loaded from a configuration file.
+	File,
+	/// Overridden on the command line.
+	CommandLine,
+	/// Set at runtime, eg. through the console.
+	Runtime,
+}
+
+impl fmt::Display for Layer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Layer::Default => "default",
+			Layer::File => "file",
+			Layer::CommandLine => "cmdline",
+			Layer::Runtime => "runtime",
+		})
+	}
+}
+
+/// Value with a configuration overlay per layer.
+///
+/// The effective value is the value of the highest layer which has one, falling back to the default, see [`LayeredProp`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Layered<T> {
+	/// The value loaded from a configuration file.
+	pub file: Option<T>,
+	/// The value overridden on the command line.
+	pub cmdline: Option<T>,
+	/// The value set at runtime.
+	pub runtime: Option<T>,
+}
+
+impl<T> Layered<T> {
+	/// Creates a new layered value without any overrides.
+	#[inline]
+	pub const fn new() -> Layered<T> {
+		Layered { file: None, cmdline: None, runtime: None }
+	}
+
+	/// Returns the value of the layer.
+	///
+	/// Returns `None` for the default layer, the default value is not stored in the overlay.
+	#[inline]
+	pub fn layer(&self, layer: Layer) -> Option<&T> {
+		match layer {
+			Layer::Default => None,
+			Layer::File => self.file.as_ref(),
+			Layer::CommandLine => self.cmdline.as_ref(),
+			Layer::Runtime => self.runtime.as_ref(),
+		}
+	}
+
+	/// Returns the effective value given the default value.
+	#[inline]
+	pub fn get<'a>(&'a self, default: &'a T) -> &'a T {
+		self.runtime.as_ref()
+			.or(self.cmdline.as_ref())
+			.or(self.file.as_ref())
+			.unwrap_or(default)
+	}
+
+	/// Returns the layer the effective value comes from.
+	#[inline]
+	pub fn provenance(&self) -> Layer {
+		if self.runtime.is_some() { Layer::Runtime }
+		else if self.cmdline.is_some() { Layer::CommandLine }
+		else if self.file.is_some() { Layer::File }
+		else { Layer::Default }
+	}
+
+	/// Sets the value of the layer.
+	///
+	/// Setting the default layer has no effect.
+	#[inline]
+	pub fn set(&mut self, layer: Layer, value: T) {
+		match layer {
+			Layer::Default => {},
+			Layer::File => self.file = Some(value),
+			Layer::CommandLine => self.cmdline = Some(value),
+			Layer::Runtime => self.runtime = Some(value),
+		}
+	}
+
+	/// Clears the value of the layer.
+	#[inline]
+	pub fn clear(&mut self, layer: Layer) {
+		match layer {
+			Layer::Default => {},
+			Layer::File => self.file = None,
+			Layer::CommandLine => self.cmdline = None,
+			Layer::Runtime => self.runtime = None,
+		}
+	}
+
+	/// Reverts to the value of the layer by clearing all the layers above it.
+	///
+	/// ```
+	/// let mut volume = cvar::Layered::new();
+	/// volume.set(cvar::Layer::File, 0.5);
+	/// volume.set(cvar::Layer::Runtime, 0.25);
+	///
+	/// volume.revert(cvar::Layer::File);
+	/// assert_eq!(*volume.get(&1.0), 0.5);
+	/// assert_eq!(volume.provenance(), cvar::Layer::File);
+	/// ```
+	#[inline]
+	pub fn revert(&mut self, layer: Layer) {
+		if layer < Layer::Runtime {
+			self.runtime = None;
+		}
+		if layer < Layer::CommandLine {
+			self.cmdline = None;
+		}
+		if layer < Layer::File {
+			self.file = None;
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Layered property node.
+///
+/// Setting the property through the console sets the [runtime](Layer::Runtime) layer, the loaders set their own layer, see [`IProperty::set_layer`].
+/// Resetting the property clears all the layers.
+///
+/// ```
+/// let mut volume = cvar::Layered::new();
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::LayeredProp("volume", &mut volume, &1.0));
+/// });
+/// cvar::ini::load(&mut root, "volume=0.5", &mut cvar::NullWriter);
+/// assert_eq!(cvar::console::provenance(&mut root, "volume"), Some(cvar::Layer::File));
+///
+/// cvar::console::set_silent(&mut root, "volume", "0.25");
+/// assert_eq!(cvar::console::provenance(&mut root, "volume"), Some(cvar::Layer::Runtime));
+///
+/// # drop(root);
+/// volume.revert(cvar::Layer::File);
+/// assert_eq!(volume.file, Some(0.5));
+/// assert_eq!(volume.runtime, None);
+/// ```
+pub struct LayeredProp<'a, 'x, T: 'static> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	variable: &'x mut Layered<T>,
+	default: &'a T,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn LayeredProp<'a, 'x, T>(name: &'a str, variable: &'x mut Layered<T>, default: &'a T) -> LayeredProp<'a, 'x, T> {
+	LayeredProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default }
+}
+
+impl<'a, 'x, T> LayeredProp<'a, 'x, T> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut Layered<T>, default: &'a T) -> LayeredProp<'a, 'x, T> {
+		LayeredProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+}
+
+impl<'a, 'x, T> INode for LayeredProp<'a, 'x, T>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x, T> IProperty for LayeredProp<'a, 'x, T>
+	where T: Clone + PartialEq + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn get_value(&self) -> &dyn IValue {
+		self.variable.get(self.default)
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(val) = val.downcast_ref::<T>() {
+			self.variable.runtime = Some(val.clone());
+			true
+		}
+		else {
+			let _ = write_mismatched_types::<T>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => {
+				self.variable.runtime = Some(val);
+				true
+			},
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn set_layer(&mut self, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+		if layer == Layer::Default {
+			let _ = writer.write_str("cannot set the default layer");
+			return false;
+		}
+		match val.parse::<T>() {
+			Ok(val) => {
+				self.variable.set(layer, val);
+				true
+			},
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		validate_parse::<T>(val, writer)
+	}
+
+	fn reset(&mut self) {
+		self.variable.revert(Layer::Default);
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.default
+	}
+
+	fn state(&self) -> PropState {
+		match *self.variable.get(self.default) == *self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+
+	fn provenance(&self) -> Layer {
+		self.variable.provenance()
+	}
+}
@@ -57,9 +57,109 @@ assert_eq!(writer, "Hello, World!\n");
 This example is extremely basic, for more complex scenarios see the examples.
 */
 
-use std::{any, error::Error as StdError, fmt, num, io, str::FromStr};
+#![allow(clippy::tabs_in_doc_comments)]
+
+use std::{any, error::Error as StdError, fmt, num, io, str::FromStr, time::Duration};
 
 pub mod console;
+pub mod commands;
+pub mod ini;
+pub mod testing;
+
+#[cfg(feature = "toml")]
+pub mod toml;
+
+#[cfg(feature = "json")]
+pub mod json;
+
+mod apply;
+pub use self::apply::{ApplyGroup, ApplyProp};
+
+#[cfg(feature = "serde")]
+mod serde_list;
+#[cfg(feature = "serde")]
+pub use self::serde_list::SerdeList;
+
+#[cfg(feature = "serde")]
+mod serde_tree;
+#[cfg(feature = "serde")]
+pub use self::serde_tree::{SerdeTree, deserialize_into};
+
+mod gate;
+pub use self::gate::{Access, IGate, Gate, CheatGate, LevelGate, Permissions, PermissionsGate};
+
+mod defaults;
+pub use self::defaults::Defaults;
+
+mod conditional;
+pub use self::conditional::{Conditional, eval_condition};
+
+mod alias;
+pub use self::alias::{Aliases, Deprecated};
+
+mod menu;
+pub use self::menu::{IMenuHint, MenuHint, MenuRow, MenuWidget};
+
+mod observe;
+pub use self::observe::{ChangeSource, Coalesce, IObserver, Observe};
+
+mod readonly;
+pub use self::readonly::ReadOnly;
+
+mod locks;
+pub use self::locks::{Locks, Locked};
+
+mod latched;
+pub use self::latched::{Latched, LatchedProp};
+
+mod layered;
+pub use self::layered::{Layer, Layered, LayeredProp};
+
+mod completion;
+pub use self::completion::CompletionIndex;
+
+mod index;
+pub use self::index::Index;
+
+mod collections;
+pub use self::collections::{ArrayProps, MapProps};
+
+mod dynamic;
+pub use self::dynamic::DynamicList;
+
+mod sampler;
+pub use self::sampler::Sampler;
+
+mod bounded;
+pub use self::bounded::BoundedStringProp;
+
+mod stepped;
+pub use self::stepped::{Quantize, SteppedProp};
+
+mod accessor;
+pub use self::accessor::{AccessorProp, ComputedProp};
+
+mod secret;
+pub use self::secret::SecretProp;
+
+mod bitflags;
+pub use self::bitflags::FlagsProp;
+
+mod float;
+pub use self::float::{AngleProp, AngleValue, FloatFormat, FloatProp, FloatValue, ParseAngleError, ParsePercentError, PercentProp, PercentValue};
+
+mod path;
+pub use self::path::{PathKind, PathProp};
+
+#[cfg(feature = "regex")]
+mod regex;
+#[cfg(feature = "regex")]
+pub use self::regex::RegexProp;
+
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "url")]
+pub use self::url::UrlProp;
 
 #[cfg(test)]
 mod tests;
@@ -71,6 +171,36 @@ pub trait INode {
 	/// Returns the node name.
 	fn name(&self) -> &str;
 
+	/// Returns the node description.
+	///
+	/// Human readable help text, empty if the node is not documented.
+	fn description(&self) -> &str {
+		""
+	}
+
+	/// Returns the node display label.
+	///
+	/// Human readable name for user interfaces, defaults to the node name.
+	/// Unlike the name the label is not used to address the node.
+	fn label(&self) -> &str {
+		self.name()
+	}
+
+	/// Returns the stable node identifier.
+	///
+	/// Unlike the path the identifier remains valid when the node is renamed or moved, `None` if the node does not declare one.
+	/// See [`console::find_id`] to find a node by its identifier.
+	fn id(&self) -> Option<u64> {
+		None
+	}
+
+	/// Returns the presentation hints for menus.
+	///
+	/// `None` if the node does not declare any, see [`IMenuHint`].
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		None
+	}
+
 	/// Downcasts to a more specific node interface.
 	fn as_node(&mut self) -> Node<'_>;
 
@@ -95,6 +225,38 @@ impl INode for Node<'_> {
 		}
 	}
 
+	fn description(&self) -> &str {
+		match self {
+			Node::Prop(prop) => prop.description(),
+			Node::List(list) => list.description(),
+			Node::Action(act) => act.description(),
+		}
+	}
+
+	fn label(&self) -> &str {
+		match self {
+			Node::Prop(prop) => prop.label(),
+			Node::List(list) => list.label(),
+			Node::Action(act) => act.label(),
+		}
+	}
+
+	fn id(&self) -> Option<u64> {
+		match self {
+			Node::Prop(prop) => prop.id(),
+			Node::List(list) => list.id(),
+			Node::Action(act) => act.id(),
+		}
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		match self {
+			Node::Prop(prop) => prop.menu_hint(),
+			Node::List(list) => list.menu_hint(),
+			Node::Action(act) => act.menu_hint(),
+		}
+	}
+
 	fn as_node(&mut self) -> Node<'_> {
 		match self {
 			Node::Prop(prop) => Node::Prop(*prop),
@@ -111,6 +273,9 @@ impl INode for Node<'_> {
 //----------------------------------------------------------------
 
 /// Property values.
+///
+/// Implemented for every type implementing [`Display`](fmt::Display).
+/// Properties which can be set additionally require their values to implement [`FromStr`], see [`Property`].
 pub trait IValue: any::Any + fmt::Display {
 	/// Returns the value as a `&dyn Any` trait object.
 	fn as_any(&self) -> &dyn any::Any;
@@ -146,10 +311,7 @@ impl dyn IValue {
 	}
 }
 
-impl<T: 'static + Sized> IValue for T
-	where T: Clone + Default + PartialEq + fmt::Display + FromStr,
-	      T::Err: StdError + Send + Sync + 'static
-{
+impl<T: 'static + Sized + fmt::Display> IValue for T {
 	fn as_any(&self) -> &dyn any::Any {
 		self
 	}
@@ -157,109 +319,991 @@ impl<T: 'static + Sized> IValue for T
 
 //----------------------------------------------------------------
 
-/// Format the value as hexadecimal.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+macro_rules! RadixValue {
+	($(#[$meta:meta])* $name:ident) => {
+		$(#[$meta])*
+		#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+		#[repr(transparent)]
+		pub struct $name<T>(pub T);
+
+		impl<T> $name<T> {
+			#[doc = concat!("Transmutes to a `&", stringify!($name), "<T>`.")]
+			#[inline]
+			pub fn from_ref(value: &T) -> &Self {
+				unsafe { &*(value as *const T as *const Self) }
+			}
+			#[doc = concat!("Transmutes to a `&mut ", stringify!($name), "<T>`.")]
+			#[inline]
+			pub fn from_mut(value: &mut T) -> &mut Self {
+				unsafe { &mut *(value as *mut T as *mut Self) }
+			}
+		}
+
+		impl<T> From<T> for $name<T> {
+			#[inline]
+			fn from(value: T) -> Self {
+				Self(value)
+			}
+		}
+		impl<T> AsRef<T> for $name<T> {
+			#[inline]
+			fn as_ref(&self) -> &T {
+				&self.0
+			}
+		}
+		impl<T> AsMut<T> for $name<T> {
+			#[inline]
+			fn as_mut(&mut self) -> &mut T {
+				&mut self.0
+			}
+		}
+	};
+}
+
+RadixValue! {
+	/// Format the value as hexadecimal.
+	HexValue
+}
+
+RadixValue! {
+	/// Format the value as octal.
+	OctValue
+}
+
+RadixValue! {
+	/// Format the value as binary, eg. bitmasks.
+	///
+	/// ```
+	/// let mut mask = 0b1010u8;
+	/// let mut root = cvar::Visit(|f| {
+	/// 	f(&mut cvar::BinProp("mask", &mut mask, &0));
+	/// });
+	///
+	/// assert_eq!(cvar::console::get(&mut root, "mask").as_deref(), Some("0b1010"));
+	/// assert!(cvar::console::set_silent(&mut root, "mask", "0b1100"));
+	/// assert_eq!(cvar::console::get(&mut root, "mask").as_deref(), Some("0b1100"));
+	/// ```
+	BinValue
+}
+
+// Parses an integer with an optional `!` (bitwise not), `-` (negate) and `0x`, `0o` or `0b` radix prefix
+macro_rules! parse_radix {
+	($ty:ty, $s:expr) => {{
+		let mut s: &str = $s;
+		let mut not = false;
+		if let Some(rest) = s.strip_prefix("!") {
+			s = rest;
+			not = true;
+		}
+
+		let mut negate = false;
+		if let Some(rest) = s.strip_prefix("-") {
+			s = rest;
+			negate = true;
+		}
+
+		let mut radix = 10;
+		for (prefix, prefix_radix) in [("0x", 16), ("0o", 8), ("0b", 2)] {
+			if let Some(rest) = s.strip_prefix(prefix) {
+				s = rest;
+				radix = prefix_radix;
+				break;
+			}
+		}
+
+		<$ty>::from_str_radix(s, radix).map(|mut value| {
+			if negate {
+				value = value.wrapping_neg();
+			}
+			if not {
+				value = !value;
+			}
+			value
+		})
+	}};
+}
+
+macro_rules! impl_RadixValue {
+	($name:ident, $fmt:literal, $ty:ty) => {
+		impl fmt::Display for $name<$ty> {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				if self.0 == 0 {
+					f.write_str("0")
+				}
+				else {
+					write!(f, $fmt, self.0)
+				}
+			}
+		}
+
+		impl FromStr for $name<$ty> {
+			type Err = num::ParseIntError;
+			fn from_str(s: &str) -> Result<Self, num::ParseIntError> {
+				parse_radix!($ty, s).map($name)
+			}
+		}
+	};
+}
+
+macro_rules! impl_HexValue {
+	($($ty:ty),*) => {$(
+		impl_RadixValue!(HexValue, "{:#x}", $ty);
+		impl_RadixValue!(OctValue, "{:#o}", $ty);
+		impl_RadixValue!(BinValue, "{:#b}", $ty);
+	)*};
+}
+
+impl_HexValue!(u128, u64, u32, u16, u8);
+impl_HexValue!(i128, i64, i32, i16, i8);
+impl_HexValue!(usize, isize);
+
+// Formats bytes as pairs of lowercase hex digits
+fn write_hex_bytes(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+	for byte in bytes {
+		write!(f, "{byte:02x}")?;
+	}
+	Ok(())
+}
+
+// Parses pairs of hex digits with an optional 0x prefix
+#[allow(clippy::manual_is_multiple_of)] // is_multiple_of requires Rust 1.87
+fn parse_hex_bytes(s: &str) -> Result<Vec<u8>, ParseHexBytesError> {
+	let s = s.strip_prefix("0x").unwrap_or(s);
+	if s.len() % 2 != 0 {
+		return Err(ParseHexBytesError::InvalidLength);
+	}
+	(0..s.len()).step_by(2)
+		.map(|i| match s.get(i..i + 2) {
+			Some(pair) if pair.bytes().all(|b| b.is_ascii_hexdigit()) => u8::from_str_radix(pair, 16).map_err(|_| ParseHexBytesError::InvalidDigit),
+			_ => Err(ParseHexBytesError::InvalidDigit),
+		})
+		.collect()
+}
+
+/// Formats the bytes as hex digits, eg. hashes and keys.
+///
+/// ```
+/// let value = cvar::HexValue([0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(value.to_string(), "deadbeef");
+/// assert_eq!("0xDEADBEEF".parse(), Ok(value));
+/// ```
+impl<const N: usize> fmt::Display for HexValue<[u8; N]> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_hex_bytes(f, &self.0)
+	}
+}
+
+impl<const N: usize> FromStr for HexValue<[u8; N]> {
+	type Err = ParseHexBytesError;
+	fn from_str(s: &str) -> Result<Self, ParseHexBytesError> {
+		let bytes = parse_hex_bytes(s)?;
+		bytes.try_into().map(HexValue).map_err(|_| ParseHexBytesError::InvalidLength)
+	}
+}
+
+impl fmt::Display for HexValue<Vec<u8>> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_hex_bytes(f, &self.0)
+	}
+}
+
+impl FromStr for HexValue<Vec<u8>> {
+	type Err = ParseHexBytesError;
+	fn from_str(s: &str) -> Result<Self, ParseHexBytesError> {
+		parse_hex_bytes(s).map(HexValue)
+	}
+}
+
+/// Error parsing a [`HexValue`] of bytes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseHexBytesError {
+	/// The number of hex digits does not match the number of bytes.
+	InvalidLength,
+	/// A character is not a hex digit.
+	InvalidDigit,
+}
+
+impl fmt::Display for ParseHexBytesError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			ParseHexBytesError::InvalidLength => "invalid number of hex digits",
+			ParseHexBytesError::InvalidDigit => "invalid hex digit",
+		})
+	}
+}
+
+impl StdError for ParseHexBytesError {}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn HexProp<'a, 'x, T>(name: &'a str, value: &'x mut T, default: &'a T) -> crate::Property<'a, 'x, HexValue<T>> {
+	crate::Property(name, HexValue::from_mut(value), HexValue::from_ref(default))
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn OctProp<'a, 'x, T>(name: &'a str, value: &'x mut T, default: &'a T) -> crate::Property<'a, 'x, OctValue<T>> {
+	crate::Property(name, OctValue::from_mut(value), OctValue::from_ref(default))
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn BinProp<'a, 'x, T>(name: &'a str, value: &'x mut T, default: &'a T) -> crate::Property<'a, 'x, BinValue<T>> {
+	crate::Property(name, BinValue::from_mut(value), BinValue::from_ref(default))
+}
+
+//----------------------------------------------------------------
+
+/// Boolean value with lenient parsing.
+///
+/// Parses `true`/`false`, `1`/`0`, `yes`/`no` and `on`/`off` ignoring case, formats as `true` or `false`.
+///
+/// ```
+/// let mut vsync = false;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::BoolProp("vsync", &mut vsync, &false));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "vsync", "On"));
+/// assert_eq!(cvar::console::get(&mut root, "vsync").as_deref(), Some("true"));
+/// assert_eq!(cvar::console::complete(&mut root, "vsync f"), ["vsync false"]);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[repr(transparent)]
-pub struct HexValue<T>(pub T);
+pub struct BoolValue(pub bool);
 
-impl<T> HexValue<T> {
-	/// Transmutes to a `&HexValue<T>`.
+impl BoolValue {
+	/// Transmutes to a `&BoolValue`.
 	#[inline]
-	pub fn from_ref(value: &T) -> &Self {
-		unsafe { &*(value as *const T as *const Self) }
+	pub fn from_ref(value: &bool) -> &Self {
+		unsafe { &*(value as *const bool as *const Self) }
 	}
-	/// Transmutes to a `&mut HexValue<T>`.
+	/// Transmutes to a `&mut BoolValue`.
 	#[inline]
-	pub fn from_mut(value: &mut T) -> &mut Self {
-		unsafe { &mut *(value as *mut T as *mut Self) }
+	pub fn from_mut(value: &mut bool) -> &mut Self {
+		unsafe { &mut *(value as *mut bool as *mut Self) }
 	}
 }
 
-impl<T> From<T> for HexValue<T> {
+impl From<bool> for BoolValue {
 	#[inline]
-	fn from(value: T) -> Self {
+	fn from(value: bool) -> Self {
 		Self(value)
 	}
 }
-impl<T> AsRef<T> for HexValue<T> {
+impl AsRef<bool> for BoolValue {
 	#[inline]
-	fn as_ref(&self) -> &T {
+	fn as_ref(&self) -> &bool {
 		&self.0
 	}
 }
-impl<T> AsMut<T> for HexValue<T> {
+impl AsMut<bool> for BoolValue {
 	#[inline]
-	fn as_mut(&mut self) -> &mut T {
+	fn as_mut(&mut self) -> &mut bool {
 		&mut self.0
 	}
 }
 
-macro_rules! impl_HexValue {
-	($ty:ty) => {
-		impl fmt::Display for HexValue<$ty> {
-			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-				if self.0 == 0 {
-					f.write_str("0")
-				}
-				else {
-					write!(f, "{:#x}", self.0)
-				}
+impl fmt::Display for BoolValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)
+	}
+}
+
+impl FromStr for BoolValue {
+	type Err = ParseBoolValueError;
+	fn from_str(s: &str) -> Result<Self, ParseBoolValueError> {
+		const TRUE: [&str; 4] = ["true", "1", "yes", "on"];
+		const FALSE: [&str; 4] = ["false", "0", "no", "off"];
+		if TRUE.iter().any(|t| s.eq_ignore_ascii_case(t)) {
+			Ok(BoolValue(true))
+		}
+		else if FALSE.iter().any(|f| s.eq_ignore_ascii_case(f)) {
+			Ok(BoolValue(false))
+		}
+		else {
+			Err(ParseBoolValueError)
+		}
+	}
+}
+
+/// Error parsing a [`BoolValue`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParseBoolValueError;
+
+impl fmt::Display for ParseBoolValueError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("expected true/false, 1/0, yes/no or on/off")
+	}
+}
+
+impl StdError for ParseBoolValueError {}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn BoolProp<'a, 'x>(name: &'a str, value: &'x mut bool, default: &'a bool) -> crate::Property<'a, 'x, BoolValue> {
+	crate::Property(name, BoolValue::from_mut(value), BoolValue::from_ref(default))
+}
+
+// Returns the valid values of boolean types
+#[inline]
+fn bool_values(val: &dyn IValue) -> Option<&'static [&'static str]> {
+	if val.downcast_ref::<bool>().is_some() || val.downcast_ref::<BoolValue>().is_some() {
+		Some(&["false", "true"])
+	}
+	else {
+		None
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Duration value with units.
+///
+/// Parses a sequence of whole numbers followed by a unit, eg. `500ms`, `2s` or `1m30s`.
+/// The units are `h`, `m`, `s`, `ms`, `us` and `ns`, a plain `0` is accepted as zero.
+/// Formats in the same notation with the largest units first.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// let mut timeout = Duration::from_secs(30);
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::DurationProp("timeout", &mut timeout, &Duration::from_secs(30)));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "timeout", "1m30s"));
+/// assert_eq!(cvar::console::get(&mut root, "timeout").as_deref(), Some("1m30s"));
+/// assert!(cvar::console::set_silent(&mut root, "timeout", "1500ms"));
+/// assert_eq!(cvar::console::get(&mut root, "timeout").as_deref(), Some("1s500ms"));
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut root, "timeout", "30", &mut writer));
+/// assert_eq!(writer, "error: timeout \"30\": missing unit\n");
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct DurationValue(pub Duration);
+
+impl DurationValue {
+	/// Transmutes to a `&DurationValue`.
+	#[inline]
+	pub fn from_ref(value: &Duration) -> &Self {
+		unsafe { &*(value as *const Duration as *const Self) }
+	}
+	/// Transmutes to a `&mut DurationValue`.
+	#[inline]
+	pub fn from_mut(value: &mut Duration) -> &mut Self {
+		unsafe { &mut *(value as *mut Duration as *mut Self) }
+	}
+}
+
+impl From<Duration> for DurationValue {
+	#[inline]
+	fn from(value: Duration) -> Self {
+		Self(value)
+	}
+}
+impl AsRef<Duration> for DurationValue {
+	#[inline]
+	fn as_ref(&self) -> &Duration {
+		&self.0
+	}
+}
+impl AsMut<Duration> for DurationValue {
+	#[inline]
+	fn as_mut(&mut self) -> &mut Duration {
+		&mut self.0
+	}
+}
+
+const DURATION_UNITS: [(&str, u128); 6] = [
+	("h", 3_600_000_000_000),
+	("m", 60_000_000_000),
+	("s", 1_000_000_000),
+	("ms", 1_000_000),
+	("us", 1_000),
+	("ns", 1),
+];
+
+impl fmt::Display for DurationValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut nanos = self.0.as_nanos();
+		if nanos == 0 {
+			return f.write_str("0s");
+		}
+		for &(unit, scale) in &DURATION_UNITS {
+			if nanos >= scale {
+				write!(f, "{}{unit}", nanos / scale)?;
+				nanos %= scale;
 			}
 		}
+		Ok(())
+	}
+}
 
-		impl FromStr for HexValue<$ty> {
-			type Err = num::ParseIntError;
-			fn from_str(mut s: &str) -> Result<Self, num::ParseIntError> {
-				let mut not = false;
-				if let Some(rest) = s.strip_prefix("!") {
-					s = rest;
-					not = true;
-				}
+impl FromStr for DurationValue {
+	type Err = ParseDurationError;
+	fn from_str(s: &str) -> Result<Self, ParseDurationError> {
+		if s.is_empty() {
+			return Err(ParseDurationError::Empty);
+		}
+		if s == "0" {
+			return Ok(DurationValue(Duration::ZERO));
+		}
+		let mut rest = s;
+		let mut nanos = 0u128;
+		while !rest.is_empty() {
+			let digits = rest.find(|chr: char| !chr.is_ascii_digit()).unwrap_or(rest.len());
+			let number: u128 = rest[..digits].parse().map_err(|_| ParseDurationError::InvalidNumber)?;
+			rest = &rest[digits..];
+			let letters = rest.find(|chr: char| !chr.is_ascii_alphabetic()).unwrap_or(rest.len());
+			let unit = &rest[..letters];
+			rest = &rest[letters..];
+			if unit.is_empty() {
+				return Err(ParseDurationError::MissingUnit);
+			}
+			let Some(&(_, scale)) = DURATION_UNITS.iter().find(|&&(name, _)| name == unit) else {
+				return Err(ParseDurationError::UnknownUnit);
+			};
+			nanos = number.checked_mul(scale).and_then(|n| n.checked_add(nanos)).ok_or(ParseDurationError::Overflow)?;
+		}
+		let secs = u64::try_from(nanos / 1_000_000_000).map_err(|_| ParseDurationError::Overflow)?;
+		Ok(DurationValue(Duration::new(secs, (nanos % 1_000_000_000) as u32)))
+	}
+}
 
-				let mut negate = false;
-				if let Some(rest) = s.strip_prefix("-") {
-					s = rest;
-					negate = true;
-				}
+/// Error parsing a [`DurationValue`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseDurationError {
+	/// The string is empty.
+	Empty,
+	/// Expected a whole number.
+	InvalidNumber,
+	/// A number is not followed by a unit.
+	MissingUnit,
+	/// The unit is not one of `h`, `m`, `s`, `ms`, `us` or `ns`.
+	UnknownUnit,
+	/// The duration does not fit.
+	Overflow,
+}
+
+impl fmt::Display for ParseDurationError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			ParseDurationError::Empty => "empty duration",
+			ParseDurationError::InvalidNumber => "expected a whole number",
+			ParseDurationError::MissingUnit => "missing unit",
+			ParseDurationError::UnknownUnit => "unknown unit, expected h, m, s, ms, us or ns",
+			ParseDurationError::Overflow => "duration too large",
+		})
+	}
+}
 
-				let mut radix = 10;
-				if let Some(rest) = s.strip_prefix("0x") {
-					s = rest;
-					radix = 16;
-				}
+impl StdError for ParseDurationError {}
 
-				let mut value = <$ty>::from_str_radix(s, radix)?;
+#[allow(non_snake_case)]
+#[inline]
+pub fn DurationProp<'a, 'x>(name: &'a str, value: &'x mut Duration, default: &'a Duration) -> crate::Property<'a, 'x, DurationValue> {
+	crate::Property(name, DurationValue::from_mut(value), DurationValue::from_ref(default))
+}
 
-				if negate {
-					value = value.wrapping_neg();
-				}
+//----------------------------------------------------------------
 
-				if not {
-					value = !value;
-				}
+/// RGBA color value.
+///
+/// Parses `#RRGGBB`, `#RRGGBBAA` and `r,g,b` or `r,g,b,a` with components from 0 to 255.
+/// Formats as `#rrggbb`, or `#rrggbbaa` if the color is not opaque.
+///
+/// ```
+/// let mut crosshair = cvar::ColorValue::rgb(0, 255, 0);
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("crosshair_color", &mut crosshair, &cvar::ColorValue::rgb(0, 255, 0)));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "crosshair_color", "255, 128, 0"));
+/// assert_eq!(cvar::console::get(&mut root, "crosshair_color").as_deref(), Some("#ff8000"));
+/// assert!(cvar::console::set_silent(&mut root, "crosshair_color", "#FF000080"));
+/// assert_eq!(cvar::console::get(&mut root, "crosshair_color").as_deref(), Some("#ff000080"));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ColorValue {
+	pub r: u8,
+	pub g: u8,
+	pub b: u8,
+	pub a: u8,
+}
+
+impl ColorValue {
+	/// Creates an opaque color.
+	#[inline]
+	pub const fn rgb(r: u8, g: u8, b: u8) -> ColorValue {
+		ColorValue { r, g, b, a: 255 }
+	}
+	/// Creates a color with alpha.
+	#[inline]
+	pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> ColorValue {
+		ColorValue { r, g, b, a }
+	}
+}
+
+/// Opaque black.
+impl Default for ColorValue {
+	#[inline]
+	fn default() -> ColorValue {
+		ColorValue::rgb(0, 0, 0)
+	}
+}
 
-				Ok(HexValue(value))
+impl From<[u8; 4]> for ColorValue {
+	#[inline]
+	fn from([r, g, b, a]: [u8; 4]) -> ColorValue {
+		ColorValue { r, g, b, a }
+	}
+}
+impl From<ColorValue> for [u8; 4] {
+	#[inline]
+	fn from(color: ColorValue) -> [u8; 4] {
+		[color.r, color.g, color.b, color.a]
+	}
+}
+
+impl fmt::Display for ColorValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)?;
+		if self.a != 255 {
+			write!(f, "{:02x}", self.a)?;
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for ColorValue {
+	type Err = ParseColorError;
+	fn from_str(s: &str) -> Result<Self, ParseColorError> {
+		if let Some(hex) = s.strip_prefix('#') {
+			if !(hex.len() == 6 || hex.len() == 8) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+				return Err(ParseColorError);
 			}
+			let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| ParseColorError);
+			let a = if hex.len() == 8 { component(6)? } else { 255 };
+			return Ok(ColorValue::rgba(component(0)?, component(2)?, component(4)?, a));
 		}
-	};
+		let mut components = [255u8; 4];
+		let mut count = 0;
+		for part in s.split(',') {
+			if count == components.len() {
+				return Err(ParseColorError);
+			}
+			components[count] = part.trim().parse().map_err(|_| ParseColorError)?;
+			count += 1;
+		}
+		if count < 3 {
+			return Err(ParseColorError);
+		}
+		Ok(ColorValue::from(components))
+	}
+}
+
+/// Error parsing a [`ColorValue`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParseColorError;
+
+impl fmt::Display for ParseColorError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("expected #RRGGBB, #RRGGBBAA or r,g,b")
+	}
+}
+
+impl StdError for ParseColorError {}
+
+//----------------------------------------------------------------
+
+/// List of values separated by a character.
+///
+/// Parses the items separated by `SEP` with surrounding whitespace trimmed, an empty string is an empty list.
+/// Formats the items joined by `SEP`. Items containing the separator do not round-trip.
+///
+/// ```
+/// let mut admins = vec![76561197960287930u64];
+/// let mut tags = vec![String::from("casual")];
+/// let default_tags = Vec::new();
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ListProp("admins", &mut admins, &Vec::new()));
+/// 	f(&mut cvar::Property("tags", cvar::ListValue::<_, ';'>::from_mut(&mut tags), cvar::ListValue::from_ref(&default_tags)));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "admins", "1, 2,3"));
+/// assert_eq!(cvar::console::get(&mut root, "admins").as_deref(), Some("1,2,3"));
+/// assert!(cvar::console::set_silent(&mut root, "tags", "casual; eu,west"));
+/// assert_eq!(cvar::console::get(&mut root, "tags").as_deref(), Some("casual;eu,west"));
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut root, "admins", "1,x", &mut writer));
+/// assert_eq!(writer, "error: admins \"1,x\": item 1: invalid digit found in string\n");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct ListValue<T, const SEP: char = ','>(pub Vec<T>);
+
+impl<T, const SEP: char> ListValue<T, SEP> {
+	/// Transmutes to a `&ListValue<T, SEP>`.
+	#[inline]
+	pub fn from_ref(value: &Vec<T>) -> &Self {
+		unsafe { &*(value as *const Vec<T> as *const Self) }
+	}
+	/// Transmutes to a `&mut ListValue<T, SEP>`.
+	#[inline]
+	pub fn from_mut(value: &mut Vec<T>) -> &mut Self {
+		unsafe { &mut *(value as *mut Vec<T> as *mut Self) }
+	}
+}
+
+impl<T, const SEP: char> From<Vec<T>> for ListValue<T, SEP> {
+	#[inline]
+	fn from(value: Vec<T>) -> Self {
+		Self(value)
+	}
+}
+impl<T, const SEP: char> AsRef<Vec<T>> for ListValue<T, SEP> {
+	#[inline]
+	fn as_ref(&self) -> &Vec<T> {
+		&self.0
+	}
+}
+impl<T, const SEP: char> AsMut<Vec<T>> for ListValue<T, SEP> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut Vec<T> {
+		&mut self.0
+	}
+}
+
+impl<T: fmt::Display, const SEP: char> fmt::Display for ListValue<T, SEP> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, item) in self.0.iter().enumerate() {
+			if i > 0 {
+				fmt::Write::write_char(f, SEP)?;
+			}
+			fmt::Display::fmt(item, f)?;
+		}
+		Ok(())
+	}
+}
+
+impl<T: FromStr, const SEP: char> FromStr for ListValue<T, SEP> {
+	type Err = ParseListError<T::Err>;
+	fn from_str(s: &str) -> Result<Self, ParseListError<T::Err>> {
+		if s.trim().is_empty() {
+			return Ok(ListValue(Vec::new()));
+		}
+		s.split(SEP)
+			.enumerate()
+			.map(|(index, item)| item.trim().parse().map_err(|error| ParseListError { index, error }))
+			.collect::<Result<Vec<T>, _>>()
+			.map(ListValue)
+	}
+}
+
+/// Error parsing a [`ListValue`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseListError<E> {
+	/// Index of the item which failed to parse.
+	pub index: usize,
+	/// Error parsing the item.
+	pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for ParseListError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "item {}: {}", self.index, self.error)
+	}
+}
+
+impl<E: StdError + 'static> StdError for ParseListError<E> {
+	fn source(&self) -> Option<&(dyn StdError + 'static)> {
+		Some(&self.error)
+	}
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn ListProp<'a, 'x, T>(name: &'a str, value: &'x mut Vec<T>, default: &'a Vec<T>) -> crate::Property<'a, 'x, ListValue<T>> {
+	crate::Property(name, ListValue::from_mut(value), ListValue::from_ref(default))
+}
+
+//----------------------------------------------------------------
+
+/// Fixed-size array of values separated by whitespace.
+///
+/// Parses exactly `N` items separated by whitespace, formats the items joined by a space.
+/// See [`ArrayProps`] to expose every item as its own property instead.
+///
+/// ```
+/// let mut color = [1.0, 0.5, 0.0];
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ArrayProp("color", &mut color, &[1.0, 1.0, 1.0]));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "color", "0.2  0.4 0.6"));
+/// assert_eq!(cvar::console::get(&mut root, "color").as_deref(), Some("0.2 0.4 0.6"));
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut root, "color", "0.2 0.4", &mut writer));
+/// assert_eq!(writer, "error: color \"0.2 0.4\": expected 3 items, found 2\n");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct ArrayValue<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> ArrayValue<T, N> {
+	/// Transmutes to a `&ArrayValue<T, N>`.
+	#[inline]
+	pub fn from_ref(value: &[T; N]) -> &Self {
+		unsafe { &*(value as *const [T; N] as *const Self) }
+	}
+	/// Transmutes to a `&mut ArrayValue<T, N>`.
+	#[inline]
+	pub fn from_mut(value: &mut [T; N]) -> &mut Self {
+		unsafe { &mut *(value as *mut [T; N] as *mut Self) }
+	}
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayValue<T, N> {
+	#[inline]
+	fn from(value: [T; N]) -> Self {
+		Self(value)
+	}
+}
+impl<T, const N: usize> AsRef<[T; N]> for ArrayValue<T, N> {
+	#[inline]
+	fn as_ref(&self) -> &[T; N] {
+		&self.0
+	}
+}
+impl<T, const N: usize> AsMut<[T; N]> for ArrayValue<T, N> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut [T; N] {
+		&mut self.0
+	}
+}
+
+impl<T: fmt::Display, const N: usize> fmt::Display for ArrayValue<T, N> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, item) in self.0.iter().enumerate() {
+			if i > 0 {
+				f.write_str(" ")?;
+			}
+			fmt::Display::fmt(item, f)?;
+		}
+		Ok(())
+	}
+}
+
+impl<T: FromStr, const N: usize> FromStr for ArrayValue<T, N> {
+	type Err = ParseArrayError<T::Err>;
+	fn from_str(s: &str) -> Result<Self, ParseArrayError<T::Err>> {
+		let items = s.split_whitespace()
+			.enumerate()
+			.map(|(index, item)| item.parse().map_err(|error| ParseArrayError::Item { index, error }))
+			.collect::<Result<Vec<T>, _>>()?;
+		let found = items.len();
+		items.try_into().map(ArrayValue).map_err(|_| ParseArrayError::Count { expected: N, found })
+	}
+}
+
+/// Error parsing an [`ArrayValue`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseArrayError<E> {
+	/// The number of items is wrong.
+	Count { expected: usize, found: usize },
+	/// An item failed to parse.
+	Item { index: usize, error: E },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseArrayError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseArrayError::Count { expected, found } => write!(f, "expected {expected} items, found {found}"),
+			ParseArrayError::Item { index, error } => write!(f, "item {index}: {error}"),
+		}
+	}
+}
+
+impl<E: StdError + 'static> StdError for ParseArrayError<E> {
+	fn source(&self) -> Option<&(dyn StdError + 'static)> {
+		match self {
+			ParseArrayError::Count { .. } => None,
+			ParseArrayError::Item { error, .. } => Some(error),
+		}
+	}
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn ArrayProp<'a, 'x, T, const N: usize>(name: &'a str, value: &'x mut [T; N], default: &'a [T; N]) -> crate::Property<'a, 'x, ArrayValue<T, N>> {
+	crate::Property(name, ArrayValue::from_mut(value), ArrayValue::from_ref(default))
+}
+
+//----------------------------------------------------------------
+
+/// Binary value encoded as base64.
+///
+/// Uses the standard alphabet with padding, parsing also accepts values without padding.
+///
+/// ```
+/// let mut key = vec![0xde, 0xad, 0xbe, 0xef];
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Base64Prop("auth_key", &mut key, &Vec::new()));
+/// });
+///
+/// assert_eq!(cvar::console::get(&mut root, "auth_key").as_deref(), Some("3q2+7w=="));
+/// assert!(cvar::console::set_silent(&mut root, "auth_key", "aGVsbG8"));
+/// # drop(root);
+/// assert_eq!(key, b"hello");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Base64Value(pub Vec<u8>);
+
+impl Base64Value {
+	/// Transmutes to a `&Base64Value`.
+	#[inline]
+	pub fn from_ref(value: &Vec<u8>) -> &Self {
+		unsafe { &*(value as *const Vec<u8> as *const Self) }
+	}
+	/// Transmutes to a `&mut Base64Value`.
+	#[inline]
+	pub fn from_mut(value: &mut Vec<u8>) -> &mut Self {
+		unsafe { &mut *(value as *mut Vec<u8> as *mut Self) }
+	}
+}
+
+impl From<Vec<u8>> for Base64Value {
+	#[inline]
+	fn from(value: Vec<u8>) -> Self {
+		Self(value)
+	}
+}
+impl AsRef<Vec<u8>> for Base64Value {
+	#[inline]
+	fn as_ref(&self) -> &Vec<u8> {
+		&self.0
+	}
+}
+impl AsMut<Vec<u8>> for Base64Value {
+	#[inline]
+	fn as_mut(&mut self) -> &mut Vec<u8> {
+		&mut self.0
+	}
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl fmt::Display for Base64Value {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for chunk in self.0.chunks(3) {
+			let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, &byte)| bits | (byte as u32) << (16 - i * 8));
+			for i in 0..4 {
+				let chr = if i <= chunk.len() { BASE64_ALPHABET[(bits >> (18 - i * 6)) as usize & 0x3f] } else { b'=' };
+				fmt::Write::write_char(f, chr as char)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for Base64Value {
+	type Err = ParseBase64Error;
+	fn from_str(s: &str) -> Result<Self, ParseBase64Error> {
+		let data = s.trim_end_matches('=');
+		let padding = s.len() - data.len();
+		// Padding is optional but when present it must complete the last group of 4 characters
+		if data.len() % 4 == 1 || padding > 2 || padding > 0 && !s.len().is_multiple_of(4) {
+			return Err(ParseBase64Error::InvalidLength);
+		}
+		let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+		let mut bits = 0u32;
+		let mut count = 0;
+		for (position, chr) in data.bytes().enumerate() {
+			let Some(index) = BASE64_ALPHABET.iter().position(|&a| a == chr) else {
+				return Err(ParseBase64Error::InvalidCharacter { position });
+			};
+			bits = bits << 6 | index as u32;
+			count += 6;
+			if count >= 8 {
+				count -= 8;
+				bytes.push((bits >> count) as u8);
+			}
+		}
+		Ok(Base64Value(bytes))
+	}
+}
+
+/// Error parsing a [`Base64Value`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseBase64Error {
+	/// The character at the byte position is not in the base64 alphabet.
+	InvalidCharacter { position: usize },
+	/// The number of characters or padding is invalid.
+	InvalidLength,
+}
+
+impl fmt::Display for ParseBase64Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseBase64Error::InvalidCharacter { position } => write!(f, "invalid base64 character at position {position}"),
+			ParseBase64Error::InvalidLength => f.write_str("invalid base64 length"),
+		}
+	}
 }
 
-impl_HexValue!(u64);
-impl_HexValue!(u32);
-impl_HexValue!(u16);
-impl_HexValue!(u8);
-
-impl_HexValue!(i64);
-impl_HexValue!(i32);
-impl_HexValue!(i16);
-impl_HexValue!(i8);
+impl StdError for ParseBase64Error {}
 
 #[allow(non_snake_case)]
 #[inline]
-pub fn HexProp<'a, 'x, T>(name: &'a str, value: &'x mut T, default: &'a T) -> crate::Property<'a, 'x, HexValue<T>> {
-	crate::Property(name, HexValue::from_mut(value), HexValue::from_ref(default))
+pub fn Base64Prop<'a, 'x>(name: &'a str, value: &'x mut Vec<u8>, default: &'a Vec<u8>) -> crate::Property<'a, 'x, Base64Value> {
+	crate::Property(name, Base64Value::from_mut(value), Base64Value::from_ref(default))
+}
+
+//----------------------------------------------------------------
+
+/// Conventional property flags.
+///
+/// The meaning of the flags is defined by the caller, these are the bits this crate gives meaning to.
+/// Applications are free to use the remaining bits for their own purposes.
+pub mod flags {
+	/// The value is saved in the configuration.
+	pub const ARCHIVE: u32 = 0x1;
+	/// Changing the value is considered cheating.
+	pub const CHEAT: u32 = 0x2;
+	/// The node is not listed in the console.
+	pub const HIDDEN: u32 = 0x4;
+	/// The value cannot be changed through the console.
+	pub const READONLY: u32 = 0x8;
+	/// The value is never saved, eg. transient values such as the current frame rate.
+	///
+	/// Honored by the [`ini`](crate::ini), `json` and `toml` modules.
+	pub const NO_PERSIST: u32 = 0x10;
+	/// The property is kept for compatibility and should no longer be used, see [`Deprecated`](crate::Deprecated).
+	pub const DEPRECATED: u32 = 0x20;
+	/// The property has a value which has not taken effect yet, see [`LatchedProp`](crate::LatchedProp).
+	pub const PENDING: u32 = 0x40;
+	/// The value is sensitive, eg. a password or an API token, the console prints it masked.
+	///
+	/// See [`SecretProp`](crate::SecretProp).
+	pub const SECRET: u32 = 0x80;
+	/// The value is worth graphing over time, eg. frame times and tuning values.
+	///
+	/// See [`Sampler::track_flagged`](crate::Sampler::track_flagged).
+	pub const METRIC: u32 = 0x100;
 }
 
+// Displayed in place of secret values
+const SECRET_MASK: &str = "****";
+
 //----------------------------------------------------------------
 
 /// Property state.
@@ -274,6 +1318,24 @@ pub enum PropState {
 	Invalid,
 }
 
+// Compares values, float values are compared within the tolerance
+#[inline]
+fn values_eq<T: IValue + PartialEq>(a: &T, b: &T, epsilon: f64) -> bool {
+	if *a == *b {
+		return true;
+	}
+	if epsilon > 0.0 {
+		let (a, b): (&dyn IValue, &dyn IValue) = (a, b);
+		if let (Some(&a), Some(&b)) = (a.downcast_ref::<f64>(), b.downcast_ref::<f64>()) {
+			return (a - b).abs() <= epsilon;
+		}
+		if let (Some(&a), Some(&b)) = (a.downcast_ref::<f32>(), b.downcast_ref::<f32>()) {
+			return ((a - b) as f64).abs() <= epsilon;
+		}
+	}
+	false
+}
+
 /// Property node interface.
 ///
 /// Provides an object safe interface for properties, type erasing its implementation.
@@ -281,12 +1343,55 @@ pub trait IProperty: INode {
 	/// Gets the value.
 	fn get_value(&self) -> &dyn IValue;
 
+	/// Gets the value to save in the configuration.
+	///
+	/// This is the current value unless a change has not taken effect yet, eg. [`LatchedProp`] returns its pending value so it is not lost when saving.
+	fn persist_value(&self) -> &dyn IValue {
+		self.get_value()
+	}
+
 	/// Sets the value.
 	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool;
 
 	/// Sets the value parsed from string.
+	///
+	/// On failure the reason is written to the writer.
+	/// On success the writer may receive a note for the user, eg. when the value does not take effect immediately.
 	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool;
 
+	/// Sets the value parsed from string in a configuration layer.
+	///
+	/// The file loaders such as [`ini::load`] set the [`File`](Layer::File) layer and [`console::load_args`] sets the [`CommandLine`](Layer::CommandLine) layer.
+	/// Only properties with layers keep the values apart, see [`LayeredProp`], other properties set their value as with [set](IProperty::set).
+	/// Setting the [`Runtime`](Layer::Runtime) layer is the same as [set](IProperty::set).
+	fn set_layer(&mut self, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+		let _ = layer;
+		self.set(val, writer)
+	}
+
+	/// Validates the value parsed from string without changing the property.
+	///
+	/// Returns whether [set](IProperty::set) would accept the value, on failure the reason is written to the writer.
+	/// Properties which cannot tell without side effects, eg. setters calling into the application, only check that the value parses.
+	///
+	/// ```
+	/// use cvar::IProperty;
+	///
+	/// let mut fov = 90;
+	/// let mut prop = cvar::ClampedProp("fov", &mut fov, &90, Some(&60), Some(&120));
+	///
+	/// let mut writer = String::new();
+	/// assert!(prop.validate("100", &mut writer));
+	/// assert!(!prop.validate("150", &mut writer));
+	/// assert_eq!(writer, "value 150 out of range [60, 120]");
+	/// assert_eq!(fov, 90);
+	/// ```
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		let _ = val;
+		let _ = writer;
+		true
+	}
+
 	/// Resets the value to its default.
 	///
 	/// If this operation fails (for eg. read-only properties), it does so silently.
@@ -295,16 +1400,85 @@ pub trait IProperty: INode {
 	/// Gets the default value.
 	fn default_value(&self) -> &dyn IValue;
 
+	/// Sets the default value parsed from string.
+	///
+	/// The new default is the baseline for the [state](IProperty::state) and [reset](IProperty::reset), eg. after loading a configuration layer from disk.
+	/// The current value is not changed.
+	///
+	/// Properties borrowing their default cannot change it and fail, see [`OwnedProp`].
+	///
+	/// ```
+	/// use cvar::IProperty;
+	///
+	/// let mut volume = cvar::OwnedProp(String::from("volume"), 0.5, 1.0);
+	/// assert!(volume.set_default("0.5", &mut cvar::NullWriter));
+	/// assert_eq!(volume.state(), cvar::PropState::Default);
+	///
+	/// volume.set("0.8", &mut cvar::NullWriter);
+	/// volume.reset();
+	/// assert_eq!(volume.variable, 0.5);
+	/// ```
+	fn set_default(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		let _ = val;
+		let _ = writer.write_str("default value cannot be changed");
+		false
+	}
+
 	/// Returns the state of the property.
 	fn state(&self) -> PropState;
 
 	/// Returns the flags associated with the property.
 	///
-	/// The meaning of this value is defined by the caller.
+	/// The meaning of this value is defined by the caller, see the [`flags`] module for conventional flags.
 	fn flags(&self) -> u32 {
 		0
 	}
 
+	/// Returns the tolerance for comparing float values.
+	///
+	/// Used when computing the [state](IProperty::state) and by [`console::diff`], zero compares values exactly.
+	fn epsilon(&self) -> f64 {
+		0.0
+	}
+
+	/// Returns the inclusive lower bound of the value, if any.
+	///
+	/// Values outside the bounds are rejected by the property, see [`ClampedProp`].
+	/// Settings menus use the bounds to render sliders.
+	///
+	/// ```
+	/// let mut volume = 0.5f32;
+	/// let mut root = cvar::Visit(|f| {
+	/// 	f(&mut cvar::ClampedProp("volume", &mut volume, &1.0, Some(&0.0), Some(&1.0)));
+	/// });
+	///
+	/// let mut bounds = None;
+	/// cvar::console::find(&mut root, "volume", |node| {
+	/// 	if let cvar::Node::Prop(prop) = node.as_node() {
+	/// 		bounds = Some((prop.min_value().map(|v| v.to_string()), prop.max_value().map(|v| v.to_string())));
+	/// 	}
+	/// });
+	/// assert_eq!(bounds, Some((Some(String::from("0")), Some(String::from("1")))));
+	/// ```
+	fn min_value(&self) -> Option<&dyn IValue> {
+		None
+	}
+
+	/// Returns the inclusive upper bound of the value, if any.
+	fn max_value(&self) -> Option<&dyn IValue> {
+		None
+	}
+
+	/// Returns the configuration layer the current value came from.
+	///
+	/// Properties without layers report [`Layer::Default`] for default values and [`Layer::Runtime`] otherwise.
+	fn provenance(&self) -> Layer {
+		match self.state() {
+			PropState::Default => Layer::Default,
+			_ => Layer::Runtime,
+		}
+	}
+
 	/// Returns the name of the concrete type.
 	#[cfg(feature = "type_name")]
 	fn type_name(&self) -> &str {
@@ -327,6 +1501,8 @@ impl fmt::Debug for dyn IProperty + '_ {
 		debug.field("default", &self.default_value());
 		debug.field("state", &self.state());
 		debug.field("flags", &self.flags());
+		debug.field("epsilon", &self.epsilon());
+		debug.field("provenance", &self.provenance());
 		#[cfg(feature = "type_name")]
 		debug.field("type", &self.type_name());
 		debug.field("values", &self.values());
@@ -337,8 +1513,32 @@ impl fmt::Debug for dyn IProperty + '_ {
 //----------------------------------------------------------------
 
 /// Property node.
+///
+/// Any type implementing [`Clone`], [`PartialEq`], [`Display`](fmt::Display) and [`FromStr`] can be used, such as network addresses.
+/// The default is given by reference so no [`Default`] implementation is needed, `Clone` is used by [reset](IProperty::reset) and `PartialEq` by [state](IProperty::state).
+/// See [`ReadOnlyProp`] for values which are never parsed.
+///
+/// ```
+/// use std::net::{Ipv4Addr, SocketAddr};
+///
+/// let default = SocketAddr::from((Ipv4Addr::LOCALHOST, 27015));
+/// let mut server = default;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("server", &mut server, &default));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "server", "192.168.1.10:27016"));
+/// assert_eq!(cvar::console::get(&mut root, "server").as_deref(), Some("192.168.1.10:27016"));
+/// ```
 pub struct Property<'a, 'x, T: 'static> {
 	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	epsilon: f64,
+	finite: bool,
 	variable: &'x mut T,
 	default: &'a T,
 }
@@ -346,24 +1546,97 @@ pub struct Property<'a, 'x, T: 'static> {
 #[allow(non_snake_case)]
 #[inline]
 pub fn Property<'a, 'x, T>(name: &'a str, variable: &'x mut T, default: &'a T) -> Property<'a, 'x, T> {
-	Property { name, variable, default }
+	Property { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, epsilon: 0.0, finite: false, variable, default }
 }
 
 impl<'a, 'x, T> Property<'a, 'x, T> {
 	#[inline]
 	pub fn new(name: &'a str, variable: &'x mut T, default: &'a T) -> Property<'a, 'x, T> {
-		Property { name, variable, default }
+		Property { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, epsilon: 0.0, finite: false, variable, default }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+
+	/// Sets the tolerance for comparing float values.
+	///
+	/// The value is considered the default if it differs less than the tolerance, see [`IProperty::epsilon`].
+	#[inline]
+	pub fn with_epsilon(self, epsilon: f64) -> Self {
+		Self { epsilon, ..self }
+	}
+
+	/// Rejects NaN and infinite float values.
+	///
+	/// ```
+	/// let mut speed = 1.0;
+	/// let mut root = cvar::Visit(|f| {
+	/// 	f(&mut cvar::Property("speed", &mut speed, &1.0).with_finite(true));
+	/// });
+	///
+	/// let mut writer = String::new();
+	/// assert!(!cvar::console::set(&mut root, "speed", "inf", &mut writer));
+	/// assert_eq!(writer, "error: speed \"inf\": value inf is not finite\n");
+	/// ```
+	#[inline]
+	pub fn with_finite(self, finite: bool) -> Self {
+		Self { finite, ..self }
 	}
 }
 
 impl<'a, 'x, T> INode for Property<'a, 'x, T>
-	where T: Clone + Default + PartialEq + fmt::Display + FromStr,
+	where T: Clone + PartialEq + fmt::Display + FromStr,
 	      T::Err: StdError + Send + Sync + 'static
 {
 	fn name(&self) -> &str {
 		self.name
 	}
 
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
 	fn as_node(&mut self) -> Node<'_> {
 		Node::Prop(self)
 	}
@@ -374,7 +1647,7 @@ impl<'a, 'x, T> INode for Property<'a, 'x, T>
 }
 
 impl<'a, 'x, T> IProperty for Property<'a, 'x, T>
-	where T: Clone + Default + PartialEq + fmt::Display + FromStr,
+	where T: Clone + PartialEq + fmt::Display + FromStr,
 	      T::Err: StdError + Send + Sync + 'static
 {
 	fn get_value(&self) -> &dyn IValue {
@@ -383,6 +1656,9 @@ impl<'a, 'x, T> IProperty for Property<'a, 'x, T>
 
 	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
 		if let Some(val) = val.downcast_ref::<T>() {
+			if !accept_finite(self.finite, val, writer) {
+				return false;
+			}
 			self.variable.clone_from(val);
 			true
 		}
@@ -395,6 +1671,9 @@ impl<'a, 'x, T> IProperty for Property<'a, 'x, T>
 	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
 		match val.parse::<T>() {
 			Ok(val) => {
+				if !accept_finite(self.finite, &val, writer) {
+					return false;
+				}
 				*self.variable = val;
 				true
 			},
@@ -405,71 +1684,286 @@ impl<'a, 'x, T> IProperty for Property<'a, 'x, T>
 		}
 	}
 
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => accept_finite(self.finite, &val, writer),
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
 	fn reset(&mut self) {
-		self.variable.clone_from(&self.default);
+		self.variable.clone_from(self.default);
 	}
 
 	fn default_value(&self) -> &dyn IValue {
-		&*self.default
+		self.default
 	}
 
 	fn state(&self) -> PropState {
-		match *self.variable == *self.default {
+		match values_eq(&*self.variable, self.default, self.epsilon) {
 			true => PropState::Default,
 			false => PropState::UserSet,
 		}
 	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.epsilon
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		bool_values(self.default)
+	}
 }
 
 //----------------------------------------------------------------
 
+/// How [`ClampedProp`] treats values outside its bounds.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum OutOfRange {
+	/// The value is rejected, the set fails with an error.
+	#[default]
+	Reject,
+	/// The value is clamped to the nearest bound, the set succeeds with a note.
+	Clamp,
+}
+
+// Returns the bound the value exceeds
 #[inline]
-fn check_bounds_inclusive<T: PartialOrd>(val: &T, min: Option<&T>, max: Option<&T>) -> bool {
+fn exceeded_bound<'a, T: PartialOrd>(val: &T, min: Option<&'a T>, max: Option<&'a T>) -> Option<&'a T> {
 	if let Some(min) = min {
-		if *val >= *min {
-			return true;
+		if *val < *min {
+			return Some(min);
 		}
-		return false;
 	}
 	if let Some(max) = max {
-		if *val <= *max {
-			return true;
+		if *val > *max {
+			return Some(max);
 		}
+	}
+	None
+}
+
+// Checks float values for NaN and infinity, other values are always finite
+#[inline]
+fn is_finite<T: IValue>(val: &T) -> bool {
+	let val: &dyn IValue = val;
+	if let Some(val) = val.downcast_ref::<f64>() {
+		return val.is_finite();
+	}
+	if let Some(val) = val.downcast_ref::<f32>() {
+		return val.is_finite();
+	}
+	true
+}
+
+// Returns false and writes the error if non-finite values are rejected and the value is not finite
+#[inline]
+fn accept_finite<T: IValue + fmt::Display>(finite: bool, val: &T, writer: &mut dyn IWrite) -> bool {
+	if finite && !is_finite(val) {
+		let _ = write_not_finite(writer, val);
 		return false;
 	}
-	return true;
+	true
+}
+
+// Writes the error for a non-finite value: value NaN is not finite
+#[inline]
+fn write_not_finite<T: fmt::Display>(writer: &mut dyn IWrite, val: &T) -> fmt::Result {
+	write!(writer, "value {val} is not finite")
+}
+
+// Writes the error for a value outside its bounds: value 500 out of range [0, 100]
+fn write_out_of_range<T: fmt::Display>(writer: &mut dyn IWrite, val: &T, min: Option<&T>, max: Option<&T>) -> fmt::Result {
+	write!(writer, "value {val} out of range [")?;
+	match min { Some(min) => write!(writer, "{min}")?, None => writer.write_str("..")? };
+	writer.write_str(", ")?;
+	match max { Some(max) => write!(writer, "{max}")?, None => writer.write_str("..")? };
+	writer.write_str("]")
 }
 
 /// Property node with its value clamped.
+///
+/// Values outside the bounds are rejected or clamped to the nearest bound, see [`OutOfRange`].
+///
+/// ```
+/// let mut volume = 50;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ClampedProp("volume", &mut volume, &50, Some(&0), Some(&100)));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut root, "volume", "500", &mut writer));
+/// assert_eq!(writer, "error: volume \"500\": value 500 out of range [0, 100]\n");
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ClampedProp("volume", &mut volume, &50, Some(&0), Some(&100)).with_out_of_range(cvar::OutOfRange::Clamp));
+/// });
+/// writer.clear();
+/// assert!(cvar::console::set(&mut root, "volume", "500", &mut writer));
+/// assert_eq!(writer, "volume: value 500 clamped to 100\n");
+/// ```
 pub struct ClampedProp<'a, 'x, T: 'static> {
 	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	epsilon: f64,
 	variable: &'x mut T,
 	default: &'a T,
 	min: Option<&'a T>,
 	max: Option<&'a T>,
+	out_of_range: OutOfRange,
+	finite: bool,
 }
 
 #[allow(non_snake_case)]
 #[inline]
 pub fn ClampedProp<'a, 'x, T>(name: &'a str, variable: &'x mut T, default: &'a T, min: Option<&'a T>, max: Option<&'a T>) -> ClampedProp<'a, 'x, T> {
-	ClampedProp { name, variable, default, min, max }
+	ClampedProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, epsilon: 0.0, variable, default, min, max, out_of_range: OutOfRange::Reject, finite: false }
 }
 
 impl<'a, 'x, T> ClampedProp<'a, 'x, T> {
 	#[inline]
 	pub fn new(name: &'a str, variable: &'x mut T, default: &'a T, min: Option<&'a T>, max: Option<&'a T>) -> ClampedProp<'a, 'x, T> {
-		ClampedProp { name, variable, default, min, max }
+		ClampedProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, epsilon: 0.0, variable, default, min, max, out_of_range: OutOfRange::Reject, finite: false }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+
+	/// Sets the tolerance for comparing float values.
+	///
+	/// The value is considered the default if it differs less than the tolerance, see [`IProperty::epsilon`].
+	#[inline]
+	pub fn with_epsilon(self, epsilon: f64) -> Self {
+		Self { epsilon, ..self }
+	}
+
+	/// Sets how values outside the bounds are treated.
+	#[inline]
+	pub fn with_out_of_range(self, out_of_range: OutOfRange) -> Self {
+		Self { out_of_range, ..self }
+	}
+
+	/// Rejects NaN and infinite float values.
+	///
+	/// NaN is not ordered and passes the bounds check, a property which should never hold NaN must reject it explicitly.
+	///
+	/// ```
+	/// let mut gravity = 9.81;
+	/// let mut root = cvar::Visit(|f| {
+	/// 	f(&mut cvar::ClampedProp("gravity", &mut gravity, &9.81, Some(&0.0), None).with_finite(true));
+	/// });
+	///
+	/// let mut writer = String::new();
+	/// assert!(!cvar::console::set(&mut root, "gravity", "nan", &mut writer));
+	/// assert_eq!(writer, "error: gravity \"nan\": value NaN is not finite\n");
+	/// ```
+	#[inline]
+	pub fn with_finite(self, finite: bool) -> Self {
+		Self { finite, ..self }
+	}
+}
+
+impl<'a, 'x, T> ClampedProp<'a, 'x, T>
+	where T: Clone + PartialOrd + fmt::Display
+{
+	// Stores the value if it is within bounds, otherwise rejects or clamps it
+	fn store(&mut self, val: &T, writer: &mut dyn IWrite) -> bool {
+		match self.accept(val, writer) {
+			Some(val) => {
+				let val = val.clone();
+				*self.variable = val;
+				true
+			},
+			None => false,
+		}
+	}
+
+	// Returns the value to store or writes why the value is rejected
+	fn accept<'v>(&'v self, val: &'v T, writer: &mut dyn IWrite) -> Option<&'v T> {
+		if self.finite && !is_finite(val) {
+			let _ = write_not_finite(writer, val);
+			return None;
+		}
+		let Some(bound) = exceeded_bound(val, self.min, self.max) else {
+			return Some(val);
+		};
+		match self.out_of_range {
+			OutOfRange::Reject => {
+				let _ = write_out_of_range(writer, val, self.min, self.max);
+				None
+			},
+			OutOfRange::Clamp => {
+				let _ = write!(writer, "value {val} clamped to {bound}");
+				Some(bound)
+			},
+		}
 	}
 }
 
 impl<'a, 'x, T> INode for ClampedProp<'a, 'x, T>
-	where T: Clone + Default + PartialEq + PartialOrd + fmt::Display + FromStr,
+	where T: Clone + PartialEq + PartialOrd + fmt::Display + FromStr,
 	      T::Err: StdError + Send + Sync + 'static
 {
 	fn name(&self) -> &str {
 		self.name
 	}
 
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
 	fn as_node(&mut self) -> Node<'_> {
 		Node::Prop(self)
 	}
@@ -480,7 +1974,7 @@ impl<'a, 'x, T> INode for ClampedProp<'a, 'x, T>
 }
 
 impl<'a, 'x, T> IProperty for ClampedProp<'a, 'x, T>
-	where T: Clone + Default + PartialEq + PartialOrd + fmt::Display + FromStr,
+	where T: Clone + PartialEq + PartialOrd + fmt::Display + FromStr,
 	      T::Err: StdError + Send + Sync + 'static
 {
 	fn get_value(&self) -> &dyn IValue {
@@ -489,10 +1983,7 @@ impl<'a, 'x, T> IProperty for ClampedProp<'a, 'x, T>
 
 	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
 		if let Some(val) = val.downcast_ref::<T>() {
-			if check_bounds_inclusive(val, self.min, self.max) {
-				self.variable.clone_from(val);
-			}
-			true
+			self.store(val, writer)
 		}
 		else {
 			let _ = write_mismatched_types::<T>(writer, val);
@@ -502,12 +1993,17 @@ impl<'a, 'x, T> IProperty for ClampedProp<'a, 'x, T>
 
 	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
 		match val.parse::<T>() {
-			Ok(val) => {
-				if check_bounds_inclusive(&val, self.min, self.max) {
-					*self.variable = val;
-				}
-				true
+			Ok(val) => self.store(&val, writer),
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
 			},
+		}
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => self.accept(&val, writer).is_some(),
 			Err(err) => {
 				let _ = write_error(writer, &err);
 				false
@@ -516,26 +2012,67 @@ impl<'a, 'x, T> IProperty for ClampedProp<'a, 'x, T>
 	}
 
 	fn reset(&mut self) {
-		self.variable.clone_from(&self.default);
+		self.variable.clone_from(self.default);
 	}
 
 	fn default_value(&self) -> &dyn IValue {
-		&*self.default
+		self.default
 	}
 
 	fn state(&self) -> PropState {
-		match *self.variable == *self.default {
+		match values_eq(&*self.variable, self.default, self.epsilon) {
 			true => PropState::Default,
 			false => PropState::UserSet,
 		}
 	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.epsilon
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.min.map(|min| min as &dyn IValue)
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.max.map(|max| max as &dyn IValue)
+	}
 }
 
 //----------------------------------------------------------------
 
 /// Read-only property node.
+///
+/// The value only needs to implement [`PartialEq`] and [`Display`](fmt::Display), it is never parsed.
+///
+/// ```
+/// use std::fmt;
+///
+/// #[derive(PartialEq)]
+/// enum Status { Offline, Online }
+/// impl fmt::Display for Status {
+/// 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+/// 		f.write_str(match self { Status::Offline => "offline", Status::Online => "online" })
+/// 	}
+/// }
+///
+/// let status = Status::Online;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::ReadOnlyProp("status", &status, &Status::Offline));
+/// });
+/// assert_eq!(cvar::console::get(&mut root, "status").as_deref(), Some("online"));
+/// ```
 pub struct ReadOnlyProp<'a, T: 'static> {
 	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
 	variable: &'a T,
 	default: &'a T,
 }
@@ -543,13 +2080,45 @@ pub struct ReadOnlyProp<'a, T: 'static> {
 #[allow(non_snake_case)]
 #[inline]
 pub fn ReadOnlyProp<'a, T>(name: &'a str, variable: &'a T, default: &'a T) -> ReadOnlyProp<'a, T> {
-	ReadOnlyProp { name, variable, default }
+	ReadOnlyProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default }
 }
 
 impl<'a, T> ReadOnlyProp<'a, T> {
 	#[inline]
 	pub fn new(name: &'a str, variable: &'a T, default: &'a T) -> ReadOnlyProp<'a, T> {
-		ReadOnlyProp { name, variable, default }
+		ReadOnlyProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
 	}
 }
 
@@ -558,6 +2127,22 @@ impl<'a, T: PartialEq + IValue> INode for ReadOnlyProp<'a, T> {
 		self.name
 	}
 
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
 	fn as_node(&mut self) -> Node<'_> {
 		Node::Prop(self)
 	}
@@ -569,7 +2154,7 @@ impl<'a, T: PartialEq + IValue> INode for ReadOnlyProp<'a, T> {
 
 impl<'a, T: PartialEq + IValue> IProperty for ReadOnlyProp<'a, T> {
 	fn get_value(&self) -> &dyn IValue {
-		&*self.variable
+		self.variable
 	}
 
 	fn set_value(&mut self, _val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
@@ -582,10 +2167,15 @@ impl<'a, T: PartialEq + IValue> IProperty for ReadOnlyProp<'a, T> {
 		false
 	}
 
+	fn validate(&self, _val: &str, writer: &mut dyn IWrite) -> bool {
+		let _ = writer.write_str("cannot set read-only property");
+		false
+	}
+
 	fn reset(&mut self) {}
 
 	fn default_value(&self) -> &dyn IValue {
-		&*self.default
+		self.default
 	}
 
 	fn state(&self) -> PropState {
@@ -594,39 +2184,112 @@ impl<'a, T: PartialEq + IValue> IProperty for ReadOnlyProp<'a, T> {
 			false => PropState::UserSet,
 		}
 	}
+
+	fn flags(&self) -> u32 {
+		self.flags | flags::READONLY
+	}
 }
 
 //----------------------------------------------------------------
 
 /// Property node which owns its variable.
+#[non_exhaustive]
 pub struct OwnedProp<T: 'static> {
 	pub name: String,
+	pub desc: String,
+	pub label: String,
+	pub id: Option<u64>,
+	pub menu_hint: Option<&'static (dyn IMenuHint + Sync)>,
+	pub flags: u32,
+	pub epsilon: f64,
+	pub finite: bool,
 	pub variable: T,
 	pub default: T,
-	_private: (),
 }
 
 #[allow(non_snake_case)]
 #[inline]
 pub fn OwnedProp<T>(name: String, variable: T, default: T) -> OwnedProp<T> {
-	OwnedProp { name, variable, default, _private: () }
+	OwnedProp { name, desc: String::new(), label: String::new(), id: None, menu_hint: None, flags: 0, epsilon: 0.0, finite: false, variable, default }
 }
 
 impl<T> OwnedProp<T> {
 	#[inline]
 	pub fn new(name: String, variable: T, default: T) -> OwnedProp<T> {
-		OwnedProp { name, variable, default, _private: () }
+		OwnedProp { name, desc: String::new(), label: String::new(), id: None, menu_hint: None, flags: 0, epsilon: 0.0, finite: false, variable, default }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: String) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: String) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'static (dyn IMenuHint + Sync)) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+
+	/// Sets the tolerance for comparing float values.
+	///
+	/// The value is considered the default if it differs less than the tolerance, see [`IProperty::epsilon`].
+	#[inline]
+	pub fn with_epsilon(self, epsilon: f64) -> Self {
+		Self { epsilon, ..self }
+	}
+
+	/// Rejects NaN and infinite float values, see [`Property::with_finite`].
+	#[inline]
+	pub fn with_finite(self, finite: bool) -> Self {
+		Self { finite, ..self }
 	}
 }
 
 impl<T> INode for OwnedProp<T>
-	where T: Clone + Default + PartialEq + fmt::Display + FromStr,
+	where T: Clone + PartialEq + fmt::Display + FromStr,
 	      T::Err: StdError + Send + Sync + 'static
 {
 	fn name(&self) -> &str {
 		&self.name
 	}
 
+	fn description(&self) -> &str {
+		&self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { &self.name } else { &self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint.map(|hint| hint as &dyn IMenuHint)
+	}
+
 	fn as_node(&mut self) -> Node<'_> {
 		Node::Prop(self)
 	}
@@ -637,7 +2300,7 @@ impl<T> INode for OwnedProp<T>
 }
 
 impl<T> IProperty for OwnedProp<T>
-	where T: Clone + Default + PartialEq + fmt::Display + FromStr,
+	where T: Clone + PartialEq + fmt::Display + FromStr,
 	      T::Err: StdError + Send + Sync + 'static
 {
 	fn get_value(&self) -> &dyn IValue {
@@ -646,6 +2309,9 @@ impl<T> IProperty for OwnedProp<T>
 
 	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
 		if let Some(val) = val.downcast_ref::<T>() {
+			if !accept_finite(self.finite, val, writer) {
+				return false;
+			}
 			self.variable.clone_from(val);
 			true
 		}
@@ -658,6 +2324,9 @@ impl<T> IProperty for OwnedProp<T>
 	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
 		match val.parse::<T>() {
 			Ok(val) => {
+				if !accept_finite(self.finite, &val, writer) {
+					return false;
+				}
 				self.variable = val;
 				true
 			},
@@ -668,6 +2337,16 @@ impl<T> IProperty for OwnedProp<T>
 		}
 	}
 
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => accept_finite(self.finite, &val, writer),
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
 	fn reset(&mut self) {
 		self.variable.clone_from(&self.default);
 	}
@@ -676,12 +2355,37 @@ impl<T> IProperty for OwnedProp<T>
 		&self.default
 	}
 
+	fn set_default(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => {
+				self.default = val;
+				true
+			},
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
 	fn state(&self) -> PropState {
-		match self.variable == self.default {
+		match values_eq(&self.variable, &self.default, self.epsilon) {
 			true => PropState::Default,
 			false => PropState::UserSet,
 		}
 	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.epsilon
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		bool_values(&self.default)
+	}
 }
 
 //----------------------------------------------------------------
@@ -730,8 +2434,7 @@ impl fmt::Debug for dyn IVisit + '_ {
 /// 	f(&mut cvar::Property("value", &mut value, &0));
 /// });
 ///
-/// let mut writer = String::new();
-/// let _ = cvar::console::set(&mut visitor, "value", "42", &mut writer);
+/// cvar::console::set_silent(&mut visitor, "value", "42");
 /// assert_eq!(value, 42);
 /// ```
 #[derive(Copy, Clone, Debug)]
@@ -744,6 +2447,140 @@ impl<F: FnMut(&mut dyn FnMut(&mut dyn INode))> IVisit for Visit<F> {
 	}
 }
 
+/// Node visitor which combines multiple visitors into one tree.
+///
+/// Visits every visitor in order, use it when parts of the configuration are owned by different crates.
+///
+/// ```
+/// let mut fps_max = 300;
+/// let mut engine = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("fps_max", &mut fps_max, &300));
+/// });
+/// let mut friendly_fire = false;
+/// let mut game = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("friendly_fire", &mut friendly_fire, &false));
+/// });
+///
+/// let mut root = cvar::MergedRoot(&mut [&mut engine, &mut game]);
+/// assert!(cvar::console::set(&mut root, "fps_max", "144", &mut cvar::NullWriter));
+/// assert!(cvar::console::set(&mut root, "friendly_fire", "true", &mut cvar::NullWriter));
+/// ```
+#[derive(Debug)]
+pub struct MergedRoot<'a, 'x>(pub &'a mut [&'x mut dyn IVisit]);
+
+impl<'a, 'x> IVisit for MergedRoot<'a, 'x> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let Self(roots) = self;
+		for root in roots.iter_mut() {
+			root.visit(f);
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Returns `true` if the node name can be addressed from the console.
+///
+/// Valid names are not empty and do not contain whitespace, control characters or double quotes.
+/// Names may contain `.` to pretend to be part of a nested list, but the parts separated by `.` may not be empty.
+///
+/// ```
+/// assert!(cvar::is_valid_name("volume"));
+/// assert!(cvar::is_valid_name("video.width"));
+/// assert!(cvar::is_valid_name("reload!"));
+/// assert!(!cvar::is_valid_name(""));
+/// assert!(!cvar::is_valid_name("master volume"));
+/// assert!(!cvar::is_valid_name("video..width"));
+/// assert!(!cvar::is_valid_name(".width"));
+/// ```
+pub fn is_valid_name(name: &str) -> bool {
+	name.split('.').all(|part| {
+		!part.is_empty() && !part.chars().any(|chr| chr.is_whitespace() || chr.is_control() || chr == '"')
+	})
+}
+
+/// Node visitor which rejects invalid names.
+///
+/// Wraps a visitor and hides all nodes (including their children) for which [`is_valid_name`] returns `false`.
+/// Use [`console::check_names`] to report them instead.
+///
+/// ```
+/// let mut a = 1;
+/// let mut b = 2;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("a", &mut a, &1));
+/// 	f(&mut cvar::Property("b b", &mut b, &2));
+/// });
+///
+/// let mut names = Vec::new();
+/// cvar::console::walk(&mut cvar::StrictNames(&mut root), |path, _node| names.push(path.to_string()));
+/// assert_eq!(names, ["a"]);
+/// ```
+#[derive(Debug)]
+pub struct StrictNames<'a>(pub &'a mut dyn IVisit);
+
+impl<'a> IVisit for StrictNames<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let Self(this) = self;
+		visit_strict(*this, f);
+	}
+}
+
+fn visit_strict(root: &mut dyn IVisit, f: &mut dyn FnMut(&mut dyn INode)) {
+	root.visit(&mut |node| {
+		if is_valid_name(node.name()) {
+			match node.as_node() {
+				Node::List(list) => f(&mut StrictList(list)),
+				_ => f(node),
+			}
+		}
+	});
+}
+
+struct StrictList<'a>(&'a mut dyn IList);
+
+impl<'a> INode for StrictList<'a> {
+	fn name(&self) -> &str {
+		self.0.name()
+	}
+
+	fn description(&self) -> &str {
+		self.0.description()
+	}
+
+	fn label(&self) -> &str {
+		self.0.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.0.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.0.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IList for StrictList<'a> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a> IVisit for StrictList<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_strict(self.0.as_ivisit(), f);
+	}
+}
+
 //----------------------------------------------------------------
 
 /// List of child nodes.
@@ -768,25 +2605,249 @@ impl fmt::Debug for dyn IList + '_ {
 #[derive(Debug)]
 pub struct List<'a, 'x> {
 	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	visitor: &'x mut dyn IVisit,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn List<'a, 'x>(name: &'a str, visitor: &'x mut dyn IVisit) -> List<'a, 'x> {
+	List { name, desc: "", label: "", id: None, menu_hint: None, visitor }
+}
+
+impl<'a, 'x> List<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, visitor: &'x mut dyn IVisit) -> List<'a, 'x> {
+		List { name, desc: "", label: "", id: None, menu_hint: None, visitor }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+}
+
+impl<'a, 'x> INode for List<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IList for List<'a, 'x> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self.visitor
+	}
+}
+
+//----------------------------------------------------------------
+
+/// List node which owns its visitor.
+///
+/// ```
+/// let mut physics = cvar::DynamicList::new();
+/// physics.insert("gravity", Box::new(cvar::OwnedProp(String::from("gravity"), 9.81, 9.81)));
+///
+/// let mut plugins = cvar::DynamicList::new();
+/// plugins.insert("physics", Box::new(cvar::OwnedList(String::from("physics"), physics)));
+///
+/// assert_eq!(cvar::console::get(&mut plugins, "physics.gravity").as_deref(), Some("9.81"));
+/// ```
+#[non_exhaustive]
+pub struct OwnedList {
+	pub name: String,
+	pub desc: String,
+	pub label: String,
+	pub id: Option<u64>,
+	pub menu_hint: Option<&'static (dyn IMenuHint + Sync)>,
+	pub visitor: Box<dyn IVisit + Send>,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn OwnedList<V: IVisit + Send + 'static>(name: String, visitor: V) -> OwnedList {
+	OwnedList { name, desc: String::new(), label: String::new(), id: None, menu_hint: None, visitor: Box::new(visitor) }
+}
+
+impl OwnedList {
+	#[inline]
+	pub fn new<V: IVisit + Send + 'static>(name: String, visitor: V) -> OwnedList {
+		OwnedList { name, desc: String::new(), label: String::new(), id: None, menu_hint: None, visitor: Box::new(visitor) }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: String) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: String) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'static (dyn IMenuHint + Sync)) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+}
+
+impl fmt::Debug for OwnedList {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("OwnedList")
+			.field("name", &self.name)
+			.field("desc", &self.desc)
+			.field("label", &self.label)
+			.field("id", &self.id)
+			.finish_non_exhaustive()
+	}
+}
+
+impl INode for OwnedList {
+	fn name(&self) -> &str {
+		&self.name
+	}
+
+	fn description(&self) -> &str {
+		&self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { &self.name } else { &self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint.map(|hint| hint as &dyn IMenuHint)
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl IList for OwnedList {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		&mut *self.visitor
+	}
+}
+
+//----------------------------------------------------------------
+
+/// List node which mounts a visitor under a dotted prefix.
+///
+/// Every component of the prefix becomes a nested list node, the innermost list visits the mounted visitor.
+/// Use it to mount the trees registered by plugins without writing wrapper structs.
+///
+/// Mounting multiple visitors under a shared prefix results in multiple list nodes with the same name, the console visits all of them by default, see [`MultiMatch`](console::MultiMatch).
+///
+/// ```
+/// let mut gravity = 9.81;
+/// let mut physics = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("gravity", &mut gravity, &9.81));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Mounted("plugins.physics", &mut physics));
+/// });
+///
+/// assert!(cvar::console::set(&mut root, "plugins.physics.gravity", "1.62", &mut cvar::NullWriter));
+/// assert_eq!(cvar::console::get(&mut root, "plugins.physics.gravity").as_deref(), Some("1.62"));
+/// ```
+#[derive(Debug)]
+pub struct Mounted<'a, 'x> {
+	prefix: &'a str,
+	menu_hint: Option<&'a dyn IMenuHint>,
 	visitor: &'x mut dyn IVisit,
 }
 
 #[allow(non_snake_case)]
 #[inline]
-pub fn List<'a, 'x>(name: &'a str, visitor: &'x mut dyn IVisit) -> List<'a, 'x> {
-	List { name, visitor }
+pub fn Mounted<'a, 'x>(prefix: &'a str, visitor: &'x mut dyn IVisit) -> Mounted<'a, 'x> {
+	Mounted { prefix, menu_hint: None, visitor }
 }
 
-impl<'a, 'x> List<'a, 'x> {
+impl<'a, 'x> Mounted<'a, 'x> {
 	#[inline]
-	pub fn new(name: &'a str, visitor: &'x mut dyn IVisit) -> List<'a, 'x> {
-		List { name, visitor }
+	pub fn new(prefix: &'a str, visitor: &'x mut dyn IVisit) -> Mounted<'a, 'x> {
+		Mounted { prefix, menu_hint: None, visitor }
+	}
+
+	/// Sets the presentation hints for menus.
+	///
+	/// The hints apply to the innermost list node which visits the mounted visitor.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
 	}
 }
 
-impl<'a, 'x> INode for List<'a, 'x> {
+impl<'a, 'x> INode for Mounted<'a, 'x> {
 	fn name(&self) -> &str {
-		self.name
+		self.prefix.split_once('.').map_or(self.prefix, |(name, _)| name)
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		if self.prefix.contains('.') { None } else { self.menu_hint }
 	}
 
 	fn as_node(&mut self) -> Node<'_> {
@@ -798,9 +2859,17 @@ impl<'a, 'x> INode for List<'a, 'x> {
 	}
 }
 
-impl<'a, 'x> IList for List<'a, 'x> {
+impl<'a, 'x> IList for Mounted<'a, 'x> {
 	fn as_ivisit(&mut self) -> &mut dyn IVisit {
-		self.visitor
+		if self.prefix.contains('.') { self } else { self.visitor }
+	}
+}
+
+impl<'a, 'x> IVisit for Mounted<'a, 'x> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		if let Some((_, tail)) = self.prefix.split_once('.') {
+			f(&mut Mounted { prefix: tail, menu_hint: self.menu_hint, visitor: &mut *self.visitor });
+		}
 	}
 }
 
@@ -844,6 +2913,17 @@ fn write_error<T: ?Sized + StdError>(writer: &mut dyn IWrite, v: &T) -> fmt::Res
 	writer.write_fmt(format_args!("{}", v))
 }
 
+// Checks the value parses as T, the error is written to the writer
+fn validate_parse<T: FromStr>(val: &str, writer: &mut dyn IWrite) -> bool where T::Err: StdError {
+	match val.parse::<T>() {
+		Ok(_) => true,
+		Err(err) => {
+			let _ = write_error(writer, &err);
+			false
+		},
+	}
+}
+
 #[cfg(feature = "type_name")]
 #[inline]
 fn write_mismatched_types<T: IValue>(writer: &mut dyn IWrite, val: &dyn IValue) -> fmt::Result {
@@ -851,6 +2931,7 @@ fn write_mismatched_types<T: IValue>(writer: &mut dyn IWrite, val: &dyn IValue)
 }
 
 #[cfg(not(feature = "type_name"))]
+#[allow(clippy::extra_unused_type_parameters)]
 #[inline]
 fn write_mismatched_types<T: IValue>(writer: &mut dyn IWrite, _val: &dyn IValue) -> fmt::Result {
 	writer.write_str("mismatched types")
@@ -903,8 +2984,236 @@ impl IoWriter<io::Stderr> {
 	}
 }
 
+// Calls the closure for every complete line, the remainder stays buffered
+fn write_lines(line: &mut String, s: &str, mut f: impl FnMut(String)) {
+	line.push_str(s);
+	while let Some(index) = line.find('\n') {
+		let rest = line.split_off(index + 1);
+		line.pop();
+		f(std::mem::replace(line, rest));
+	}
+}
+
+/// Channel writer.
+///
+/// Helper which sends every line written to it without the trailing newline.
+/// Incomplete lines are buffered until the next newline or until the writer is dropped.
+///
+/// ```
+/// let (sender, receiver) = std::sync::mpsc::channel();
+/// let mut writer = cvar::ChannelWriter::new(sender);
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Action("hello!", |_args, writer| { let _ = write!(writer, "Hello\nWorld"); }));
+/// });
+/// cvar::console::invoke(&mut root, "hello!", "", &mut writer);
+/// drop(writer);
+///
+/// assert_eq!(receiver.iter().collect::<Vec<_>>(), ["Hello", "World"]);
+/// ```
+#[derive(Debug)]
+pub struct ChannelWriter {
+	sender: std::sync::mpsc::Sender<String>,
+	line: String,
+}
+
+impl ChannelWriter {
+	#[inline]
+	pub fn new(sender: std::sync::mpsc::Sender<String>) -> ChannelWriter {
+		ChannelWriter { sender, line: String::new() }
+	}
+}
+
+impl fmt::Write for ChannelWriter {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let mut result = Ok(());
+		write_lines(&mut self.line, s, |line| {
+			if self.sender.send(line).is_err() {
+				result = Err(fmt::Error);
+			}
+		});
+		result
+	}
+}
+
+impl IWrite for ChannelWriter {}
+
+impl Drop for ChannelWriter {
+	fn drop(&mut self) {
+		if !self.line.is_empty() {
+			let _ = self.sender.send(std::mem::take(&mut self.line));
+		}
+	}
+}
+
+/// Bounded writer.
+///
+/// Helper which forwards at most a maximum number of bytes to the inner writer.
+/// Output beyond the limit is dropped and replaced by the `...` marker if it fits, the marker counts towards the limit.
+/// Use it to run listing commands over large trees for outputs with strict size limits.
+///
+/// ```
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Action("hello!", |_args, writer| { let _ = write!(writer, "Hello World"); }));
+/// });
+///
+/// let mut writer = cvar::BoundedWriter::new(String::new(), 8);
+/// cvar::console::invoke(&mut root, "hello!", "", &mut writer);
+/// assert!(writer.is_truncated());
+/// assert_eq!(writer.into_inner(), "Hello...");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BoundedWriter<W> {
+	inner: W,
+	max_bytes: usize,
+	written: usize,
+	truncated: bool,
+}
+
+impl<W: IWrite> BoundedWriter<W> {
+	/// The marker written when the output is truncated.
+	pub const MARKER: &'static str = "...";
+
+	#[inline]
+	pub const fn new(inner: W, max_bytes: usize) -> BoundedWriter<W> {
+		BoundedWriter { inner, max_bytes, written: 0, truncated: false }
+	}
+
+	/// Returns `true` if output was dropped.
+	#[inline]
+	pub fn is_truncated(&self) -> bool {
+		self.truncated
+	}
+
+	/// Returns the number of bytes forwarded to the inner writer, including the marker.
+	#[inline]
+	pub fn written(&self) -> usize {
+		self.written
+	}
+
+	/// Returns the inner writer.
+	#[inline]
+	pub fn into_inner(self) -> W {
+		self.inner
+	}
+}
+
+impl<W: IWrite> fmt::Write for BoundedWriter<W> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		if self.truncated {
+			return Ok(());
+		}
+		if self.written + s.len() <= self.max_bytes {
+			self.written += s.len();
+			return self.inner.write_str(s);
+		}
+		// Keep as much as fits next to the marker without splitting a character
+		self.truncated = true;
+		let available = self.max_bytes.saturating_sub(self.written + Self::MARKER.len());
+		let mut end = available.min(s.len());
+		while !s.is_char_boundary(end) {
+			end -= 1;
+		}
+		let marker = if self.written + end + Self::MARKER.len() <= self.max_bytes { Self::MARKER } else { "" };
+		self.written += end + marker.len();
+		self.inner.write_str(&s[..end])?;
+		self.inner.write_str(marker)
+	}
+}
+
+impl<W: IWrite> IWrite for BoundedWriter<W> {}
+
+/// Log writer.
+///
+/// Helper which forwards every line written to it to the `log` crate.
+/// Incomplete lines are buffered until the next newline or until the writer is dropped.
+#[cfg(feature = "log")]
+#[derive(Debug)]
+pub struct LogWriter {
+	level: log::Level,
+	line: String,
+}
+
+#[cfg(feature = "log")]
+impl LogWriter {
+	/// Creates a writer logging at the given level.
+	#[inline]
+	pub fn new(level: log::Level) -> LogWriter {
+		LogWriter { level, line: String::new() }
+	}
+
+	/// Creates a writer logging at the info level.
+	#[inline]
+	pub fn info() -> LogWriter {
+		LogWriter::new(log::Level::Info)
+	}
+}
+
+#[cfg(feature = "log")]
+impl fmt::Write for LogWriter {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let level = self.level;
+		write_lines(&mut self.line, s, |line| log::log!(level, "{line}"));
+		Ok(())
+	}
+}
+
+#[cfg(feature = "log")]
+impl IWrite for LogWriter {}
+
+#[cfg(feature = "log")]
+impl Drop for LogWriter {
+	fn drop(&mut self) {
+		if !self.line.is_empty() {
+			log::log!(self.level, "{}", self.line);
+		}
+	}
+}
+
+/// Writes the output of the closure to a formatter.
+///
+/// Writers must be `'static` so a formatter cannot be used as a writer directly, the output is buffered instead.
+///
+/// ```
+/// struct Listing<'a>(std::cell::RefCell<&'a mut dyn cvar::IVisit>);
+///
+/// impl std::fmt::Display for Listing<'_> {
+/// 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// 		cvar::write_formatter(f, |writer| cvar::console::print(*self.0.borrow_mut(), "", writer))
+/// 	}
+/// }
+///
+/// let mut volume = 0.5;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0));
+/// });
+/// assert_eq!(Listing(std::cell::RefCell::new(&mut root)).to_string(), "volume is \"0.5\"\n");
+/// ```
+pub fn write_formatter(f: &mut fmt::Formatter, out: impl FnOnce(&mut dyn IWrite)) -> fmt::Result {
+	let mut buf = String::new();
+	out(&mut buf);
+	f.write_str(&buf)
+}
+
 //----------------------------------------------------------------
 
+/// Result of invoking an action, see [`IAction::invoke`].
+pub type BoxResult = Result<(), Box<dyn StdError>>;
+
+/// Error for actions given invalid arguments.
+///
+/// The console functions write the [usage](IAction::usage) of the action instead of the error.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct InvalidArgs;
+
+impl fmt::Display for InvalidArgs {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("invalid arguments")
+	}
+}
+
+impl StdError for InvalidArgs {}
+
 /// Action node interface.
 ///
 /// Provides an object safe interface for actions, type erasing its implementation.
@@ -912,7 +3221,22 @@ pub trait IAction: INode {
 	/// Invokes the closure associated with the Action.
 	///
 	/// Given argument string and a console interface to writer output to.
-	fn invoke(&mut self, args: &str, writer: &mut dyn IWrite);
+	/// Returns an error if the action failed, the console functions write the error and report the failure.
+	fn invoke(&mut self, args: &str, writer: &mut dyn IWrite) -> BoxResult;
+
+	/// Returns the usage of the arguments, eg. `<player> [reason]`.
+	///
+	/// Empty if the action did not declare its usage.
+	fn usage(&self) -> &str {
+		""
+	}
+
+	/// Completes the partial arguments.
+	///
+	/// Returns the candidates for the complete argument string, see [`console::complete`].
+	fn complete(&mut self, _partial_args: &str) -> Vec<String> {
+		Vec::new()
+	}
 }
 
 impl fmt::Debug for dyn IAction + '_ {
@@ -925,31 +3249,319 @@ impl fmt::Debug for dyn IAction + '_ {
 
 //----------------------------------------------------------------
 
+/// Argument completion callback given the partial arguments, see [`IAction::complete`].
+pub type Complete<'a> = &'a dyn Fn(&str) -> Vec<String>;
+
+/// Value returned by action callbacks.
+///
+/// Callbacks return `()`, a `bool` which is `false` if the arguments are invalid, or a `Result` with any error convertible to a boxed error.
+/// When the arguments are invalid the console writes the usage of the action, see [`Action::with_usage`].
+///
+/// ```
+/// let mut port = 0u16;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Action("connect", |args, _writer| -> Result<(), Box<dyn std::error::Error>> {
+/// 		port = args.parse()?;
+/// 		Ok(())
+/// 	}));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::invoke(&mut root, "connect", "http", &mut writer));
+/// assert_eq!(writer, "error: connect: invalid digit found in string\n");
+/// assert!(cvar::console::invoke(&mut root, "connect", "8080", &mut writer));
+/// ```
+pub trait IActionResult {
+	/// Converts into the result of the action.
+	fn into_result(self) -> BoxResult;
+}
+
+impl IActionResult for () {
+	#[inline]
+	fn into_result(self) -> BoxResult {
+		Ok(())
+	}
+}
+
+impl IActionResult for bool {
+	#[inline]
+	fn into_result(self) -> BoxResult {
+		if self { Ok(()) } else { Err(Box::new(InvalidArgs)) }
+	}
+}
+
+impl<E: Into<Box<dyn StdError>>> IActionResult for Result<(), E> {
+	#[inline]
+	fn into_result(self) -> BoxResult {
+		self.map_err(Into::into)
+	}
+}
+
 /// Action node.
-#[derive(Debug)]
-pub struct Action<'a, F: FnMut(&str, &mut dyn IWrite)> {
+pub struct Action<'a, F> {
 	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	usage: &'a str,
 	invoke: F,
+	complete: Option<Complete<'a>>,
 }
 
 #[allow(non_snake_case)]
 #[inline]
-pub fn Action<'a, F: FnMut(&str, &mut dyn IWrite)>(name: &'a str, invoke: F) -> Action<'a, F> {
-	Action { name, invoke }
+pub fn Action<'a, F: FnMut(&str, &mut dyn IWrite) -> R, R: IActionResult>(name: &'a str, invoke: F) -> Action<'a, F> {
+	Action { name, desc: "", label: "", id: None, menu_hint: None, usage: "", invoke, complete: None }
 }
 
-impl<'a, F: FnMut(&str, &mut dyn IWrite)> Action<'a, F> {
+impl<'a, F: FnMut(&str, &mut dyn IWrite) -> R, R: IActionResult> Action<'a, F> {
 	#[inline]
 	pub fn new(name: &'a str, invoke: F) -> Action<'a, F> {
-		Action { name, invoke }
+		Action { name, desc: "", label: "", id: None, menu_hint: None, usage: "", invoke, complete: None }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the argument completion callback.
+	///
+	/// ```
+	/// let complete = |partial: &str| ["easy", "hard"].iter().filter(|s| s.starts_with(partial)).map(|s| s.to_string()).collect();
+	/// let mut root = cvar::Visit(|f| {
+	/// 	f(&mut cvar::Action("difficulty", |_args, _writer| ()).with_complete(&complete));
+	/// });
+	///
+	/// assert_eq!(cvar::console::complete(&mut root, "difficulty h"), ["difficulty hard"]);
+	/// ```
+	#[inline]
+	pub fn with_complete(self, complete: Complete<'a>) -> Self {
+		Self { complete: Some(complete), ..self }
+	}
+
+	/// Sets the usage of the arguments.
+	///
+	/// If the callback returns `false` or [`InvalidArgs`] the console writes `usage: path <usage>`.
+	///
+	/// ```
+	/// let mut players = vec![String::from("Bob")];
+	/// let mut root = cvar::Visit(|f| {
+	/// 	f(&mut cvar::Action("kick", |args, writer| {
+	/// 		let Some(player) = args.split_whitespace().next() else { return false };
+	/// 		players.retain(|name| name != player);
+	/// 		let _ = writeln!(writer, "kicked {player}");
+	/// 		true
+	/// 	}).with_usage("<player> [reason]"));
+	/// });
+	///
+	/// let mut writer = String::new();
+	/// cvar::console::invoke(&mut root, "kick", "", &mut writer);
+	/// assert_eq!(writer, "usage: kick <player> [reason]\n");
+	/// ```
+	#[inline]
+	pub fn with_usage(self, usage: &'a str) -> Self {
+		Self { usage, ..self }
+	}
+}
+
+impl<'a, F: FnMut(&str, &mut dyn IWrite) -> R + fmt::Debug, R: IActionResult> fmt::Debug for Action<'a, F> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Action")
+			.field("name", &self.name)
+			.field("desc", &self.desc)
+			.field("label", &self.label)
+			.field("id", &self.id)
+			.field("menu_hint", &self.menu_hint)
+			.field("usage", &self.usage)
+			.field("invoke", &self.invoke)
+			.field("complete", &self.complete.is_some())
+			.finish()
 	}
 }
 
-impl<'a, F: FnMut(&str, &mut dyn IWrite)> INode for Action<'a, F> {
+impl<'a, F: FnMut(&str, &mut dyn IWrite) -> R, R: IActionResult> INode for Action<'a, F> {
 	fn name(&self) -> &str {
 		self.name
 	}
 
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Action(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, F: FnMut(&str, &mut dyn IWrite) -> R, R: IActionResult> IAction for Action<'a, F> {
+	fn invoke(&mut self, args: &str, writer: &mut dyn IWrite) -> BoxResult {
+		(self.invoke)(args, writer).into_result()
+	}
+
+	fn usage(&self) -> &str {
+		self.usage
+	}
+
+	fn complete(&mut self, partial_args: &str) -> Vec<String> {
+		match self.complete {
+			Some(complete) => complete(partial_args),
+			None => Vec::new(),
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Boxed action callback given the arguments and the writer, see [`OwnedAction`].
+pub type OwnedInvoke = Box<dyn FnMut(&str, &mut dyn IWrite) -> BoxResult + Send>;
+
+/// Action node which owns its callback.
+///
+/// ```
+/// let mut plugins = cvar::DynamicList::new();
+/// let hello = cvar::OwnedAction(String::from("hello!"), |args, writer| { let _ = writeln!(writer, "hello {args}"); });
+/// plugins.insert("hello!", Box::new(hello));
+///
+/// let mut writer = String::new();
+/// cvar::console::invoke(&mut plugins, "hello!", "world", &mut writer);
+/// assert_eq!(writer, "hello world\n");
+/// ```
+#[non_exhaustive]
+pub struct OwnedAction {
+	pub name: String,
+	pub desc: String,
+	pub label: String,
+	pub id: Option<u64>,
+	pub menu_hint: Option<&'static (dyn IMenuHint + Sync)>,
+	pub usage: String,
+	pub invoke: OwnedInvoke,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn OwnedAction<F: FnMut(&str, &mut dyn IWrite) -> R + Send + 'static, R: IActionResult>(name: String, invoke: F) -> OwnedAction {
+	OwnedAction::new(name, invoke)
+}
+
+impl OwnedAction {
+	#[inline]
+	pub fn new<F: FnMut(&str, &mut dyn IWrite) -> R + Send + 'static, R: IActionResult>(name: String, mut invoke: F) -> OwnedAction {
+		let invoke: OwnedInvoke = Box::new(move |args, writer| invoke(args, writer).into_result());
+		OwnedAction { name, desc: String::new(), label: String::new(), id: None, menu_hint: None, usage: String::new(), invoke }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: String) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: String) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'static (dyn IMenuHint + Sync)) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the usage of the arguments, see [`Action::with_usage`].
+	///
+	/// ```
+	/// let mut plugins = cvar::DynamicList::new();
+	/// let kick = cvar::OwnedAction(String::from("kick"), |args, _writer| !args.is_empty())
+	/// 	.with_usage(String::from("<player>"));
+	/// plugins.insert("kick", Box::new(kick));
+	///
+	/// let mut writer = String::new();
+	/// cvar::console::invoke(&mut plugins, "kick", "", &mut writer);
+	/// assert_eq!(writer, "usage: kick <player>\n");
+	/// ```
+	#[inline]
+	pub fn with_usage(self, usage: String) -> Self {
+		Self { usage, ..self }
+	}
+}
+
+impl fmt::Debug for OwnedAction {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("OwnedAction")
+			.field("name", &self.name)
+			.field("desc", &self.desc)
+			.field("label", &self.label)
+			.field("id", &self.id)
+			.field("usage", &self.usage)
+			.finish_non_exhaustive()
+	}
+}
+
+impl INode for OwnedAction {
+	fn name(&self) -> &str {
+		&self.name
+	}
+
+	fn description(&self) -> &str {
+		&self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { &self.name } else { &self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint.map(|hint| hint as &dyn IMenuHint)
+	}
+
 	fn as_node(&mut self) -> Node<'_> {
 		Node::Action(self)
 	}
@@ -959,8 +3571,12 @@ impl<'a, F: FnMut(&str, &mut dyn IWrite)> INode for Action<'a, F> {
 	}
 }
 
-impl<'a, F: FnMut(&str, &mut dyn IWrite)> IAction for Action<'a, F> {
-	fn invoke(&mut self, args: &str, writer: &mut dyn IWrite) {
+impl IAction for OwnedAction {
+	fn invoke(&mut self, args: &str, writer: &mut dyn IWrite) -> BoxResult {
 		(self.invoke)(args, writer)
 	}
+
+	fn usage(&self) -> &str {
+		&self.usage
+	}
 }
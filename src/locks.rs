@@ -0,0 +1,338 @@
+use super::*;
+
+/// Temporary write locks on subtrees.
+///
+/// The host locks a subtree while it must not change, eg. while a worker thread reads a snapshot of it.
+/// Wrap the tree in [`Locked`] to enforce the locks.
+///
+/// Sets against a locked property fail with `temporarily locked`, or when queueing is enabled they are queued and applied by [`apply_pending`](Self::apply_pending) once the subtree is unlocked.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Locks {
+	locked: Vec<String>,
+	queue: bool,
+	pending: Vec<(String, String)>,
+}
+
+impl Locks {
+	/// Creates a new set of locks without any locked subtrees.
+	#[inline]
+	pub const fn new() -> Locks {
+		Locks { locked: Vec::new(), queue: false, pending: Vec::new() }
+	}
+
+	/// Queues sets against locked properties instead of failing them.
+	#[inline]
+	pub fn with_queue(self, queue: bool) -> Self {
+		Locks { queue, ..self }
+	}
+
+	/// Locks the subtree at the path.
+	pub fn lock(&mut self, path: &str) {
+		if !self.locked.iter().any(|locked| locked == path) {
+			self.locked.push(path.to_string());
+		}
+	}
+
+	/// Unlocks the subtree at the path.
+	///
+	/// Queued sets are not applied until [`apply_pending`](Self::apply_pending) is called.
+	pub fn unlock(&mut self, path: &str) {
+		self.locked.retain(|locked| locked != path);
+	}
+
+	/// Returns `true` if the path is in a locked subtree.
+	pub fn is_locked(&self, path: &str) -> bool {
+		self.locked.iter().any(|locked| {
+			path.strip_prefix(locked.as_str()).is_some_and(|tail| tail.is_empty() || tail.starts_with('.') || locked.is_empty())
+		})
+	}
+
+	/// Returns the queued sets as the path and the value.
+	#[inline]
+	pub fn pending(&self) -> &[(String, String)] {
+		&self.pending
+	}
+
+	/// Applies the queued sets which are no longer locked.
+	///
+	/// Errors are written to the writer. Returns `false` if any property failed to set.
+	pub fn apply_pending(&mut self, root: &mut dyn IVisit, writer: &mut dyn IWrite) -> bool {
+		let mut result = true;
+		let pending = std::mem::take(&mut self.pending);
+		for (path, val) in pending {
+			if self.is_locked(&path) {
+				self.pending.push((path, val));
+			}
+			else {
+				result &= console::set(root, &path, &val, writer);
+			}
+		}
+		result
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Node visitor which enforces the [`Locks`].
+///
+/// ```
+/// let mut quality = 2;
+/// let mut render = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("quality", &mut quality, &2));
+/// });
+///
+/// let mut locks = cvar::Locks::new();
+/// locks.lock("render");
+///
+/// let mut writer = String::new();
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("render", &mut render));
+/// });
+/// assert!(!cvar::console::set(&mut cvar::Locked(&mut root, &mut locks), "render.quality", "3", &mut writer));
+/// assert_eq!(writer, "error: render.quality \"3\": temporarily locked\n");
+///
+/// let mut locks = locks.with_queue(true);
+/// assert!(cvar::console::set(&mut cvar::Locked(&mut root, &mut locks), "render.quality", "3", &mut cvar::NullWriter));
+/// assert_eq!(cvar::console::get(&mut root, "render.quality").as_deref(), Some("2"));
+///
+/// locks.unlock("render");
+/// assert!(locks.apply_pending(&mut root, &mut cvar::NullWriter));
+/// assert_eq!(cvar::console::get(&mut root, "render.quality").as_deref(), Some("3"));
+/// ```
+pub struct Locked<'a, 'x> {
+	root: &'x mut dyn IVisit,
+	locks: &'a mut Locks,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn Locked<'a, 'x>(root: &'x mut dyn IVisit, locks: &'a mut Locks) -> Locked<'a, 'x> {
+	Locked { root, locks }
+}
+
+impl<'a, 'x> Locked<'a, 'x> {
+	#[inline]
+	pub fn new(root: &'x mut dyn IVisit, locks: &'a mut Locks) -> Locked<'a, 'x> {
+		Locked { root, locks }
+	}
+}
+
+impl<'a, 'x> fmt::Debug for Locked<'a, 'x> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Locked")
+			.field("locks", &self.locks)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a, 'x> IVisit for Locked<'a, 'x> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_locked(self.root, &mut String::new(), self.locks, f);
+	}
+}
+
+fn visit_locked(root: &mut dyn IVisit, path: &mut String, locks: &mut Locks, f: &mut dyn FnMut(&mut dyn INode)) {
+	root.visit(&mut |node| {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(node.name());
+		match node.as_node() {
+			Node::Prop(prop) => f(&mut LockedProp { prop, path, locks: &mut *locks }),
+			Node::List(list) => f(&mut LockedList { list, path, locks: &mut *locks }),
+			Node::Action(_) => f(node),
+		}
+		path.truncate(len);
+	});
+}
+
+struct LockedProp<'a> {
+	prop: &'a mut dyn IProperty,
+	path: &'a str,
+	locks: &'a mut Locks,
+}
+
+impl<'a> LockedProp<'a> {
+	// Returns the result if the property is locked, the value is queued or the error is written
+	fn check(&mut self, val: &dyn fmt::Display, writer: &mut dyn IWrite) -> Option<bool> {
+		if !self.locks.is_locked(self.path) {
+			return None;
+		}
+		if self.locks.queue {
+			self.locks.pending.push((self.path.to_string(), val.to_string()));
+			let _ = writer.write_str("queued until unlocked");
+			Some(true)
+		}
+		else {
+			let _ = writer.write_str("temporarily locked");
+			Some(false)
+		}
+	}
+}
+
+impl<'a> INode for LockedProp<'a> {
+	fn name(&self) -> &str {
+		self.prop.name()
+	}
+
+	fn description(&self) -> &str {
+		self.prop.description()
+	}
+
+	fn label(&self) -> &str {
+		self.prop.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.prop.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.prop.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IProperty for LockedProp<'a> {
+	fn get_value(&self) -> &dyn IValue {
+		self.prop.get_value()
+	}
+
+	fn persist_value(&self) -> &dyn IValue {
+		self.prop.persist_value()
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		match self.check(&val, writer) {
+			Some(result) => result,
+			None => self.prop.set_value(val, writer),
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match self.check(&val, writer) {
+			Some(result) => result,
+			None => self.prop.set(val, writer),
+		}
+	}
+
+	fn set_layer(&mut self, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+		match self.check(&val, writer) {
+			Some(result) => result,
+			None => self.prop.set_layer(layer, val, writer),
+		}
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.validate(val, writer)
+	}
+
+	fn reset(&mut self) {
+		if !self.locks.is_locked(self.path) {
+			self.prop.reset();
+		}
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.prop.default_value()
+	}
+
+	fn set_default(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		// Queued values are replayed with set, defaults are refused while locked
+		if self.locks.is_locked(self.path) {
+			let _ = writer.write_str("temporarily locked");
+			return false;
+		}
+		self.prop.set_default(val, writer)
+	}
+
+	fn state(&self) -> PropState {
+		self.prop.state()
+	}
+
+	fn flags(&self) -> u32 {
+		self.prop.flags()
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.prop.epsilon()
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.prop.min_value()
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.prop.max_value()
+	}
+
+	fn provenance(&self) -> Layer {
+		self.prop.provenance()
+	}
+
+	#[cfg(feature = "type_name")]
+	fn type_name(&self) -> &str {
+		self.prop.type_name()
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		self.prop.values()
+	}
+}
+
+struct LockedList<'a> {
+	list: &'a mut dyn IList,
+	path: &'a str,
+	locks: &'a mut Locks,
+}
+
+impl<'a> INode for LockedList<'a> {
+	fn name(&self) -> &str {
+		self.list.name()
+	}
+
+	fn description(&self) -> &str {
+		self.list.description()
+	}
+
+	fn label(&self) -> &str {
+		self.list.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.list.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.list.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IList for LockedList<'a> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a> IVisit for LockedList<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let mut path = self.path.to_string();
+		visit_locked(self.list.as_ivisit(), &mut path, self.locks, f);
+	}
+}
@@ -0,0 +1,124 @@
+use super::*;
+
+/// How a node is laid out in a menu.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum MenuRow {
+	/// The frontend decides.
+	#[default]
+	Auto,
+	/// The node is shown on its own row with its label.
+	Labeled,
+	/// The node is shown without its label, eg. a button.
+	Inline,
+	/// The children of a list node are shown in a collapsible section.
+	Collapsible,
+}
+
+/// Widget preferred to edit a property.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum MenuWidget {
+	/// The frontend decides, typically based on the type of the value.
+	#[default]
+	Auto,
+	Checkbox,
+	/// Slider between the [lower](IProperty::min_value) and [upper](IProperty::max_value) bounds.
+	Slider,
+	/// Numeric field changed by dragging.
+	Drag,
+	TextInput,
+	/// Selection from the [valid values](IProperty::values).
+	Dropdown,
+	ColorPicker,
+}
+
+/// Presentation hints for menus.
+///
+/// Debug menus and settings screens (egui, imgui, web, ...) consult the hints of a node through [`INode::menu_hint`] so presentation is defined once on the node instead of per frontend.
+/// All hints are optional, frontends are free to ignore them.
+pub trait IMenuHint {
+	/// Returns how the node is laid out.
+	fn row(&self) -> MenuRow {
+		MenuRow::Auto
+	}
+
+	/// Returns the widget preferred to edit the property.
+	fn widget(&self) -> MenuWidget {
+		MenuWidget::Auto
+	}
+
+	/// Returns the sort weight, nodes with a lower weight are shown first.
+	///
+	/// Nodes with the same weight keep their visitation order.
+	fn weight(&self) -> i32 {
+		0
+	}
+
+	/// Returns the name of the group the node is shown in, empty for no group.
+	fn group(&self) -> &str {
+		""
+	}
+}
+
+impl fmt::Debug for dyn IMenuHint + '_ {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("IMenuHint")
+			.field("row", &self.row())
+			.field("widget", &self.widget())
+			.field("weight", &self.weight())
+			.field("group", &self.group())
+			.finish()
+	}
+}
+
+/// Presentation hints for menus.
+///
+/// ```
+/// const VOLUME_HINT: cvar::MenuHint = cvar::MenuHint {
+/// 	widget: cvar::MenuWidget::Slider,
+/// 	group: "Audio",
+/// 	..cvar::MenuHint::DEFAULT
+/// };
+///
+/// let mut volume = 1.0;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0).with_menu_hint(&VOLUME_HINT));
+/// });
+///
+/// let mut widgets = Vec::new();
+/// cvar::console::walk(&mut root, |path, node| {
+/// 	if let Some(hint) = node.menu_hint() {
+/// 		widgets.push((path.to_string(), hint.widget(), hint.group().to_string()));
+/// 	}
+/// });
+/// assert_eq!(widgets, [(String::from("volume"), cvar::MenuWidget::Slider, String::from("Audio"))]);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct MenuHint<'a> {
+	pub row: MenuRow,
+	pub widget: MenuWidget,
+	pub weight: i32,
+	pub group: &'a str,
+}
+
+impl MenuHint<'_> {
+	/// Hints which leave every decision to the frontend.
+	pub const DEFAULT: MenuHint<'static> = MenuHint { row: MenuRow::Auto, widget: MenuWidget::Auto, weight: 0, group: "" };
+}
+
+impl IMenuHint for MenuHint<'_> {
+	fn row(&self) -> MenuRow {
+		self.row
+	}
+
+	fn widget(&self) -> MenuWidget {
+		self.widget
+	}
+
+	fn weight(&self) -> i32 {
+		self.weight
+	}
+
+	fn group(&self) -> &str {
+		self.group
+	}
+}
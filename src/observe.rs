@@ -0,0 +1,368 @@
+use super::*;
+
+/// Operation which changed a property.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ChangeSource {
+	/// The value was set, see [`IProperty::set`] and [`IProperty::set_value`].
+	Set,
+	/// The value was reset to its default, see [`IProperty::reset`].
+	Reset,
+}
+
+/// Change observer.
+///
+/// Used by the [`Observe`] visitor to report changed properties.
+pub trait IObserver {
+	/// Called after the value of the property at the path has changed.
+	///
+	/// The values are formatted as strings, properties which are set to their current value are not reported.
+	fn changed(&mut self, path: &str, old: &str, new: &str, source: ChangeSource);
+}
+
+impl<F: FnMut(&str, &str, &str, ChangeSource)> IObserver for F {
+	#[inline]
+	fn changed(&mut self, path: &str, old: &str, new: &str, source: ChangeSource) {
+		self(path, old, new, source)
+	}
+}
+
+impl fmt::Debug for dyn IObserver + '_ {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("IObserver").finish_non_exhaustive()
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Change observer which coalesces bursts of changes.
+///
+/// Collects the changes and reports a single change per path when flushed, from the value before the first change to the final value.
+/// Paths which end up at their original value are not reported.
+/// Flush once per frame or when a time window elapses to prevent observer storms when sliders are dragged or config files are applied.
+///
+/// ```
+/// let mut volume = 1.0;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0));
+/// });
+///
+/// let mut changes = Vec::new();
+/// let mut coalesce = cvar::Coalesce::new(|path: &str, old: &str, new: &str, _source| changes.push(format!("{path}: {old} -> {new}")));
+/// for val in ["0.9", "0.8", "0.7"] {
+/// 	cvar::console::set_silent(&mut cvar::Observe(&mut root, &mut coalesce), "volume", val);
+/// }
+/// coalesce.flush();
+/// # drop(coalesce);
+///
+/// assert_eq!(changes, ["volume: 1 -> 0.7"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Coalesce<O> {
+	inner: O,
+	pending: Vec<(String, String, String, ChangeSource)>,
+}
+
+impl<O: IObserver> Coalesce<O> {
+	/// Creates a coalescing observer which reports to the inner observer.
+	#[inline]
+	pub const fn new(inner: O) -> Coalesce<O> {
+		Coalesce { inner, pending: Vec::new() }
+	}
+
+	/// Returns the number of paths with pending changes.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.pending.len()
+	}
+
+	/// Returns `true` if there are no pending changes.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.pending.is_empty()
+	}
+
+	/// Reports the pending changes to the inner observer in the order the paths first changed.
+	///
+	/// The source is the source of the last change.
+	pub fn flush(&mut self) {
+		for (path, old, new, source) in self.pending.drain(..) {
+			if old != new {
+				self.inner.changed(&path, &old, &new, source);
+			}
+		}
+	}
+
+	/// Returns the inner observer.
+	#[inline]
+	pub fn inner(&self) -> &O {
+		&self.inner
+	}
+
+	/// Returns the inner observer, discarding the pending changes.
+	#[inline]
+	pub fn into_inner(self) -> O {
+		self.inner
+	}
+}
+
+impl<O: IObserver> IObserver for Coalesce<O> {
+	fn changed(&mut self, path: &str, old: &str, new: &str, source: ChangeSource) {
+		match self.pending.iter_mut().find(|(pending, ..)| pending == path) {
+			Some((_, _, pending_new, pending_source)) => {
+				pending_new.clear();
+				pending_new.push_str(new);
+				*pending_source = source;
+			},
+			None => self.pending.push((path.to_string(), old.to_string(), new.to_string(), source)),
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Node visitor which reports changed properties.
+///
+/// Wraps a visitor and notifies the observer whenever one of its properties is set or reset.
+///
+/// ```
+/// let mut volume = 1.0;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0));
+/// });
+///
+/// let mut changes = Vec::new();
+/// let mut observer = |path: &str, old: &str, new: &str, source| changes.push(format!("{path}: {old} -> {new} ({source:?})"));
+/// let mut observed = cvar::Observe(&mut root, &mut observer);
+/// cvar::console::set_silent(&mut observed, "volume", "0.5");
+/// cvar::console::reset_all(&mut observed);
+///
+/// assert_eq!(changes, ["volume: 1 -> 0.5 (Set)", "volume: 0.5 -> 1 (Reset)"]);
+/// ```
+pub struct Observe<'a, 'x> {
+	root: &'x mut dyn IVisit,
+	observer: &'a mut dyn IObserver,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn Observe<'a, 'x>(root: &'x mut dyn IVisit, observer: &'a mut dyn IObserver) -> Observe<'a, 'x> {
+	Observe { root, observer }
+}
+
+impl<'a, 'x> Observe<'a, 'x> {
+	#[inline]
+	pub fn new(root: &'x mut dyn IVisit, observer: &'a mut dyn IObserver) -> Observe<'a, 'x> {
+		Observe { root, observer }
+	}
+}
+
+impl<'a, 'x> fmt::Debug for Observe<'a, 'x> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Observe").finish_non_exhaustive()
+	}
+}
+
+impl<'a, 'x> IVisit for Observe<'a, 'x> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_observed(self.root, &mut String::new(), self.observer, f);
+	}
+}
+
+fn visit_observed(root: &mut dyn IVisit, path: &mut String, observer: &mut dyn IObserver, f: &mut dyn FnMut(&mut dyn INode)) {
+	root.visit(&mut |node| {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(node.name());
+		match node.as_node() {
+			Node::Prop(prop) => f(&mut ObserveProp { prop, path, observer: &mut *observer }),
+			Node::List(list) => f(&mut ObserveList { list, path, observer: &mut *observer }),
+			Node::Action(_) => f(node),
+		}
+		path.truncate(len);
+	});
+}
+
+struct ObserveProp<'a> {
+	prop: &'a mut dyn IProperty,
+	path: &'a str,
+	observer: &'a mut dyn IObserver,
+}
+
+impl<'a> ObserveProp<'a> {
+	// Reports the change if the value differs from the old value
+	fn notify(&mut self, old: String, source: ChangeSource) {
+		let new = self.prop.get_value().to_string();
+		if old != new {
+			self.observer.changed(self.path, &old, &new, source);
+		}
+	}
+}
+
+impl<'a> INode for ObserveProp<'a> {
+	fn name(&self) -> &str {
+		self.prop.name()
+	}
+
+	fn description(&self) -> &str {
+		self.prop.description()
+	}
+
+	fn label(&self) -> &str {
+		self.prop.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.prop.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.prop.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IProperty for ObserveProp<'a> {
+	fn get_value(&self) -> &dyn IValue {
+		self.prop.get_value()
+	}
+
+	fn persist_value(&self) -> &dyn IValue {
+		self.prop.persist_value()
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		let old = self.prop.get_value().to_string();
+		let success = self.prop.set_value(val, writer);
+		if success {
+			self.notify(old, ChangeSource::Set);
+		}
+		success
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		let old = self.prop.get_value().to_string();
+		let success = self.prop.set(val, writer);
+		if success {
+			self.notify(old, ChangeSource::Set);
+		}
+		success
+	}
+
+	fn set_layer(&mut self, layer: Layer, val: &str, writer: &mut dyn IWrite) -> bool {
+		let old = self.prop.get_value().to_string();
+		let success = self.prop.set_layer(layer, val, writer);
+		if success {
+			self.notify(old, ChangeSource::Set);
+		}
+		success
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.validate(val, writer)
+	}
+
+	fn reset(&mut self) {
+		let old = self.prop.get_value().to_string();
+		self.prop.reset();
+		self.notify(old, ChangeSource::Reset);
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.prop.default_value()
+	}
+
+	fn set_default(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.prop.set_default(val, writer)
+	}
+
+	fn state(&self) -> PropState {
+		self.prop.state()
+	}
+
+	fn flags(&self) -> u32 {
+		self.prop.flags()
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.prop.epsilon()
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.prop.min_value()
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.prop.max_value()
+	}
+
+	fn provenance(&self) -> Layer {
+		self.prop.provenance()
+	}
+
+	#[cfg(feature = "type_name")]
+	fn type_name(&self) -> &str {
+		self.prop.type_name()
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		self.prop.values()
+	}
+}
+
+struct ObserveList<'a> {
+	list: &'a mut dyn IList,
+	path: &'a str,
+	observer: &'a mut dyn IObserver,
+}
+
+impl<'a> INode for ObserveList<'a> {
+	fn name(&self) -> &str {
+		self.list.name()
+	}
+
+	fn description(&self) -> &str {
+		self.list.description()
+	}
+
+	fn label(&self) -> &str {
+		self.list.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.list.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.list.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IList for ObserveList<'a> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a> IVisit for ObserveList<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let mut path = self.path.to_string();
+		visit_observed(self.list.as_ivisit(), &mut path, self.observer, f);
+	}
+}
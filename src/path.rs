@@ -0,0 +1,230 @@
+use std::path::{Path, PathBuf};
+use super::*;
+
+/// Kind of filesystem entry required by [`PathProp`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum PathKind {
+	/// Any kind, or a path which does not exist.
+	#[default]
+	Any,
+	/// An existing file.
+	File,
+	/// An existing directory.
+	Dir,
+}
+
+/// Path property node with validation.
+///
+/// Optionally checks the path when it is set: it must exist, be a file or a directory, or be writable.
+/// The checks are done against the filesystem at the time of the set, the default value is not checked.
+///
+/// ```
+/// let mut logs = std::env::temp_dir();
+/// let default = logs.clone();
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::PathProp("logs", &mut logs, &default).with_kind(cvar::PathKind::Dir));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut root, "logs", "does/not/exist", &mut writer));
+/// assert_eq!(writer, "error: logs \"does/not/exist\": path does not exist\n");
+/// ```
+pub struct PathProp<'a, 'x> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	variable: &'x mut PathBuf,
+	default: &'a PathBuf,
+	exists: bool,
+	kind: PathKind,
+	writable: bool,
+	// The displayed value and default value
+	display: String,
+	default_display: String,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn PathProp<'a, 'x>(name: &'a str, variable: &'x mut PathBuf, default: &'a PathBuf) -> PathProp<'a, 'x> {
+	PathProp::new(name, variable, default)
+}
+
+impl<'a, 'x> PathProp<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut PathBuf, default: &'a PathBuf) -> PathProp<'a, 'x> {
+		let display = variable.display().to_string();
+		let default_display = default.display().to_string();
+		PathProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default, exists: false, kind: PathKind::Any, writable: false, display, default_display }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+
+	/// Requires the path to exist.
+	#[inline]
+	pub fn with_exists(self, exists: bool) -> Self {
+		Self { exists, ..self }
+	}
+
+	/// Requires the path to be a file or a directory, which implies it exists.
+	#[inline]
+	pub fn with_kind(self, kind: PathKind) -> Self {
+		Self { kind, ..self }
+	}
+
+	/// Requires the path to be writable.
+	///
+	/// An existing path must not be read-only, a path which does not exist must be in a directory which is not read-only.
+	#[inline]
+	pub fn with_writable(self, writable: bool) -> Self {
+		Self { writable, ..self }
+	}
+
+	// Writes the reason the path is rejected
+	fn check_path(&self, path: &Path, writer: &mut dyn IWrite) -> bool {
+		let metadata = path.metadata().ok();
+		let error = match (&metadata, self.kind) {
+			(None, PathKind::File | PathKind::Dir) => Some("path does not exist"),
+			(None, PathKind::Any) if self.exists => Some("path does not exist"),
+			(Some(metadata), PathKind::File) if !metadata.is_file() => Some("path is not a file"),
+			(Some(metadata), PathKind::Dir) if !metadata.is_dir() => Some("path is not a directory"),
+			_ => None,
+		};
+		let error = error.or_else(|| {
+			if !self.writable {
+				return None;
+			}
+			let readonly = match metadata {
+				Some(metadata) => metadata.permissions().readonly(),
+				None => {
+					let parent = path.parent().filter(|parent| !parent.as_os_str().is_empty()).unwrap_or(Path::new("."));
+					parent.metadata().map_or(true, |metadata| !metadata.is_dir() || metadata.permissions().readonly())
+				},
+			};
+			if readonly { Some("path is not writable") } else { None }
+		});
+		match error {
+			Some(error) => {
+				let _ = writer.write_str(error);
+				false
+			},
+			None => true,
+		}
+	}
+
+	fn store(&mut self, path: PathBuf) {
+		self.display = path.display().to_string();
+		*self.variable = path;
+	}
+}
+
+impl<'a, 'x> INode for PathProp<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IProperty for PathProp<'a, 'x> {
+	fn get_value(&self) -> &dyn IValue {
+		&self.display
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		// PathBuf does not implement Display, paths are set as strings
+		if let Some(val) = val.downcast_ref::<String>() {
+			self.set(val, writer)
+		}
+		else {
+			let _ = write_mismatched_types::<String>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		let path = PathBuf::from(val);
+		if !self.check_path(&path, writer) {
+			return false;
+		}
+		self.store(path);
+		true
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.check_path(Path::new(val), writer)
+	}
+
+	fn reset(&mut self) {
+		self.store(self.default.clone());
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		&self.default_display
+	}
+
+	fn state(&self) -> PropState {
+		match *self.variable == *self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+}
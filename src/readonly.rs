@@ -0,0 +1,185 @@
+use super::*;
+
+/// Node visitor which only allows browsing.
+///
+/// Wraps a visitor and exposes every property as read-only, actions are hidden.
+/// Hand this view to code which should be able to inspect but not change the configuration.
+///
+/// ```
+/// let mut volume = 0.5;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("volume", &mut volume, &1.0));
+/// 	f(&mut cvar::Action("quit", |_args, _writer| ()));
+/// });
+///
+/// let mut view = cvar::ReadOnly(&mut root);
+/// let mut writer = String::new();
+/// assert_eq!(cvar::console::get(&mut view, "volume").as_deref(), Some("0.5"));
+/// assert!(!cvar::console::set(&mut view, "volume", "1.0", &mut writer));
+/// assert!(!cvar::console::invoke(&mut view, "quit", "", &mut writer));
+/// assert_eq!(writer, "error: volume \"1.0\": cannot set read-only property\n");
+/// ```
+#[derive(Debug)]
+pub struct ReadOnly<'a>(pub &'a mut dyn IVisit);
+
+impl<'a> IVisit for ReadOnly<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		let Self(this) = self;
+		visit_readonly(*this, f);
+	}
+}
+
+fn visit_readonly(root: &mut dyn IVisit, f: &mut dyn FnMut(&mut dyn INode)) {
+	root.visit(&mut |node| {
+		match node.as_node() {
+			Node::Prop(prop) => f(&mut ReadOnlyView(prop)),
+			Node::List(list) => f(&mut ReadOnlyList(list)),
+			Node::Action(_) => {},
+		}
+	});
+}
+
+struct ReadOnlyView<'a>(&'a mut dyn IProperty);
+
+impl<'a> INode for ReadOnlyView<'a> {
+	fn name(&self) -> &str {
+		self.0.name()
+	}
+
+	fn description(&self) -> &str {
+		self.0.description()
+	}
+
+	fn label(&self) -> &str {
+		self.0.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.0.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.0.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IProperty for ReadOnlyView<'a> {
+	fn get_value(&self) -> &dyn IValue {
+		self.0.get_value()
+	}
+
+	fn persist_value(&self) -> &dyn IValue {
+		self.0.persist_value()
+	}
+
+	fn set_value(&mut self, _val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		let _ = writer.write_str("cannot set read-only property");
+		false
+	}
+
+	fn set(&mut self, _val: &str, writer: &mut dyn IWrite) -> bool {
+		let _ = writer.write_str("cannot set read-only property");
+		false
+	}
+
+	fn validate(&self, _val: &str, writer: &mut dyn IWrite) -> bool {
+		let _ = writer.write_str("cannot set read-only property");
+		false
+	}
+
+	fn reset(&mut self) {}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.0.default_value()
+	}
+
+	fn set_default(&mut self, _val: &str, writer: &mut dyn IWrite) -> bool {
+		let _ = writer.write_str("cannot set read-only property");
+		false
+	}
+
+	fn state(&self) -> PropState {
+		self.0.state()
+	}
+
+	fn flags(&self) -> u32 {
+		self.0.flags() | flags::READONLY
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.0.epsilon()
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.0.min_value()
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.0.max_value()
+	}
+
+	fn provenance(&self) -> Layer {
+		self.0.provenance()
+	}
+
+	#[cfg(feature = "type_name")]
+	fn type_name(&self) -> &str {
+		self.0.type_name()
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		self.0.values()
+	}
+}
+
+struct ReadOnlyList<'a>(&'a mut dyn IList);
+
+impl<'a> INode for ReadOnlyList<'a> {
+	fn name(&self) -> &str {
+		self.0.name()
+	}
+
+	fn description(&self) -> &str {
+		self.0.description()
+	}
+
+	fn label(&self) -> &str {
+		self.0.label()
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.0.id()
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.0.menu_hint()
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IList for ReadOnlyList<'a> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a> IVisit for ReadOnlyList<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_readonly(self.0.as_ivisit(), f);
+	}
+}
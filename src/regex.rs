@@ -0,0 +1,158 @@
+use ::regex::Regex;
+use super::*;
+
+/// String property node validated by a regular expression.
+///
+/// Rejects values the pattern does not match, anchor the pattern with `^` and `$` to match the whole value.
+/// Compile the pattern once and keep it around, the node only borrows it.
+///
+/// ```
+/// let pattern = regex::Regex::new("^[a-z][a-z0-9_]*$").unwrap();
+/// let mut map = String::from("dust2");
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::RegexProp("map", &mut map, &String::from("dust2"), &pattern));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut root, "map", "../maps/dust2", &mut writer));
+/// assert_eq!(writer, "error: map \"../maps/dust2\": value does not match pattern ^[a-z][a-z0-9_]*$\n");
+/// assert!(cvar::console::set(&mut root, "map", "inferno", &mut writer));
+/// ```
+pub struct RegexProp<'a, 'x> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	variable: &'x mut String,
+	default: &'a String,
+	pattern: &'a Regex,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn RegexProp<'a, 'x>(name: &'a str, variable: &'x mut String, default: &'a String, pattern: &'a Regex) -> RegexProp<'a, 'x> {
+	RegexProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default, pattern }
+}
+
+impl<'a, 'x> RegexProp<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut String, default: &'a String, pattern: &'a Regex) -> RegexProp<'a, 'x> {
+		RegexProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default, pattern }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+}
+
+impl<'a, 'x> INode for RegexProp<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IProperty for RegexProp<'a, 'x> {
+	fn get_value(&self) -> &dyn IValue {
+		&*self.variable
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(val) = val.downcast_ref::<String>() {
+			self.set(val, writer)
+		}
+		else {
+			let _ = write_mismatched_types::<String>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		if !self.validate(val, writer) {
+			return false;
+		}
+		self.variable.clear();
+		self.variable.push_str(val);
+		true
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		if !self.pattern.is_match(val) {
+			let _ = write!(writer, "value does not match pattern {}", self.pattern.as_str());
+			return false;
+		}
+		true
+	}
+
+	fn reset(&mut self) {
+		self.variable.clone_from(self.default);
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.default
+	}
+
+	fn state(&self) -> PropState {
+		match *self.variable == *self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+}
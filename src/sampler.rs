@@ -0,0 +1,169 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use super::*;
+
+/// Records numeric property values over time.
+///
+/// Tracks a set of property paths, added by hand or by the [`METRIC`](flags::METRIC) flag, and keeps their most recent values in fixed size ring buffers, eg. for drawing graphs in a debug overlay.
+///
+/// The sampler does not run on its own, call [`poll`](Self::poll) every frame and it samples the tree when the interval has elapsed.
+/// Values are parsed from the property's string representation, properties which do not format as a number are skipped.
+///
+/// ```
+/// use std::time::{Duration, Instant};
+///
+/// let mut frame_time = 16.6;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("frame_time", &mut frame_time, &0.0));
+/// });
+///
+/// let mut sampler = cvar::Sampler::new(128, Duration::from_millis(100));
+/// sampler.track("frame_time");
+///
+/// let now = Instant::now();
+/// assert!(sampler.poll(&mut root, now));
+/// assert!(!sampler.poll(&mut root, now + Duration::from_millis(50)));
+/// assert!(sampler.poll(&mut root, now + Duration::from_millis(100)));
+///
+/// let samples: Vec<f64> = sampler.samples("frame_time").unwrap().collect();
+/// assert_eq!(samples, [16.6, 16.6]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sampler {
+	series: Vec<Series>,
+	capacity: usize,
+	interval: Duration,
+	last: Option<Instant>,
+}
+
+#[derive(Clone, Debug)]
+struct Series {
+	path: String,
+	values: VecDeque<f64>,
+}
+
+impl Sampler {
+	/// Creates a new sampler keeping `capacity` samples per path, sampling once every `interval`.
+	#[inline]
+	pub fn new(capacity: usize, interval: Duration) -> Sampler {
+		Sampler { series: Vec::new(), capacity, interval, last: None }
+	}
+
+	/// Starts tracking the property at the given path.
+	pub fn track(&mut self, path: &str) {
+		if !self.series.iter().any(|series| series.path == path) {
+			let values = VecDeque::with_capacity(self.capacity);
+			self.series.push(Series { path: path.to_string(), values });
+		}
+	}
+
+	/// Starts tracking every property in the tree with the [`METRIC`](flags::METRIC) flag.
+	///
+	/// Call again after the tree changes shape, paths which are already tracked keep their samples.
+	///
+	/// ```
+	/// use std::time::Duration;
+	///
+	/// let mut frame_time = 16.6;
+	/// let mut fov = 90;
+	/// let mut root = cvar::Visit(|f| {
+	/// 	f(&mut cvar::Property::new("frame_time", &mut frame_time, &0.0).with_flags(cvar::flags::METRIC));
+	/// 	f(&mut cvar::Property("fov", &mut fov, &90));
+	/// });
+	///
+	/// let mut sampler = cvar::Sampler::new(128, Duration::from_millis(100));
+	/// sampler.track_flagged(&mut root);
+	/// assert_eq!(sampler.paths().collect::<Vec<_>>(), ["frame_time"]);
+	/// ```
+	pub fn track_flagged(&mut self, root: &mut dyn IVisit) {
+		console::walk(root, |path, node| {
+			if let Node::Prop(prop) = node.as_node() {
+				if prop.flags() & flags::METRIC != 0 {
+					self.track(path);
+				}
+			}
+		});
+	}
+
+	/// Stops tracking the property at the given path and discards its samples.
+	pub fn untrack(&mut self, path: &str) {
+		self.series.retain(|series| series.path != path);
+	}
+
+	/// Returns an iterator over the tracked paths.
+	#[inline]
+	pub fn paths(&self) -> impl Iterator<Item = &str> {
+		self.series.iter().map(|series| series.path.as_str())
+	}
+
+	/// Samples the tree if the interval has elapsed since the last sample.
+	///
+	/// Returns `true` if the tree was sampled.
+	pub fn poll(&mut self, root: &mut dyn IVisit, now: Instant) -> bool {
+		if let Some(last) = self.last {
+			if now.saturating_duration_since(last) < self.interval {
+				return false;
+			}
+		}
+		self.last = Some(now);
+		self.sample(root);
+		true
+	}
+
+	/// Samples the tree right now.
+	pub fn sample(&mut self, root: &mut dyn IVisit) {
+		let capacity = self.capacity;
+		for series in &mut self.series {
+			let mut value = None;
+			console::find(root, &series.path, |node| {
+				if let Node::Prop(prop) = node.as_node() {
+					value = prop.get_value().to_string().parse::<f64>().ok();
+				}
+			});
+			if let Some(value) = value.filter(|_| capacity > 0) {
+				if series.values.len() >= capacity {
+					series.values.pop_front();
+				}
+				series.values.push_back(value);
+			}
+		}
+	}
+
+	/// Returns the recorded samples for the path, oldest first.
+	///
+	/// Returns `None` if the path is not tracked.
+	pub fn samples(&self, path: &str) -> Option<impl Iterator<Item = f64> + '_> {
+		let series = self.series.iter().find(|series| series.path == path)?;
+		Some(series.values.iter().copied())
+	}
+
+	/// Returns the most recent sample for the path.
+	pub fn latest(&self, path: &str) -> Option<f64> {
+		let series = self.series.iter().find(|series| series.path == path)?;
+		series.values.back().copied()
+	}
+
+	/// Discards all recorded samples.
+	pub fn clear(&mut self) {
+		for series in &mut self.series {
+			series.values.clear();
+		}
+		self.last = None;
+	}
+
+	/// Writes the latest samples in the Prometheus text exposition format.
+	///
+	/// Every tracked path is exported as a gauge, the `.` separators in the path are replaced with `_`.
+	pub fn write_prometheus(&self, prefix: &str, writer: &mut dyn IWrite) -> fmt::Result {
+		for series in &self.series {
+			if let Some(value) = series.values.back() {
+				let name: String = series.path.chars()
+					.map(|chr| if chr.is_ascii_alphanumeric() { chr } else { '_' })
+					.collect();
+				writeln!(writer, "# TYPE {prefix}{name} gauge")?;
+				writeln!(writer, "{prefix}{name} {value}")?;
+			}
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,151 @@
+use super::*;
+
+/// Secret string property node.
+///
+/// Setting the property works as usual but its value and default value are displayed as `****`, so secrets such as API tokens are not echoed into logs.
+/// The application reads the secret directly from the variable.
+///
+/// The property has the [`SECRET`](flags::SECRET) flag and, because the displayed value cannot be loaded back, the [`NO_PERSIST`](flags::NO_PERSIST) flag.
+///
+/// ```
+/// let mut token = String::new();
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::SecretProp("api_token", &mut token, &String::new()));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "api_token", "hunter2"));
+/// assert_eq!(cvar::console::get(&mut root, "api_token").as_deref(), Some("****"));
+///
+/// let mut writer = String::new();
+/// cvar::console::print(&mut root, "", &mut writer);
+/// assert_eq!(writer, "api_token is \"****\"\n");
+/// # drop(root);
+/// assert_eq!(token, "hunter2");
+/// ```
+pub struct SecretProp<'a, 'x> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	variable: &'x mut String,
+	default: &'a String,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn SecretProp<'a, 'x>(name: &'a str, variable: &'x mut String, default: &'a String) -> SecretProp<'a, 'x> {
+	SecretProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default }
+}
+
+impl<'a, 'x> SecretProp<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut String, default: &'a String) -> SecretProp<'a, 'x> {
+		SecretProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+}
+
+impl<'a, 'x> INode for SecretProp<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IProperty for SecretProp<'a, 'x> {
+	fn get_value(&self) -> &dyn IValue {
+		&SECRET_MASK
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(val) = val.downcast_ref::<String>() {
+			self.variable.clone_from(val);
+			true
+		}
+		else {
+			let _ = write_mismatched_types::<String>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, _writer: &mut dyn IWrite) -> bool {
+		self.variable.clear();
+		self.variable.push_str(val);
+		true
+	}
+
+	fn reset(&mut self) {
+		self.variable.clone_from(self.default);
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		&SECRET_MASK
+	}
+
+	fn state(&self) -> PropState {
+		match *self.variable == *self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags | flags::SECRET | flags::NO_PERSIST
+	}
+}
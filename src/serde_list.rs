@@ -0,0 +1,342 @@
+use serde_json::Value;
+use super::*;
+
+/// List node exposing a serializable struct.
+///
+/// Quick integration of existing configuration structs without implementing [`IVisit`] for them.
+/// The value is round-tripped through serde's data model:
+/// struct fields become properties and nested structs become lists.
+///
+/// Setting a property deserializes the modified value back into the struct, if this fails the struct is left unchanged.
+///
+/// ```
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Audio {
+/// 	volume: f32,
+/// 	device: String,
+/// }
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+/// 	audio: Audio,
+/// 	fullscreen: bool,
+/// }
+///
+/// let mut config = Config { audio: Audio { volume: 0.5, device: String::new() }, fullscreen: false };
+/// let default = Config { audio: Audio { volume: 1.0, device: String::new() }, fullscreen: false };
+///
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::SerdeList("config", &mut config, &default));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(cvar::console::set(&mut root, "config.audio.device", "Speakers", &mut writer));
+/// assert!(cvar::console::set(&mut root, "config.fullscreen", "true", &mut writer));
+/// assert!(!cvar::console::set(&mut root, "config.audio.volume", "loud", &mut writer));
+/// assert_eq!(cvar::console::get(&mut root, "config.audio.volume").as_deref(), Some("0.5"));
+/// # drop(root);
+/// # assert_eq!(config.audio.device, "Speakers");
+/// # assert!(config.fullscreen);
+/// ```
+pub struct SerdeList<'a, 'x, T> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	ctx: Context<'x, T>,
+}
+
+struct Context<'x, T> {
+	value: &'x mut T,
+	json: Value,
+	default_json: Value,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn SerdeList<'a, 'x, T>(name: &'a str, value: &'x mut T, default: &'a T) -> SerdeList<'a, 'x, T>
+	where T: serde::Serialize + serde::de::DeserializeOwned
+{
+	SerdeList::new(name, value, default)
+}
+
+impl<'a, 'x, T> SerdeList<'a, 'x, T>
+	where T: serde::Serialize + serde::de::DeserializeOwned
+{
+	pub fn new(name: &'a str, value: &'x mut T, default: &'a T) -> SerdeList<'a, 'x, T> {
+		let json = serde_json::to_value(&*value).unwrap_or(Value::Null);
+		let default_json = serde_json::to_value(default).unwrap_or(Value::Null);
+		let ctx = Context { value, json, default_json };
+		SerdeList { name, desc: "", label: "", id: None, ctx }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+}
+
+impl<'a, 'x, T> INode for SerdeList<'a, 'x, T>
+	where T: serde::Serialize + serde::de::DeserializeOwned
+{
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::List(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x, T> IList for SerdeList<'a, 'x, T>
+	where T: serde::Serialize + serde::de::DeserializeOwned
+{
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a, 'x, T> IVisit for SerdeList<'a, 'x, T>
+	where T: serde::Serialize + serde::de::DeserializeOwned
+{
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_object(&mut self.ctx, &mut Vec::new(), f);
+	}
+}
+
+//----------------------------------------------------------------
+
+// Type erased access to the context
+trait IContext {
+	fn lookup(&self, path: &[String]) -> (Option<&Value>, Option<&Value>);
+	fn update(&mut self, path: &[String], value: Value, writer: &mut dyn IWrite) -> bool;
+	fn validate(&self, path: &[String], value: Value, writer: &mut dyn IWrite) -> bool;
+}
+
+impl<'x, T> IContext for Context<'x, T>
+	where T: serde::Serialize + serde::de::DeserializeOwned
+{
+	fn lookup(&self, path: &[String]) -> (Option<&Value>, Option<&Value>) {
+		(pointer(&self.json, path), pointer(&self.default_json, path))
+	}
+
+	fn update(&mut self, path: &[String], value: Value, writer: &mut dyn IWrite) -> bool {
+		match self.deserialize(path, value) {
+			Ok(value) => {
+				*self.value = value;
+				// Serialize again in case the struct normalizes its values
+				self.json = serde_json::to_value(&*self.value).unwrap_or(Value::Null);
+				true
+			},
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn validate(&self, path: &[String], value: Value, writer: &mut dyn IWrite) -> bool {
+		match self.deserialize(path, value) {
+			Ok(_) => true,
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+}
+
+impl<'x, T> Context<'x, T>
+	where T: serde::Serialize + serde::de::DeserializeOwned
+{
+	// Deserializes the struct with the value at the path replaced
+	fn deserialize(&self, path: &[String], value: Value) -> Result<T, serde_json::Error> {
+		let mut json = self.json.clone();
+		if let Some(target) = pointer_mut(&mut json, path) {
+			*target = value;
+		}
+		serde_json::from_value::<T>(json)
+	}
+}
+
+fn pointer<'v>(mut value: &'v Value, path: &[String]) -> Option<&'v Value> {
+	for key in path {
+		value = value.as_object()?.get(key)?;
+	}
+	Some(value)
+}
+
+fn pointer_mut<'v>(mut value: &'v mut Value, path: &[String]) -> Option<&'v mut Value> {
+	for key in path {
+		value = value.as_object_mut()?.get_mut(key)?;
+	}
+	Some(value)
+}
+
+fn visit_object(ctx: &mut dyn IContext, path: &mut Vec<String>, f: &mut dyn FnMut(&mut dyn INode)) {
+	// Collect the fields first as the nodes need unique access to the context
+	let fields: Vec<(String, bool)> = match ctx.lookup(path).0 {
+		Some(Value::Object(map)) => map.iter().map(|(key, value)| (key.clone(), value.is_object())).collect(),
+		_ => return,
+	};
+	for (key, is_object) in fields {
+		path.push(key);
+		if is_object {
+			f(&mut SerdeNode { ctx, path, value: String::new(), default: String::new() });
+		}
+		else {
+			let (value, default) = ctx.lookup(path);
+			let value = value.map(to_display).unwrap_or_default();
+			let default = default.map(to_display).unwrap_or_default();
+			f(&mut SerdeNode { ctx, path, value, default });
+		}
+		path.pop();
+	}
+}
+
+fn to_display(value: &Value) -> String {
+	match value {
+		Value::String(s) => s.clone(),
+		_ => value.to_string(),
+	}
+}
+
+fn from_display(current: Option<&Value>, s: &str) -> Result<Value, serde_json::Error> {
+	match current {
+		Some(Value::String(_)) => Ok(Value::String(s.to_string())),
+		Some(Value::Null) => Ok(serde_json::from_str(s).unwrap_or_else(|_| Value::String(s.to_string()))),
+		_ => serde_json::from_str(s),
+	}
+}
+
+//----------------------------------------------------------------
+
+// Ephemeral node for a field of the serialized value
+struct SerdeNode<'a> {
+	ctx: &'a mut dyn IContext,
+	path: &'a mut Vec<String>,
+	value: String,
+	default: String,
+}
+
+impl<'a> INode for SerdeNode<'a> {
+	fn name(&self) -> &str {
+		self.path.last().map(String::as_str).unwrap_or("")
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		match self.ctx.lookup(self.path).0 {
+			Some(Value::Object(_)) => Node::List(self),
+			_ => Node::Prop(self),
+		}
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a> IList for SerdeNode<'a> {
+	fn as_ivisit(&mut self) -> &mut dyn IVisit {
+		self
+	}
+}
+
+impl<'a> IVisit for SerdeNode<'a> {
+	fn visit(&mut self, f: &mut dyn FnMut(&mut dyn INode)) {
+		visit_object(self.ctx, self.path, f);
+	}
+}
+
+impl<'a> IProperty for SerdeNode<'a> {
+	fn get_value(&self) -> &dyn IValue {
+		&self.value
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(val) = val.downcast_ref::<String>() {
+			self.set(val, writer)
+		}
+		else {
+			let _ = write_mismatched_types::<String>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		let value = match from_display(self.ctx.lookup(self.path).0, val) {
+			Ok(value) => value,
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				return false;
+			},
+		};
+		if !self.ctx.update(self.path, value, writer) {
+			return false;
+		}
+		self.value = self.ctx.lookup(self.path).0.map(to_display).unwrap_or_default();
+		true
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match from_display(self.ctx.lookup(self.path).0, val) {
+			Ok(value) => self.ctx.validate(self.path, value, writer),
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn reset(&mut self) {
+		let default = self.default.clone();
+		self.set(&default, &mut NullWriter);
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		&self.default
+	}
+
+	fn state(&self) -> PropState {
+		match self.value == self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn values(&self) -> Option<&[&str]> {
+		match self.ctx.lookup(self.path).0 {
+			Some(Value::Bool(_)) => Some(&["false", "true"]),
+			_ => None,
+		}
+	}
+}
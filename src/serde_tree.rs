@@ -0,0 +1,221 @@
+use std::cell::RefCell;
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess};
+use serde::ser::{Serialize, SerializeMap};
+use super::*;
+
+/// Serializable view of a visitor.
+///
+/// List nodes are serialized as nested maps, properties as map entries named by the property name.
+/// Actions and properties with the [`NO_PERSIST`](flags::NO_PERSIST) flag are not serialized.
+/// Booleans, integers and floats are serialized as their serde types, all other values are serialized as strings.
+///
+/// See [`deserialize_into`] for the inverse operation.
+///
+/// ```
+/// let mut width = 1920;
+/// let mut fullscreen = false;
+/// let mut video = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("width", &mut width, &1920));
+/// 	f(&mut cvar::Property("fullscreen", &mut fullscreen, &false));
+/// });
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::List("video", &mut video));
+/// });
+///
+/// let json = serde_json::to_string(&cvar::SerdeTree::new(&mut root)).unwrap();
+/// assert_eq!(json, r#"{"video":{"width":1920,"fullscreen":false}}"#);
+///
+/// let mut writer = String::new();
+/// let mut deserializer = serde_json::Deserializer::from_str(r#"{"video":{"width":1280,"vsync":true}}"#);
+/// assert_eq!(cvar::deserialize_into(&mut root, &mut deserializer, &mut writer).unwrap(), true);
+/// assert_eq!(writer, "warning: unknown: video.vsync\n");
+/// assert_eq!(cvar::console::get(&mut root, "video.width").as_deref(), Some("1280"));
+/// ```
+pub struct SerdeTree<'a>(RefCell<&'a mut dyn IVisit>);
+
+impl<'a> SerdeTree<'a> {
+	#[inline]
+	pub fn new(root: &'a mut dyn IVisit) -> SerdeTree<'a> {
+		SerdeTree(RefCell::new(root))
+	}
+}
+
+impl<'a> fmt::Debug for SerdeTree<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("SerdeTree").finish_non_exhaustive()
+	}
+}
+
+impl<'a> Serialize for SerdeTree<'a> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut root = self.0.borrow_mut();
+		let mut map = serializer.serialize_map(None)?;
+		let mut result = Ok(());
+		root.visit(&mut |node| {
+			if result.is_err() {
+				return;
+			}
+			result = match node.as_node() {
+				Node::Prop(prop) => {
+					if prop.flags() & flags::NO_PERSIST == 0 {
+						map.serialize_entry(prop.name(), &SerdeValue(prop.persist_value()))
+					}
+					else {
+						Ok(())
+					}
+				},
+				Node::List(list) => {
+					let name = list.name().to_string();
+					map.serialize_entry(&name, &SerdeTree::new(list.as_ivisit()))
+				},
+				Node::Action(_) => Ok(()),
+			};
+		});
+		result?;
+		map.end()
+	}
+}
+
+struct SerdeValue<'a>(&'a dyn IValue);
+
+impl<'a> Serialize for SerdeValue<'a> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let value = self.0;
+		let string = value.to_string();
+		if value.is::<bool>() {
+			if let Ok(value) = string.parse() {
+				return serializer.serialize_bool(value);
+			}
+		}
+		if value.is::<i8>() || value.is::<i16>() || value.is::<i32>() || value.is::<i64>() || value.is::<isize>() {
+			if let Ok(value) = string.parse() {
+				return serializer.serialize_i64(value);
+			}
+		}
+		if value.is::<u8>() || value.is::<u16>() || value.is::<u32>() || value.is::<u64>() || value.is::<usize>() {
+			if let Ok(value) = string.parse() {
+				return serializer.serialize_u64(value);
+			}
+		}
+		if value.is::<f32>() || value.is::<f64>() {
+			if let Ok(value) = string.parse() {
+				return serializer.serialize_f64(value);
+			}
+		}
+		serializer.serialize_str(&string)
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Deserializes a map into the visitor.
+///
+/// Nested maps address the children of list nodes, every other value is set in the [`File`](Layer::File) layer, see [`IProperty::set_layer`].
+/// Unknown keys are reported to the writer as warnings and skipped, invalid values and keys naming actions are reported as errors.
+/// Actions are never invoked.
+///
+/// Returns `Ok(false)` if any property failed to set, see [`SerdeTree`] for an example.
+pub fn deserialize_into<'de, D: serde::Deserializer<'de>>(root: &mut dyn IVisit, deserializer: D, writer: &mut dyn IWrite) -> Result<bool, D::Error> {
+	let mut path = String::new();
+	let mut result = true;
+	deserializer.deserialize_map(MapSeed { root, path: &mut path, writer, result: &mut result })?;
+	Ok(result)
+}
+
+struct MapSeed<'a> {
+	root: &'a mut dyn IVisit,
+	path: &'a mut String,
+	writer: &'a mut dyn IWrite,
+	result: &'a mut bool,
+}
+
+impl<'a, 'de> de::Visitor<'de> for MapSeed<'a> {
+	type Value = ();
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a map")
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+		while let Some(key) = map.next_key::<String>()? {
+			let len = self.path.len();
+			if len > 0 {
+				self.path.push('.');
+			}
+			self.path.push_str(&key);
+			if !console::find(self.root, self.path, |_node| ()) {
+				// warning: unknown: cvar.prop
+				let _ = writeln!(self.writer, "warning: unknown: {}", self.path);
+				map.next_value::<IgnoredAny>()?;
+			}
+			else {
+				map.next_value_seed(EntrySeed {
+					root: &mut *self.root,
+					path: &mut *self.path,
+					writer: &mut *self.writer,
+					result: &mut *self.result,
+				})?;
+			}
+			self.path.truncate(len);
+		}
+		Ok(())
+	}
+}
+
+struct EntrySeed<'a> {
+	root: &'a mut dyn IVisit,
+	path: &'a mut String,
+	writer: &'a mut dyn IWrite,
+	result: &'a mut bool,
+}
+
+impl<'a> EntrySeed<'a> {
+	fn set(self, val: &str) {
+		*self.result &= console::set_loaded(self.root, self.path, Layer::File, val, self.writer);
+	}
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntrySeed<'a> {
+	type Value = ();
+
+	fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+		deserializer.deserialize_any(self)
+	}
+}
+
+impl<'a, 'de> de::Visitor<'de> for EntrySeed<'a> {
+	type Value = ();
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a map or a value")
+	}
+
+	fn visit_bool<E: de::Error>(self, v: bool) -> Result<(), E> {
+		self.set(if v { "true" } else { "false" });
+		Ok(())
+	}
+
+	fn visit_i64<E: de::Error>(self, v: i64) -> Result<(), E> {
+		self.set(&v.to_string());
+		Ok(())
+	}
+
+	fn visit_u64<E: de::Error>(self, v: u64) -> Result<(), E> {
+		self.set(&v.to_string());
+		Ok(())
+	}
+
+	fn visit_f64<E: de::Error>(self, v: f64) -> Result<(), E> {
+		self.set(&v.to_string());
+		Ok(())
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> {
+		self.set(v);
+		Ok(())
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<(), A::Error> {
+		MapSeed { root: self.root, path: self.path, writer: self.writer, result: self.result }.visit_map(map)
+	}
+}
@@ -0,0 +1,265 @@
+use super::*;
+
+/// Numeric values which can be snapped to a step size, see [`SteppedProp`].
+pub trait Quantize: Copy + PartialOrd {
+	/// Snaps the value to the nearest multiple of the step counted from the origin.
+	///
+	/// Halfway values are rounded away from the origin, a step of zero or less leaves the value unchanged.
+	fn quantize(self, step: Self, origin: Self) -> Self;
+}
+
+macro_rules! impl_quantize_float {
+	($($ty:ty),*) => {$(
+		impl Quantize for $ty {
+			fn quantize(self, step: $ty, origin: $ty) -> $ty {
+				if step <= 0.0 || step.is_nan() || !self.is_finite() {
+					return self;
+				}
+				let steps = ((self - origin) / step).round();
+				// Divide by the reciprocal when it is whole to avoid noise such as 0.35000000000000003
+				let recip = step.recip();
+				let offset = if (recip - recip.round()).abs() < 1e-6 { steps / recip.round() } else { steps * step };
+				origin + offset
+			}
+		}
+	)*};
+}
+impl_quantize_float!(f32, f64);
+
+macro_rules! impl_quantize_int {
+	($($ty:ty),*) => {$(
+		impl Quantize for $ty {
+			fn quantize(self, step: $ty, origin: $ty) -> $ty {
+				if step <= 0 {
+					return self;
+				}
+				let (val, step, origin) = (self as i128, step as i128, origin as i128);
+				let offset = val - origin;
+				let steps = (offset.abs() + step / 2) / step * offset.signum();
+				<$ty>::try_from(origin + steps * step).unwrap_or(self)
+			}
+		}
+	)*};
+}
+impl_quantize_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Property node with its value snapped to a step size.
+///
+/// Values are snapped to the nearest multiple of the step counted from the lower bound, or from zero without a lower bound.
+/// Snapped values outside the bounds are rejected.
+/// Use for sliders whose values are replicated so every peer ends up with the same value.
+///
+/// ```
+/// let mut volume = 0.5;
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::SteppedProp("volume", &mut volume, &0.5, 0.05, Some(&0.0), Some(&1.0)));
+/// });
+///
+/// let mut writer = String::new();
+/// assert!(cvar::console::set(&mut root, "volume", "0.33", &mut writer));
+/// assert_eq!(writer, "volume: value 0.33 snapped to 0.35\n");
+/// assert_eq!(cvar::console::get(&mut root, "volume").as_deref(), Some("0.35"));
+///
+/// writer.clear();
+/// assert!(!cvar::console::set(&mut root, "volume", "1.5", &mut writer));
+/// assert_eq!(writer, "error: volume \"1.5\": value 1.5 out of range [0, 1]\n");
+/// ```
+pub struct SteppedProp<'a, 'x, T: 'static> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	epsilon: f64,
+	variable: &'x mut T,
+	default: &'a T,
+	step: T,
+	min: Option<&'a T>,
+	max: Option<&'a T>,
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn SteppedProp<'a, 'x, T>(name: &'a str, variable: &'x mut T, default: &'a T, step: T, min: Option<&'a T>, max: Option<&'a T>) -> SteppedProp<'a, 'x, T> {
+	SteppedProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, epsilon: 0.0, variable, default, step, min, max }
+}
+
+impl<'a, 'x, T> SteppedProp<'a, 'x, T> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut T, default: &'a T, step: T, min: Option<&'a T>, max: Option<&'a T>) -> SteppedProp<'a, 'x, T> {
+		SteppedProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, epsilon: 0.0, variable, default, step, min, max }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+
+	/// Sets the tolerance for comparing float values.
+	///
+	/// The value is considered the default if it differs less than the tolerance, see [`IProperty::epsilon`].
+	#[inline]
+	pub fn with_epsilon(self, epsilon: f64) -> Self {
+		Self { epsilon, ..self }
+	}
+}
+
+impl<'a, 'x, T> SteppedProp<'a, 'x, T>
+	where T: Default + Quantize + fmt::Display
+{
+	// Snaps the value and stores it if it is within bounds
+	fn store(&mut self, val: T, writer: &mut dyn IWrite) -> bool {
+		match self.snap(val, writer) {
+			Some(snapped) => {
+				*self.variable = snapped;
+				true
+			},
+			None => false,
+		}
+	}
+
+	// Returns the snapped value or writes why the value is rejected
+	fn snap(&self, val: T, writer: &mut dyn IWrite) -> Option<T> {
+		let origin = self.min.copied().unwrap_or_default();
+		let snapped = val.quantize(self.step, origin);
+		if exceeded_bound(&snapped, self.min, self.max).is_some() {
+			let _ = write_out_of_range(writer, &val, self.min, self.max);
+			return None;
+		}
+		if snapped != val {
+			let _ = write!(writer, "value {val} snapped to {snapped}");
+		}
+		Some(snapped)
+	}
+}
+
+impl<'a, 'x, T> INode for SteppedProp<'a, 'x, T>
+	where T: Default + Quantize + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x, T> IProperty for SteppedProp<'a, 'x, T>
+	where T: Default + Quantize + fmt::Display + FromStr,
+	      T::Err: StdError + Send + Sync + 'static
+{
+	fn get_value(&self) -> &dyn IValue {
+		&*self.variable
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(&val) = val.downcast_ref::<T>() {
+			self.store(val, writer)
+		}
+		else {
+			let _ = write_mismatched_types::<T>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => self.store(val, writer),
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match val.parse::<T>() {
+			Ok(val) => self.snap(val, writer).is_some(),
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				false
+			},
+		}
+	}
+
+	fn reset(&mut self) {
+		*self.variable = *self.default;
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.default
+	}
+
+	fn state(&self) -> PropState {
+		match values_eq(&*self.variable, self.default, self.epsilon) {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+
+	fn epsilon(&self) -> f64 {
+		self.epsilon
+	}
+
+	fn min_value(&self) -> Option<&dyn IValue> {
+		self.min.map(|min| min as &dyn IValue)
+	}
+
+	fn max_value(&self) -> Option<&dyn IValue> {
+		self.max.map(|max| max as &dyn IValue)
+	}
+}
@@ -0,0 +1,126 @@
+/*!
+Helpers for testing trees of properties.
+
+Call [`exercise`] from a test to catch properties whose value types do not round-trip through [`Display`](fmt::Display) and [`FromStr`]:
+
+```
+let mut volume = 0.5f32;
+let mut name = String::from("player");
+let mut root = cvar::Visit(|f| {
+	f(&mut cvar::ClampedProp("volume", &mut volume, &1.0, Some(&0.0), Some(&1.0)));
+	f(&mut cvar::Property("name", &mut name, &String::from("player")));
+});
+
+cvar::testing::exercise(&mut root);
+```
+*/
+
+use super::*;
+
+/// Malformed values which are set on every property, properties must reject or accept them without panicking.
+const MALFORMED: &[&str] = &["", " ", "-", "+", ".", "1e999999", "-0", "NaN", "0x", "\"", "\0", "\u{fffd}", "18446744073709551616", "-9223372036854775809"];
+
+/// Exercises every property in the tree.
+///
+/// For every property checks that:
+///
+/// * After a reset its state is [`PropState::Default`] and its value displays the same as its default value.
+/// * The displayed default value, its [lower](IProperty::min_value) and [upper](IProperty::max_value) bounds and its [valid values](IProperty::values) can be set.
+/// * Setting the displayed value of the property succeeds and does not change the displayed value.
+/// * Setting malformed values does not panic.
+///
+/// Read-only properties are only reset.
+/// Actions are not invoked. The properties are reset to their default values afterwards.
+///
+/// # Panics
+///
+/// Panics with the path and the invariant of every failed check.
+///
+/// ```should_panic
+/// use std::{fmt, str::FromStr};
+///
+/// // Displays the value in a format it cannot parse
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Percent(u32);
+/// impl fmt::Display for Percent {
+/// 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+/// 		write!(f, "{}%", self.0)
+/// 	}
+/// }
+/// impl FromStr for Percent {
+/// 	type Err = std::num::ParseIntError;
+/// 	fn from_str(s: &str) -> Result<Percent, Self::Err> {
+/// 		s.parse().map(Percent)
+/// 	}
+/// }
+///
+/// let mut scale = Percent(100);
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::Property("scale", &mut scale, &Percent(100)));
+/// });
+/// cvar::testing::exercise(&mut root);
+/// ```
+pub fn exercise(root: &mut dyn IVisit) {
+	let mut failures = String::new();
+	console::walk(root, |path, node| {
+		if let Node::Prop(prop) = node.as_node() {
+			exercise_prop(path, prop, &mut failures);
+		}
+	});
+	if !failures.is_empty() {
+		panic!("exercising the properties failed:\n{failures}");
+	}
+}
+
+fn exercise_prop(path: &str, prop: &mut dyn IProperty, failures: &mut dyn IWrite) {
+	prop.reset();
+	let default = prop.default_value().to_string();
+	if prop.flags() & flags::READONLY != 0 {
+		return;
+	}
+	check_reset(path, prop, &default, failures);
+
+	let mut values = vec![default.clone()];
+	values.extend(prop.min_value().into_iter().chain(prop.max_value()).map(|val| val.to_string()));
+	if let Some(valid) = prop.values() {
+		values.extend(valid.iter().map(|val| val.to_string()));
+	}
+	for val in &values {
+		let mut err = String::new();
+		if !prop.set(val, &mut err) {
+			let _ = writeln!(failures, "{path}: cannot set {val:?}: {err}");
+			continue;
+		}
+		check_round_trip(path, prop, failures);
+	}
+
+	for val in MALFORMED {
+		prop.set(val, &mut NullWriter);
+	}
+	prop.reset();
+	check_reset(path, prop, &default, failures);
+}
+
+fn check_reset(path: &str, prop: &mut dyn IProperty, default: &str, failures: &mut dyn IWrite) {
+	let state = prop.state();
+	if state != PropState::Default {
+		let _ = writeln!(failures, "{path}: state is {state:?} after reset");
+	}
+	let value = prop.get_value().to_string();
+	if value != default {
+		let _ = writeln!(failures, "{path}: value is {value:?} after reset, expected the default {default:?}");
+	}
+}
+
+fn check_round_trip(path: &str, prop: &mut dyn IProperty, failures: &mut dyn IWrite) {
+	let value = prop.get_value().to_string();
+	let mut err = String::new();
+	if !prop.set(&value, &mut err) {
+		let _ = writeln!(failures, "{path}: cannot set its own value {value:?}: {err}");
+		return;
+	}
+	let round_trip = prop.get_value().to_string();
+	if round_trip != value {
+		let _ = writeln!(failures, "{path}: value {value:?} changed to {round_trip:?} after setting it");
+	}
+}
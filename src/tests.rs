@@ -1,3 +1,5 @@
+#![allow(clippy::redundant_pattern_matching)]
+
 use crate::*;
 
 struct Foo {
@@ -61,3 +63,671 @@ fn main() {
 	assert!(console::set(&mut root, "foo.string", "any", &mut writer));
 	assert_eq!(console::get_value::<String>(&mut root, "foo.string"), Some(String::from("any")));
 }
+
+#[test]
+fn check_names() {
+	let mut a = 0;
+	let mut b = 0;
+	let mut root = root();
+	let mut root = Visit(|f| {
+		f(&mut Property::new("", &mut a, &0));
+		f(&mut Property::new("b b", &mut b, &0));
+		f(&mut List::new("root", &mut root));
+	});
+	let mut writer = String::new();
+	assert!(!console::check_names(&mut root, &mut writer));
+	assert_eq!(writer, "invalid name: \"\"\ninvalid name: \"b b\"\n");
+
+	let mut writer = String::new();
+	assert!(console::check_names(&mut StrictNames(&mut root), &mut writer));
+	assert_eq!(writer, "");
+}
+
+#[test]
+fn complete() {
+	let mut root = root();
+	let mut index = CompletionIndex::new();
+	index.rebuild(&mut root);
+	for partial in ["", "f", "foo", "foo.", "foo.a", "foo.before", "foo.bar.", "x"] {
+		assert_eq!(console::complete(&mut root, partial), index.complete(partial), "{partial}");
+	}
+	assert_eq!(index.complete("foo."), ["foo.action", "foo.after", "foo.before", "foo.float", "foo.int", "foo.string"]);
+	assert_eq!(index.complete("f"), ["foo"]);
+
+	index.invalidate();
+	assert!(!index.is_valid());
+	index.update(&mut root);
+	assert!(index.is_valid());
+}
+
+#[test]
+fn readonly_flag() {
+	let mut value = 1;
+	let mut root = Visit(|f| {
+		f(&mut Property::new("value", &mut value, &1).with_flags(flags::READONLY | flags::ARCHIVE));
+	});
+	let mut writer = String::new();
+	assert!(!console::set(&mut root, "value", "2", &mut writer));
+	assert!(!console::set_value(&mut root, "value", &2, &mut writer));
+	assert_eq!(writer, "error: value \"2\": cannot set read-only property\nerror: value 2: cannot set read-only property\n");
+	assert_eq!(value, 1);
+}
+
+#[test]
+fn hidden_flag() {
+	let mut a = 1;
+	let mut b = 2;
+	let mut root = Visit(|f| {
+		f(&mut Property::new("a", &mut a, &1));
+		f(&mut Property::new("b", &mut b, &2).with_flags(flags::HIDDEN));
+	});
+	let mut writer = String::new();
+	console::print(&mut root, "", &mut writer);
+	assert_eq!(writer, "a is \"1\"\n");
+
+	let mut writer = String::new();
+	console::print_all(&mut root, "", &mut writer);
+	assert_eq!(writer, "a is \"1\"\nb is \"2\"\n");
+
+	let mut writer = String::new();
+	assert!(console::poke(&mut root, "", None, &mut writer));
+	assert_eq!(writer, "a is \"1\"\n");
+}
+
+#[test]
+fn epsilon() {
+	let mut a = 0.1 + 0.2;
+	let mut b = 0.1 + 0.2;
+	let mut root = Visit(|f| {
+		f(&mut Property::new("a", &mut a, &0.3));
+		f(&mut Property::new("b", &mut b, &0.3).with_epsilon(1e-9));
+	});
+	let mut states = Vec::new();
+	console::walk(&mut root, |_path, node| {
+		if let Node::Prop(prop) = node.as_node() {
+			states.push(prop.state());
+		}
+	});
+	assert_eq!(states, [PropState::UserSet, PropState::Default]);
+
+	let mut snapshot = console::snapshot(&mut root);
+	assert!(console::set(&mut snapshot, "a", "0.3", &mut NullWriter));
+	assert!(console::set(&mut snapshot, "b", "0.3", &mut NullWriter));
+	let changes = console::diff(&mut root, &mut snapshot);
+	assert_eq!(changes, [("a".to_string(), "0.30000000000000004".to_string(), "0.3".to_string())]);
+}
+
+#[test]
+fn no_persist_flag() {
+	let mut fps = 60;
+	let mut name = String::from("Player");
+	let mut root = Visit(|f| {
+		f(&mut Property::new("fps", &mut fps, &0).with_flags(flags::NO_PERSIST));
+		f(&mut Property::new("name", &mut name, &String::new()));
+	});
+	assert_eq!(ini::save(&mut root), "name=Player\n");
+	#[cfg(feature = "json")]
+	assert_eq!(json::save(&mut root), r#"{"name":"Player"}"#);
+	#[cfg(feature = "toml")]
+	assert_eq!(toml::save(&mut root), "name = \"Player\"\n");
+}
+
+#[test]
+fn path_handle() {
+	let mut a = 1;
+	let mut b = 2;
+	let mut extra = false;
+	let mut list = Visit(|f| {
+		f(&mut Property::new("a", &mut a, &0));
+		f(&mut Property::new("b.c", &mut b, &0));
+	});
+	let handle = console::resolve(&mut list, "b.c").unwrap();
+	assert_eq!(handle.get(&mut list).as_deref(), Some("2"));
+	assert!(console::resolve(&mut list, "b").is_none());
+
+	// Inserting a node in front makes the handle stale
+	let mut root = Visit(|f| {
+		f(&mut Property::new("extra", &mut extra, &false));
+		f(&mut List::new("", &mut list));
+	});
+	let handle = console::resolve(&mut root, ".b.c").unwrap();
+	assert!(handle.set(&mut root, "3", &mut NullWriter));
+	let stale = console::resolve(&mut root, ".a").unwrap();
+	let mut root = Visit(|f| {
+		f(&mut List::new("", &mut list));
+	});
+	assert_eq!(stale.get(&mut root), None);
+	assert_eq!(b, 3);
+}
+
+#[test]
+fn show_provenance() {
+	let mut volume = Layered::new();
+	volume.set(Layer::CommandLine, 0.5);
+	let mut fov = 90;
+	let mut root = Visit(|f| {
+		f(&mut LayeredProp::new("volume", &mut volume, &1.0));
+		f(&mut Property::new("fov", &mut fov, &90));
+	});
+	let console = console::Console::new(console::Options { show_provenance: true, ..Default::default() });
+	let mut writer = String::new();
+	console.print(&mut root, "", &mut writer);
+	console.poke(&mut root, "volume", None, &mut writer);
+	console.print_sorted(&mut root, "", &mut writer);
+	assert_eq!(writer, "volume is \"0.5\" [cmdline]\nfov is \"90\"\nvolume is \"0.5\" [cmdline]\nfov    \"90\"\nvolume \"0.5\" [cmdline]\n");
+}
+
+#[test]
+fn deprecated_aliases() {
+	let mut fov = 90;
+	let mut camera = Visit(|f| {
+		f(&mut Property::new("fov", &mut fov, &90));
+		f(&mut Action::new("reset", |_args, writer| { let _ = writeln!(writer, "camera reset"); }));
+	});
+	let mut root = Visit(|f| {
+		f(&mut List::new("camera", &mut camera));
+	});
+	let mut root = Deprecated::new(&mut root, &[("cam", "camera")]);
+	let mut writer = String::new();
+	assert!(console::set(&mut root, "cam.fov", "100", &mut writer));
+	console::invoke(&mut root, "cam.reset", "", &mut writer);
+	assert_eq!(writer, "cam.fov: deprecated, use camera.fov\nwarning: deprecated, use camera.reset\ncamera reset\n");
+	assert_eq!(console::get(&mut root, "camera.fov").as_deref(), Some("100"));
+}
+
+#[test]
+fn parse_permissions() {
+	let permissions: Permissions = "# admin only\nsv.* set 2\n\nsv.kick invoke 3\n".parse().unwrap();
+	assert_eq!(permissions.required("sv.cheats", Access::Set), Some(2));
+	assert_eq!(permissions.required("sv.kick", Access::Invoke), Some(3));
+	assert_eq!(permissions.required("cl.fov", Access::Set), None);
+	assert_eq!("sv.* write 2".parse::<Permissions>(), Err("line 1: unknown access: write".to_string()));
+	assert_eq!("sv.* set".parse::<Permissions>(), Err("line 1: expected a pattern, an access and a level".to_string()));
+}
+
+#[test]
+fn lint_conflicting_kinds() {
+	let mut int = 0;
+	let mut foo = Visit(|f| {
+		f(&mut Property::new("bar", &mut int, &0));
+	});
+	let mut root = Visit(|f| {
+		f(&mut List::new("foo", &mut foo));
+		f(&mut Action::new("foo.bar", |_args, _writer| ()));
+	});
+	let mut writer = String::new();
+	assert!(!console::lint(&mut root, &mut writer));
+	assert_eq!(writer, "conflicting kinds: foo.bar (prop, action)\n");
+}
+
+#[test]
+fn lint_dotted_names() {
+	let mut before = 0;
+	let mut spaced = 0;
+	let mut root = Visit(|f| {
+		f(&mut Property::new("foo.before", &mut before, &0));
+		f(&mut Property::new("foo..after", &mut spaced, &0));
+	});
+	assert!(is_valid_name("foo.before"));
+	let mut writer = String::new();
+	assert!(!console::lint(&mut root, &mut writer));
+	assert_eq!(writer, "invalid name: \"foo..after\"\n");
+	let mut names = String::new();
+	assert!(!console::check_names(&mut root, &mut names));
+	assert_eq!(writer, names);
+}
+
+#[test]
+fn quantize_integers() {
+	assert_eq!(7i32.quantize(5, 0), 5);
+	assert_eq!(8i32.quantize(5, 0), 10);
+	assert_eq!((-8i32).quantize(5, 0), -10);
+	assert_eq!(12u8.quantize(5, 1), 11);
+	assert_eq!(255u8.quantize(10, 0), 255);
+	assert_eq!(3i32.quantize(0, 0), 3);
+}
+
+#[test]
+fn parse_durations() {
+	use std::time::Duration;
+	assert_eq!("2h45m".parse(), Ok(DurationValue(Duration::from_secs(9900))));
+	assert_eq!("1s1ns".parse(), Ok(DurationValue(Duration::new(1, 1))));
+	assert_eq!("0".parse(), Ok(DurationValue(Duration::ZERO)));
+	assert_eq!("".parse::<DurationValue>(), Err(ParseDurationError::Empty));
+	assert_eq!("ms".parse::<DurationValue>(), Err(ParseDurationError::InvalidNumber));
+	assert_eq!("1.5s".parse::<DurationValue>(), Err(ParseDurationError::MissingUnit));
+	assert_eq!("3days".parse::<DurationValue>(), Err(ParseDurationError::UnknownUnit));
+	assert_eq!(DurationValue(Duration::new(3661, 1_000)).to_string(), "1h1m1s1us");
+	assert_eq!(DurationValue(Duration::ZERO).to_string(), "0s");
+}
+
+#[test]
+fn parse_colors() {
+	assert_eq!("#12aBcD".parse(), Ok(ColorValue::rgb(0x12, 0xab, 0xcd)));
+	assert_eq!("1,2,3,4".parse(), Ok(ColorValue::rgba(1, 2, 3, 4)));
+	assert_eq!("#12345".parse::<ColorValue>(), Err(ParseColorError));
+	assert_eq!("#+1+2+3".parse::<ColorValue>(), Err(ParseColorError));
+	assert_eq!("1,2".parse::<ColorValue>(), Err(ParseColorError));
+	assert_eq!("1,2,3,4,5".parse::<ColorValue>(), Err(ParseColorError));
+	assert_eq!("256,0,0".parse::<ColorValue>(), Err(ParseColorError));
+	assert_eq!(ColorValue::default().to_string(), "#000000");
+}
+
+#[test]
+fn parse_percents() {
+	assert_eq!("75%".parse(), Ok(PercentValue(0.75)));
+	assert_eq!("12.5 %".parse(), Ok(PercentValue(0.125)));
+	assert_eq!("0.5".parse(), Ok(PercentValue(0.5)));
+	assert_eq!("%".parse::<PercentValue>(), Err(ParsePercentError));
+	assert_eq!("nan%".parse::<PercentValue>(), Err(ParsePercentError));
+	assert_eq!(PercentValue(0.3).to_string(), "30%");
+	assert_eq!(PercentValue(-0.00001).to_string(), "0%");
+}
+
+#[test]
+fn parse_angles() {
+	assert_eq!("180".parse(), Ok(AngleValue(std::f32::consts::PI)));
+	assert_eq!("-90deg".parse(), Ok(AngleValue(-std::f32::consts::FRAC_PI_2)));
+	assert_eq!("45 °".parse(), Ok(AngleValue(std::f32::consts::FRAC_PI_4)));
+	assert_eq!("0.5rad".parse(), Ok(AngleValue(0.5)));
+	assert_eq!("rad".parse::<AngleValue>(), Err(ParseAngleError));
+	assert_eq!("90grad".parse::<AngleValue>(), Err(ParseAngleError));
+	assert_eq!(AngleValue(std::f32::consts::FRAC_PI_2).to_string(), "90");
+}
+
+#[test]
+fn path_checks() {
+	let mut path = std::path::PathBuf::new();
+	let default = std::path::PathBuf::new();
+	let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+	let mut writer = String::new();
+	assert!(!PathProp::new("path", &mut path, &default).with_kind(PathKind::Dir).set(manifest, &mut writer));
+	assert!(PathProp::new("path", &mut path, &default).with_kind(PathKind::File).set(manifest, &mut writer));
+	assert!(!PathProp::new("path", &mut path, &default).with_exists(true).set("missing.txt", &mut writer));
+	assert!(PathProp::new("path", &mut path, &default).set("missing.txt", &mut writer));
+	assert!(!PathProp::new("path", &mut path, &default).with_writable(true).set("missing/missing.txt", &mut writer));
+	assert_eq!(writer, "path is not a directorypath does not existpath is not writable");
+	assert_eq!(path, std::path::Path::new("missing.txt"));
+}
+
+#[test]
+fn map_props() {
+	let mut limits = std::collections::HashMap::new();
+	limits.insert(String::from("b"), 2);
+	limits.insert(String::from("a"), 1);
+	let mut root = Visit(|f| {
+		f(&mut List("limits", &mut MapProps(&mut limits, &0).with_actions(true)));
+	});
+	let mut writer = String::new();
+	console::invoke(&mut root, "limits.create!", "c", &mut writer);
+	console::invoke(&mut root, "limits.create!", "a 5", &mut writer);
+	console::invoke(&mut root, "limits.create!", "d.e 5", &mut writer);
+	console::invoke(&mut root, "limits.create!", "d x", &mut writer);
+	console::invoke(&mut root, "limits.create!", "", &mut writer);
+	assert_eq!(writer, "error: limits.create!: key already exists: a\n\
+		error: limits.create!: invalid key: \"d.e\"\n\
+		error: limits.create!: invalid value \"x\": invalid digit found in string\n\
+		usage: limits.create! <key> [value]\n");
+	let mut paths = Vec::new();
+	console::walk(&mut root, |path, _node| paths.push(path.to_string()));
+	assert_eq!(paths, ["limits", "limits.a", "limits.b", "limits.c", "limits.create!", "limits.remove!"]);
+}
+
+#[test]
+fn print_masks_secrets() {
+	let mut password = String::from("hunter2");
+	let mut root = Visit(|f| {
+		f(&mut Property::new("password", &mut password, &String::new()).with_flags(flags::SECRET));
+	});
+	let mut writer = String::new();
+	console::print(&mut root, "", &mut writer);
+	console::print_sorted(&mut root, "", &mut writer);
+	assert_eq!(writer, "password is \"****\"\npassword \"****\"\n");
+}
+
+#[test]
+fn base64_round_trip() {
+	for len in 0..8 {
+		let bytes: Vec<u8> = (0..len).map(|i| (i * 37 + 200) as u8).collect();
+		let encoded = Base64Value(bytes.clone()).to_string();
+		assert_eq!(encoded.len() % 4, 0);
+		assert_eq!(encoded.parse(), Ok(Base64Value(bytes)));
+	}
+	assert_eq!(Base64Value(b"foobar".to_vec()).to_string(), "Zm9vYmFy");
+	assert_eq!("Zm9vYg".parse(), Ok(Base64Value(b"foob".to_vec())));
+	assert_eq!("Zm9v!mFy".parse::<Base64Value>(), Err(ParseBase64Error::InvalidCharacter { position: 4 }));
+	assert_eq!("Zm9vY".parse::<Base64Value>(), Err(ParseBase64Error::InvalidLength));
+	assert_eq!("Zg===".parse::<Base64Value>(), Err(ParseBase64Error::InvalidLength));
+	assert_eq!("aGVsbG8==".parse::<Base64Value>(), Err(ParseBase64Error::InvalidLength));
+	assert_eq!("Zm9v=".parse::<Base64Value>(), Err(ParseBase64Error::InvalidLength));
+	assert_eq!("aGVsbG8=".parse(), Ok(Base64Value(b"hello".to_vec())));
+}
+
+#[test]
+fn hex_values() {
+	assert_eq!(HexValue(u128::MAX).to_string(), "0xffffffffffffffffffffffffffffffff");
+	assert_eq!("0x7fff0000".parse(), Ok(HexValue(0x7fff0000usize)));
+	assert_eq!("-1".parse(), Ok(HexValue(-1isize)));
+	assert_eq!(HexValue(vec![0x00, 0x0a, 0xff]).to_string(), "000aff");
+	assert_eq!("0a0b".parse::<HexValue<[u8; 3]>>(), Err(ParseHexBytesError::InvalidLength));
+	assert_eq!("0g".parse::<HexValue<Vec<u8>>>(), Err(ParseHexBytesError::InvalidDigit));
+	assert_eq!("+1".parse::<HexValue<Vec<u8>>>(), Err(ParseHexBytesError::InvalidDigit));
+}
+
+#[test]
+fn radix_values() {
+	assert_eq!(OctValue(0o755u32).to_string(), "0o755");
+	assert_eq!(BinValue(0i8).to_string(), "0");
+	assert_eq!("0o17".parse(), Ok(OctValue(15u16)));
+	assert_eq!("!0b1".parse(), Ok(BinValue(0xfeu8)));
+	assert_eq!("0xff".parse(), Ok(BinValue(255u8)));
+	assert!("0b2".parse::<BinValue<u8>>().is_err());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_validate_leaves_values() {
+	let mut token = String::from("hunter2");
+	let mut scale = 1.23456f64;
+	let mut root = Visit(|f| {
+		f(&mut SecretProp("token", &mut token, &String::new()));
+		f(&mut FloatProp::new("scale", &mut scale, &1.0).with_precision(3));
+	});
+	let config = serde_json::json!({"token": "secret", "scale": "x"});
+	let issues = json::validate(&mut root, &config);
+	let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+	assert_eq!(issues, ["scale: invalid float literal"]);
+	assert_eq!(token, "hunter2");
+	assert_eq!(scale, 1.23456);
+}
+
+#[test]
+fn reset_matching_skips_readonly() {
+	let mut volume = 0.3;
+	let mut build = 2;
+	let mut root = Visit(|f| {
+		f(&mut Property("audio.volume", &mut volume, &1.0));
+		f(&mut Property::new("audio.build", &mut build, &1).with_flags(flags::READONLY));
+	});
+	let mut dry_run = String::new();
+	let mut real = String::new();
+	assert_eq!(console::reset_matching_dry_run(&mut root, "audio", &mut dry_run), 1);
+	assert_eq!(console::reset_matching(&mut root, "audio", &mut real), 1);
+	assert_eq!(dry_run, "would reset audio.volume: 0.3 -> 1\n");
+	assert_eq!(real, "audio.volume: 0.3 -> 1\n");
+	assert_eq!(build, 2);
+}
+
+#[test]
+fn reset_skips_readonly() {
+	let mut volume = 0.3;
+	let mut build = 7;
+	let mut root = Visit(|f| {
+		f(&mut Property("audio.volume", &mut volume, &1.0));
+		f(&mut Property::new("audio.build", &mut build, &1).with_flags(flags::READONLY));
+	});
+	assert!(!console::reset(&mut root, "audio.build"));
+	assert!(!console::reset(&mut root, "audio.*"));
+	console::reset_all(&mut root);
+	assert_eq!(volume, 1.0);
+	assert_eq!(build, 7);
+}
+
+#[test]
+fn guarded_set_default() {
+	fn set_default(root: &mut dyn IVisit, writer: &mut String) -> bool {
+		let mut result = false;
+		console::find(root, "volume", |node| {
+			if let Node::Prop(prop) = node.as_node() {
+				result = prop.set_default("0.5", writer);
+			}
+		});
+		result
+	}
+	let mut volume = OwnedProp::new(String::from("volume"), 1.0, 1.0).with_flags(flags::CHEAT);
+	let mut root = Visit(|f| f(&mut volume));
+	let mut locks = Locks::new();
+	locks.lock("volume");
+	let mut writer = String::new();
+	assert!(!set_default(&mut ReadOnly(&mut root), &mut writer));
+	assert!(!set_default(&mut Gate(&mut root, &CheatGate(false)), &mut writer));
+	assert!(!set_default(&mut Locked(&mut root, &mut locks), &mut writer));
+	assert_eq!(writer, "cannot set read-only propertycheats are not enabledtemporarily locked");
+	assert_eq!(volume.state(), PropState::Default);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_load_skips_actions() {
+	let mut quit = false;
+	let mut root = Visit(|f| {
+		f(&mut Action("quit!", |_args, _writer| quit = true));
+	});
+	let mut writer = String::new();
+	assert!(!json::load(&mut root, r#"{"quit!": ""}"#, &mut writer));
+	assert_eq!(writer, "error: quit!: cannot set an action\n");
+	assert!(!quit);
+}
+
+#[test]
+fn apply_prop_mismatched_types() {
+	let mut scale = 1.0f32;
+	let mut applied = false;
+	let mut root = Visit(|f| {
+		f(&mut ApplyProp(FloatProp("scale", &mut scale, &1.0), |_old: &f32, _new: &f32, _writer| applied = true));
+	});
+	let mut writer = String::new();
+	assert!(!console::set(&mut root, "scale", "2", &mut writer));
+	assert_eq!(writer, "error: scale \"2\": mismatched types: expected `f32`\n");
+	assert!(!applied);
+	assert_eq!(scale, 1.0);
+}
+
+#[test]
+fn find_matches_grep() {
+	let mut volume = 0.5;
+	let mut settings = Visit(|f| {
+		f(&mut Property("volume", &mut volume, &1.0).with_desc("Master volume.\nScales every sound played, including the music and the voice chat of other players."));
+	});
+	let mut grep = String::new();
+	assert!(console::grep(&mut settings, "voice", &mut grep));
+	assert_eq!(grep, "volume is \"0.5\" - ...e music and the voice chat of other players.\n");
+	let mut root = Visit(|f| f(&mut commands::FindAction("find", &mut settings)));
+	let mut find = String::new();
+	assert!(console::invoke(&mut root, "find", "voice", &mut find));
+	assert_eq!(find, grep);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_load_skips_actions() {
+	let mut quit = false;
+	let mut root = Visit(|f| {
+		f(&mut Action("quit!", |_args, _writer| quit = true));
+	});
+	let mut writer = String::new();
+	assert!(!toml::load(&mut root, "\"quit!\" = \"\"", &mut writer));
+	assert_eq!(writer, "error: quit!: cannot set an action\n");
+	assert!(!quit);
+}
+
+#[test]
+fn ini_load_skips_actions() {
+	let mut quit = false;
+	let mut root = Visit(|f| {
+		f(&mut Action("quit!", |_args, _writer| quit = true));
+	});
+	let mut writer = String::new();
+	assert!(!ini::load(&mut root, "quit!=", &mut writer));
+	assert_eq!(writer, "error: quit!: cannot set an action\n");
+	assert!(!quit);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_into_skips_actions() {
+	let mut quit = false;
+	let mut root = Visit(|f| {
+		f(&mut Action("quit!", |_args, _writer| quit = true));
+	});
+	let mut writer = String::new();
+	let mut deserializer = serde_json::Deserializer::from_str(r#"{"quit!": ""}"#);
+	assert!(!deserialize_into(&mut root, &mut deserializer, &mut writer).unwrap());
+	assert_eq!(writer, "error: quit!: cannot set an action\n");
+	assert!(!quit);
+}
+
+#[test]
+fn invoke_duplicate_actions() {
+	let mut root = Visit(|f| {
+		f(&mut Action("run!", |_args, _writer| false));
+		f(&mut Action("run!", |_args, _writer| true));
+	});
+	let mut writer = String::new();
+	assert!(!console::invoke(&mut root, "run!", "", &mut writer));
+	assert_eq!(writer, "error: run!: invalid arguments\n");
+}
+
+#[test]
+fn snapshot_masks_secrets() {
+	let mut password = String::from("hunter2");
+	let mut root = Visit(|f| {
+		f(&mut Property::new("password", &mut password, &String::new()).with_flags(flags::SECRET));
+	});
+	let mut snapshot = console::snapshot(&mut root);
+	let mut writer = String::new();
+	console::print(&mut snapshot, "", &mut writer);
+	assert_eq!(writer, "password is \"****\"\n");
+}
+
+#[test]
+fn save_latched_pending() {
+	let mut width = Latched::new(1920);
+	let mut root = Visit(|f| {
+		f(&mut LatchedProp("width", &mut width, &1920));
+	});
+	assert!(console::set(&mut root, "width", "1280", &mut NullWriter));
+	assert_eq!(ini::save(&mut root), "width=1280\n");
+	#[cfg(feature = "json")]
+	assert_eq!(json::save(&mut root), r#"{"width":1280}"#);
+	#[cfg(feature = "toml")]
+	assert_eq!(toml::save(&mut root), "width = 1280\n");
+}
+
+#[test]
+fn loaders_set_layers() {
+	let mut width = Layered::new();
+	let mut root = Visit(|f| {
+		f(&mut LayeredProp("width", &mut width, &1920));
+	});
+	assert!(ini::load(&mut root, "width=1280", &mut NullWriter));
+	assert!(console::load_args(&mut root, ["--width=800".to_string()], &mut NullWriter));
+	assert!(console::set(&mut root, "width", "640", &mut NullWriter));
+	assert_eq!(width, Layered { file: Some(1280), cmdline: Some(800), runtime: Some(640) });
+}
+
+#[test]
+fn index_set_stale() {
+	let mut a = 1;
+	let mut b = 2;
+	let mut extra = false;
+	let mut index = Index::new();
+	index.rebuild(&mut Visit(|f| f(&mut Property::new("b", &mut b, &0))));
+	let mut writer = String::new();
+	// Inserting a node in front makes the handle stale
+	let mut root = Visit(|f| {
+		f(&mut Property::new("extra", &mut extra, &false));
+		f(&mut Property::new("a", &mut a, &0));
+		f(&mut Property::new("b", &mut b, &0));
+	});
+	assert!(index.set(&mut root, "b", "3", &mut writer));
+	assert!(!index.is_valid());
+	index.rebuild(&mut root);
+	assert!(index.set(&mut root, "b", "4", &mut writer));
+	assert!(index.is_valid());
+	assert!(!index.set(&mut root, "c", "5", &mut writer));
+	assert_eq!(writer, "unknown: c\n");
+	assert_eq!(b, 4);
+}
+
+#[test]
+fn load_args_unknown() {
+	let mut port = 8080;
+	let mut root = Visit(|f| {
+		f(&mut Property::new("port", &mut port, &8080));
+	});
+	let mut writer = String::new();
+	let args = ["--verbose", "--x=y", "--port=3000"].map(String::from);
+	assert!(!console::load_args(&mut root, args, &mut writer));
+	assert_eq!(writer, "unknown: x\n");
+	assert_eq!(port, 3000);
+}
+
+#[test]
+fn defaults_reset_owned() {
+	let mut sensitivity = OwnedProp::new(String::from("sensitivity"), 2.0, 1.0);
+	let mut root = Visit(|f| f(&mut sensitivity));
+	console::reset_all(&mut Defaults(&mut root, &[("sensitivity", "0.5")]));
+	assert_eq!(console::get(&mut root, "sensitivity").as_deref(), Some("0.5"));
+	let mut states = Vec::new();
+	console::walk(&mut root, |_path, node| {
+		if let Node::Prop(prop) = node.as_node() {
+			states.push(prop.state());
+		}
+	});
+	assert_eq!(states, [PropState::Default]);
+}
+
+#[test]
+fn menu_hints() {
+	const HINT: MenuHint = MenuHint { widget: MenuWidget::Checkbox, ..MenuHint::DEFAULT };
+	let mut plugins = DynamicList::new();
+	plugins.insert("mute", Box::new(OwnedProp::new(String::from("mute"), false, false).with_menu_hint(&HINT)));
+	plugins.insert("clear", Box::new(OwnedAction::new(String::from("clear"), |_args, _writer| ()).with_menu_hint(&HINT)));
+	let mut volume = Layered::new();
+	let mut fov = 90;
+	let mut camera = Visit(|f| f(&mut Property::new("fov", &mut fov, &90)));
+	let mut root = Visit(|f| {
+		f(&mut LayeredProp::new("volume", &mut volume, &1.0).with_menu_hint(&HINT));
+		f(&mut Mounted::new("plugins.camera", &mut camera).with_menu_hint(&HINT));
+		f(&mut List::new("plugins", &mut plugins));
+	});
+	let mut hinted = Vec::new();
+	console::walk(&mut root, |path, node| {
+		if node.menu_hint().map(|hint| hint.widget()) == Some(MenuWidget::Checkbox) {
+			hinted.push(path.to_string());
+		}
+	});
+	assert_eq!(hinted, ["volume", "plugins.camera", "plugins.mute", "plugins.clear"]);
+}
+
+#[test]
+fn owned_finite() {
+	let mut plugins = DynamicList::new();
+	plugins.insert("scale", Box::new(OwnedProp::new(String::from("scale"), 1.0f32, 1.0).with_finite(true)));
+	let mut writer = String::new();
+	assert!(!console::set(&mut plugins, "scale", "NaN", &mut writer));
+	assert!(!console::set_value(&mut plugins, "scale", &f32::INFINITY, &mut writer));
+	assert!(console::set(&mut plugins, "scale", "2", &mut writer));
+	assert_eq!(writer, "error: scale \"NaN\": value NaN is not finite\nerror: scale inf: value inf is not finite\n");
+}
+
+#[test]
+fn console_options() {
+	let mut volume = 0.5;
+	let mut secret = 1;
+	let mut root = Visit(|f| {
+		f(&mut Property::new("Volume", &mut volume, &1.0));
+		f(&mut Property::new("debug", &mut secret, &0).with_flags(flags::HIDDEN));
+	});
+	let console = console::Console::new(console::Options { ignore_case: true, show_hidden: true, ..Default::default() });
+	assert_eq!(console.complete(&mut root, "vol"), ["Volume"]);
+	assert_eq!(console::complete(&mut root, "vol"), [""; 0]);
+	let mut writer = String::new();
+	assert!(console.grep(&mut root, "debug", &mut writer));
+	assert!(!console::grep(&mut root, "debug", &mut writer));
+	console::Console::default().print_all(&mut root, "", &mut writer);
+	console.reset_all(&mut root);
+	assert_eq!(writer, "debug is \"1\"\nVolume is \"0.5\"\ndebug is \"1\"\n");
+	assert_eq!((volume, secret), (1.0, 0));
+}
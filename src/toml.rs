@@ -0,0 +1,113 @@
+/*!
+TOML configuration files.
+
+The dotted paths of the properties map onto nested TOML tables.
+
+```
+let mut width = 1920;
+let mut fullscreen = false;
+let mut name = String::from("Player");
+let mut root = cvar::Visit(|f| {
+	f(&mut cvar::Property("video.width", &mut width, &1920));
+	f(&mut cvar::Property("video.fullscreen", &mut fullscreen, &false));
+	f(&mut cvar::Property("name", &mut name, &String::from("Player")));
+});
+
+cvar::console::set_silent(&mut root, "video.fullscreen", "true");
+let config = cvar::toml::save(&mut root);
+assert_eq!(config, "[video]\nfullscreen = true\n");
+
+cvar::console::reset_all(&mut root);
+assert!(cvar::toml::load(&mut root, &config, &mut cvar::NullWriter));
+assert_eq!(cvar::console::get(&mut root, "video.fullscreen").as_deref(), Some("true"));
+```
+*/
+
+use ::toml::{Table, Value};
+use super::*;
+
+/// Saves the properties as a TOML document.
+///
+/// Only properties which do not have their default value or have a [pending](flags::PENDING) value are saved, properties with the [`NO_PERSIST`](flags::NO_PERSIST) flag are never saved.
+/// Booleans, integers and floats are saved as their TOML types, all other values are saved as strings.
+pub fn save(root: &mut dyn IVisit) -> String {
+	let mut table = Table::new();
+	console::walk(root, |path, node| {
+		if let Node::Prop(prop) = node.as_node() {
+			let changed = prop.state() != PropState::Default || prop.flags() & flags::PENDING != 0;
+			if changed && prop.flags() & flags::NO_PERSIST == 0 {
+				insert(&mut table, path, to_toml(prop.persist_value()));
+			}
+		}
+	});
+	table.to_string()
+}
+
+// Inserts the value in the nested table given by the path
+fn insert(table: &mut Table, path: &str, value: Value) {
+	if let Some((key, tail)) = path.split_once('.') {
+		let entry = table.entry(key).or_insert_with(|| Value::Table(Table::new()));
+		if let Value::Table(child) = entry {
+			return insert(child, tail, value);
+		}
+	}
+	// Keys which conflict with a value are stored with their dotted path quoted
+	table.insert(path.to_string(), value);
+}
+
+fn to_toml(value: &dyn IValue) -> Value {
+	let string = value.to_string();
+	if value.is::<bool>() {
+		if let Ok(value) = string.parse() {
+			return Value::Boolean(value);
+		}
+	}
+	if value.is::<i8>() || value.is::<i16>() || value.is::<i32>() || value.is::<i64>() || value.is::<isize>() ||
+		value.is::<u8>() || value.is::<u16>() || value.is::<u32>() || value.is::<u64>() || value.is::<usize>() {
+		if let Ok(value) = string.parse() {
+			return Value::Integer(value);
+		}
+	}
+	if value.is::<f32>() || value.is::<f64>() || value.is::<FloatValue<f32>>() || value.is::<FloatValue<f64>>() {
+		if let Ok(value) = string.parse() {
+			return Value::Float(value);
+		}
+	}
+	Value::String(string)
+}
+
+/// Loads the properties from a TOML document.
+///
+/// Every key is set in the [`File`](Layer::File) layer, see [`IProperty::set_layer`], unknown keys, invalid values and keys naming actions are reported to the writer and skipped.
+/// Actions are never invoked.
+///
+/// Returns `false` if the document could not be parsed or any property failed to set.
+pub fn load(root: &mut dyn IVisit, text: &str, writer: &mut dyn IWrite) -> bool {
+	let table = match text.parse::<Table>() {
+		Ok(table) => table,
+		Err(err) => {
+			let _ = writeln!(writer, "error: {}", err.message());
+			return false;
+		},
+	};
+	let mut path = String::new();
+	load_table(root, &table, &mut path, writer)
+}
+
+fn load_table(root: &mut dyn IVisit, table: &Table, path: &mut String, writer: &mut dyn IWrite) -> bool {
+	let mut result = true;
+	for (key, value) in table {
+		let len = path.len();
+		if len > 0 {
+			path.push('.');
+		}
+		path.push_str(key);
+		result &= match value {
+			Value::Table(table) => load_table(root, table, path, writer),
+			Value::String(val) => console::set_loaded(root, path, Layer::File, val, writer),
+			value => console::set_loaded(root, path, Layer::File, &value.to_string(), writer),
+		};
+		path.truncate(len);
+	}
+	result
+}
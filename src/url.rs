@@ -0,0 +1,188 @@
+use ::url::Url;
+use super::*;
+
+/// URL property node.
+///
+/// Parses the value as an absolute URL and stores its canonical form, eg. the scheme and host are lowercased and an empty path becomes `/`.
+/// Optionally restricts the schemes.
+///
+/// ```
+/// let mut endpoint = String::from("https://example.com/update");
+/// let mut root = cvar::Visit(|f| {
+/// 	f(&mut cvar::UrlProp("updater.endpoint", &mut endpoint, &String::from("https://example.com/update"))
+/// 		.with_schemes(&["http", "https"]));
+/// });
+///
+/// assert!(cvar::console::set_silent(&mut root, "updater.endpoint", "HTTPS://Example.COM"));
+/// assert_eq!(cvar::console::get(&mut root, "updater.endpoint").as_deref(), Some("https://example.com/"));
+///
+/// let mut writer = String::new();
+/// assert!(!cvar::console::set(&mut root, "updater.endpoint", "garbage", &mut writer));
+/// assert!(!cvar::console::set(&mut root, "updater.endpoint", "ftp://example.com", &mut writer));
+/// assert_eq!(writer, "error: updater.endpoint \"garbage\": relative URL without a base\n\
+/// 	error: updater.endpoint \"ftp://example.com\": unsupported scheme ftp, expected http or https\n");
+/// ```
+pub struct UrlProp<'a, 'x> {
+	name: &'a str,
+	desc: &'a str,
+	label: &'a str,
+	id: Option<u64>,
+	menu_hint: Option<&'a dyn IMenuHint>,
+	flags: u32,
+	variable: &'x mut String,
+	default: &'a String,
+	schemes: &'a [&'a str],
+}
+
+#[allow(non_snake_case)]
+#[inline]
+pub fn UrlProp<'a, 'x>(name: &'a str, variable: &'x mut String, default: &'a String) -> UrlProp<'a, 'x> {
+	UrlProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default, schemes: &[] }
+}
+
+impl<'a, 'x> UrlProp<'a, 'x> {
+	#[inline]
+	pub fn new(name: &'a str, variable: &'x mut String, default: &'a String) -> UrlProp<'a, 'x> {
+		UrlProp { name, desc: "", label: "", id: None, menu_hint: None, flags: 0, variable, default, schemes: &[] }
+	}
+
+	/// Sets the node description.
+	#[inline]
+	pub fn with_desc(self, desc: &'a str) -> Self {
+		Self { desc, ..self }
+	}
+
+	/// Sets the node display label.
+	#[inline]
+	pub fn with_label(self, label: &'a str) -> Self {
+		Self { label, ..self }
+	}
+
+	/// Sets the stable node identifier.
+	#[inline]
+	pub fn with_id(self, id: u64) -> Self {
+		Self { id: Some(id), ..self }
+	}
+
+	/// Sets the presentation hints for menus.
+	#[inline]
+	pub fn with_menu_hint(self, menu_hint: &'a dyn IMenuHint) -> Self {
+		Self { menu_hint: Some(menu_hint), ..self }
+	}
+
+	/// Sets the property flags.
+	///
+	/// See the [`flags`] module for conventional flags.
+	#[inline]
+	pub fn with_flags(self, flags: u32) -> Self {
+		Self { flags, ..self }
+	}
+
+	/// Sets the allowed schemes, all schemes are allowed if empty.
+	#[inline]
+	pub fn with_schemes(self, schemes: &'a [&'a str]) -> Self {
+		Self { schemes, ..self }
+	}
+
+	// Parses the value, writes the reason the value is rejected
+	fn parse(&self, val: &str, writer: &mut dyn IWrite) -> Option<Url> {
+		let url = match Url::parse(val) {
+			Ok(url) => url,
+			Err(err) => {
+				let _ = write_error(writer, &err);
+				return None;
+			},
+		};
+		if !self.schemes.is_empty() && !self.schemes.contains(&url.scheme()) {
+			let _ = write!(writer, "unsupported scheme {}, expected ", url.scheme());
+			for (i, scheme) in self.schemes.iter().enumerate() {
+				let sep = if i == 0 { "" } else if i + 1 == self.schemes.len() { " or " } else { ", " };
+				let _ = write!(writer, "{sep}{scheme}");
+			}
+			return None;
+		}
+		Some(url)
+	}
+}
+
+impl<'a, 'x> INode for UrlProp<'a, 'x> {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn description(&self) -> &str {
+		self.desc
+	}
+
+	fn label(&self) -> &str {
+		if self.label.is_empty() { self.name } else { self.label }
+	}
+
+	fn id(&self) -> Option<u64> {
+		self.id
+	}
+
+	fn menu_hint(&self) -> Option<&dyn IMenuHint> {
+		self.menu_hint
+	}
+
+	fn as_node(&mut self) -> Node<'_> {
+		Node::Prop(self)
+	}
+
+	fn as_inode(&mut self) -> &mut dyn INode {
+		self
+	}
+}
+
+impl<'a, 'x> IProperty for UrlProp<'a, 'x> {
+	fn get_value(&self) -> &dyn IValue {
+		&*self.variable
+	}
+
+	fn set_value(&mut self, val: &dyn IValue, writer: &mut dyn IWrite) -> bool {
+		if let Some(val) = val.downcast_ref::<Url>() {
+			self.set(val.as_str(), writer)
+		}
+		else if let Some(val) = val.downcast_ref::<String>() {
+			self.set(val, writer)
+		}
+		else {
+			let _ = write_mismatched_types::<Url>(writer, val);
+			false
+		}
+	}
+
+	fn set(&mut self, val: &str, writer: &mut dyn IWrite) -> bool {
+		match self.parse(val, writer) {
+			Some(url) => {
+				*self.variable = String::from(url);
+				true
+			},
+			None => false,
+		}
+	}
+
+	fn validate(&self, val: &str, writer: &mut dyn IWrite) -> bool {
+		self.parse(val, writer).is_some()
+	}
+
+	fn reset(&mut self) {
+		self.variable.clone_from(self.default);
+	}
+
+	fn default_value(&self) -> &dyn IValue {
+		self.default
+	}
+
+	fn state(&self) -> PropState {
+		match *self.variable == *self.default {
+			true => PropState::Default,
+			false => PropState::UserSet,
+		}
+	}
+
+	fn flags(&self) -> u32 {
+		self.flags
+	}
+}